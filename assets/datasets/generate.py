@@ -0,0 +1,71 @@
+"""Generates the compressed datasets loaded by the `datasets` feature.
+
+Each file is a zlib stream of little-endian u16 values, which map to [0, 1] by dividing by 65535.
+Points are stored as [x, y, z] triplets, spheres as [x, y, z, radius] quadruplets.
+
+    python3 assets/datasets/generate.py
+"""
+
+import math
+import random
+import struct
+import zlib
+from pathlib import Path
+
+SCALE = 65535
+HERE = Path(__file__).parent
+
+
+def quantize(values):
+    return struct.pack(f"<{len(values)}H", *(round(v * SCALE) for v in values))
+
+
+def poisson(count, seed):
+    rng = random.Random(seed)
+    return [rng.random() for _ in range(3 * count)]
+
+
+def sphere_packing(count, r_min, r_max, gap, seed):
+    """Random sequential addition of polydisperse spheres, largest first, inside the unit box."""
+    rng = random.Random(seed)
+    radii = sorted((rng.uniform(r_min, r_max) for _ in range(count)), reverse=True)
+    size = 2.0 * r_max + gap
+    bins = math.ceil(1.0 / size)
+    grid = {}
+    spheres = []
+    for r in radii:
+        r = math.floor(r * SCALE) / SCALE
+        for _ in range(100000):
+            c = [round(rng.uniform(r, 1.0 - r) * SCALE) / SCALE for _ in range(3)]
+            key = [min(int(v / size), bins - 1) for v in c]
+            overlap = False
+            for dx in (-1, 0, 1):
+                for dy in (-1, 0, 1):
+                    for dz in (-1, 0, 1):
+                        for s in grid.get((key[0] + dx, key[1] + dy, key[2] + dz), ()):
+                            if math.dist(c, s[:3]) < r + s[3] + gap:
+                                overlap = True
+                                break
+                        if overlap:
+                            break
+                    if overlap:
+                        break
+                if overlap:
+                    break
+            if not overlap:
+                sphere = (*c, r)
+                spheres.append(sphere)
+                grid.setdefault(tuple(key), []).append(sphere)
+                break
+        else:
+            raise RuntimeError("packing is too dense")
+    return [v for s in spheres for v in s]
+
+
+def write(name, values):
+    (HERE / name).write_bytes(zlib.compress(quantize(values), 9))
+
+
+if __name__ == "__main__":
+    write("poisson_10k.bin.z", poisson(10000, 2024))
+    write("sphere_packing.bin.z", sphere_packing(2000, 0.02, 0.04, 1e-4, 2024))
@@ -110,7 +110,7 @@ fn plot_capacity_results() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = Path::new("benches/results");
     std::fs::create_dir_all(out_dir)?;
     let output = Command::new("git")
-        .args(&["rev-parse", "--short", "HEAD"])
+        .args(["rev-parse", "--short", "HEAD"])
         .output()
         .expect("Failed to execute git command");
     let git_hash = String::from_utf8(output.stdout).expect("Invalid UTF-8").trim().to_string();
@@ -160,22 +160,22 @@ fn plot_capacity_results() -> Result<(), Box<dyn std::error::Error>> {
                 &color,
             ))?
             .label(*method)
-            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
 
         chart.draw_series(PointSeries::of_element(
             points.iter().map(|(x, y, _, _)| (*x, *y)),
             5,
             &color,
             &|c, s, st| {
-                return EmptyElement::at(c) + Circle::new((0, 0), s, st.filled());
+                EmptyElement::at(c) + Circle::new((0, 0), s, st.filled())
             },
         ))?;
     }
 
     chart
         .configure_series_labels()
-        .background_style(&WHITE.mix(0.8))
-        .border_style(&BLACK)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
         .draw()?;
 
     println!("Plot saved to {:?}", out_file);
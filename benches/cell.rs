@@ -13,7 +13,7 @@ fn benchmark_cell_volume(c: &mut Criterion) {
         generators.push(v);
     }
     
-    tess.set_generators(&generators);
+    tess.set_generators(&generators).unwrap();
     tess.calculate();
 
     c.bench_function("cell_volume_10000", |b| {
@@ -40,7 +40,7 @@ fn benchmark_cell_centroid(c: &mut Criterion) {
         generators.push(v);
     }
     
-    tess.set_generators(&generators);
+    tess.set_generators(&generators).unwrap();
     tess.calculate();
 
     c.bench_function("cell_centroid_10000", |b| {
@@ -1,7 +1,7 @@
 use criterion::{criterion_group, Criterion, BenchmarkId};
-use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Algorithm3DOctree, Wall, Cell3DFaces};
+use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Algorithm3DOctree, AlgorithmAdaptiveGrid, AlgorithmBinnedBvh, Wall, Cell3DFaces};
 use voronoid::wall_3d::TrefoilKnotGeometry;
-use rand::prelude::*;
+use voronoid::distributions::{generate_axes_points, generate_central_box_points, generate_sphere_surface_points};
 use plotters::prelude::*;
 use serde::Deserialize;
 use std::collections::BTreeMap;
@@ -50,6 +50,22 @@ fn benchmark_distributions(c: &mut Criterion) {
             })
         });
 
+        group.bench_with_input(BenchmarkId::new("uniform/adaptive", size), &size, |b, &s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(grid_res, grid_res, grid_res, &bounds));
+            tess.random_generators(s);
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("uniform/bvh", size), &size, |b, &s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(grid_res, grid_res, grid_res, &bounds));
+            tess.random_generators(s);
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
         // Trefoil Knot Distribution
         let cx = (bounds.min[0] + bounds.max[0]) / 2.0;
         let cy = (bounds.min[1] + bounds.max[1]) / 2.0;
@@ -59,7 +75,7 @@ fn benchmark_distributions(c: &mut Criterion) {
 
         group.bench_with_input(BenchmarkId::new("trefoil/grid", size), &size, |b, &s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(grid_res, grid_res, grid_res, &bounds));
-            tess.add_wall(Wall::new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))));
+            tess.add_wall(Wall::try_new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))).unwrap());
             tess.random_generators(s);
             b.iter(|| {
                 tess.calculate();
@@ -68,7 +84,25 @@ fn benchmark_distributions(c: &mut Criterion) {
 
         group.bench_with_input(BenchmarkId::new("trefoil/moctree", size), &size, |b, &s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
-            tess.add_wall(Wall::new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))));
+            tess.add_wall(Wall::try_new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))).unwrap());
+            tess.random_generators(s);
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("trefoil/adaptive", size), &size, |b, &s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(grid_res, grid_res, grid_res, &bounds));
+            tess.add_wall(Wall::try_new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))).unwrap());
+            tess.random_generators(s);
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("trefoil/bvh", size), &size, |b, &s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(grid_res, grid_res, grid_res, &bounds));
+            tess.add_wall(Wall::try_new(-1000, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, 100))).unwrap());
             tess.random_generators(s);
             b.iter(|| {
                 tess.calculate();
@@ -76,11 +110,11 @@ fn benchmark_distributions(c: &mut Criterion) {
         });
 
         // Axes Distribution
-        let axes_points = generate_axes_points(size, &bounds);
+        let axes_points = generate_axes_points(size, &bounds, &mut rand::thread_rng());
 
         group.bench_with_input(BenchmarkId::new("axes/grid", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(grid_res, grid_res, grid_res, &bounds));
-            tess.set_generators(&axes_points);
+            tess.set_generators(&axes_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
@@ -88,18 +122,34 @@ fn benchmark_distributions(c: &mut Criterion) {
 
         group.bench_with_input(BenchmarkId::new("axes/moctree", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
-            tess.set_generators(&axes_points);
+            tess.set_generators(&axes_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("axes/adaptive", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&axes_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("axes/bvh", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&axes_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
         });
 
         // Central Box Distribution (10% volume)
-        let central_points = generate_central_box_points(size, &bounds);
+        let central_points = generate_central_box_points(size, &bounds, &mut rand::thread_rng());
 
         group.bench_with_input(BenchmarkId::new("central/grid", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(grid_res, grid_res, grid_res, &bounds));
-            tess.set_generators(&central_points);
+            tess.set_generators(&central_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
@@ -107,18 +157,34 @@ fn benchmark_distributions(c: &mut Criterion) {
 
         group.bench_with_input(BenchmarkId::new("central/moctree", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
-            tess.set_generators(&central_points);
+            tess.set_generators(&central_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("central/adaptive", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&central_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
+
+        group.bench_with_input(BenchmarkId::new("central/bvh", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&central_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
         });
 
         // Sphere Surface Distribution
-        let sphere_points = generate_sphere_surface_points(size, &bounds);
+        let sphere_points = generate_sphere_surface_points(size, &bounds, &mut rand::thread_rng());
 
         group.bench_with_input(BenchmarkId::new("sphere/grid", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(grid_res, grid_res, grid_res, &bounds));
-            tess.set_generators(&sphere_points);
+            tess.set_generators(&sphere_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
@@ -126,100 +192,34 @@ fn benchmark_distributions(c: &mut Criterion) {
 
         group.bench_with_input(BenchmarkId::new("sphere/moctree", size), &size, |b, &_s| {
             let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
-            tess.set_generators(&sphere_points);
+            tess.set_generators(&sphere_points).unwrap();
             b.iter(|| {
                 tess.calculate();
             })
         });
-    }
-    group.finish();
-}
-
-fn generate_axes_points(count: usize, bounds: &BoundingBox<3>) -> Vec<f64> {
-    let mut rng = rand::thread_rng();
-    let mut points = Vec::with_capacity(count * 3);
-    
-    let w: f64 = bounds.max[0] - bounds.min[0];
-    let h: f64 = bounds.max[1] - bounds.min[1];
-    let d: f64 = bounds.max[2] - bounds.min[2];
-    
-    let transform = |val: f64| 0.5 + 4.0 * (val - 0.5).powi(3);
-
-    for _ in 0..count {
-        let x = transform(rng.r#gen::<f64>());
-        let y = transform(rng.r#gen::<f64>());
-        let z = transform(rng.r#gen::<f64>());
-
-        points.push(bounds.min[0] + x * w);
-        points.push(bounds.min[1] + y * h);
-        points.push(bounds.min[2] + z * d);
-    }
-    points
-}
-
-fn generate_central_box_points(count: usize, bounds: &BoundingBox<3>) -> Vec<f64> {
-    let mut rng = rand::thread_rng();
-    let mut points = Vec::with_capacity(count * 3);
-    
-    let w = bounds.max[0] - bounds.min[0];
-    let h = bounds.max[1] - bounds.min[1];
-    let d = bounds.max[2] - bounds.min[2];
-    
-    let cx = (bounds.min[0] + bounds.max[0]) / 2.0;
-    let cy = (bounds.min[1] + bounds.max[1]) / 2.0;
-    let cz = (bounds.min[2] + bounds.max[2]) / 2.0;
 
-    // Scale factor for 10% volume: s = cbrt(0.1)
-    let s = 0.1f64.powf(1.0/3.0);
-    
-    for _ in 0..count {
-        points.push(cx + (rng.r#gen::<f64>() - 0.5) * w * s);
-        points.push(cy + (rng.r#gen::<f64>() - 0.5) * h * s);
-        points.push(cz + (rng.r#gen::<f64>() - 0.5) * d * s);
-    }
-    points
-}
-
-fn generate_sphere_surface_points(count: usize, bounds: &BoundingBox<3>) -> Vec<f64> {
-    let mut rng = rand::thread_rng();
-    let mut points = Vec::with_capacity(count * 3);
-    
-    let w = bounds.max[0] - bounds.min[0];
-    let h = bounds.max[1] - bounds.min[1];
-    let d = bounds.max[2] - bounds.min[2];
-    
-    let cx = (bounds.min[0] + bounds.max[0]) / 2.0;
-    let cy = (bounds.min[1] + bounds.max[1]) / 2.0;
-    let cz = (bounds.min[2] + bounds.max[2]) / 2.0;
-
-    let radius = w.min(h).min(d) / 3.0;
+        group.bench_with_input(BenchmarkId::new("sphere/adaptive", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&sphere_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
 
-    // Center point
-    points.push(cx);
-    points.push(cy);
-    points.push(cz);
-    
-    for _ in 1..count {
-        loop {
-            let x = rng.r#gen::<f64>() * 2.0 - 1.0;
-            let y = rng.r#gen::<f64>() * 2.0 - 1.0;
-            let z = rng.r#gen::<f64>() * 2.0 - 1.0;
-            let len_sq = x*x + y*y + z*z;
-            if len_sq > 0.0001 && len_sq <= 1.0 {
-                let len = len_sq.sqrt();
-                points.push(cx + x / len * radius);
-                points.push(cy + y / len * radius);
-                points.push(cz + z / len * radius);
-                break;
-            }
-        }
+        group.bench_with_input(BenchmarkId::new("sphere/bvh", size), &size, |b, &_s| {
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(grid_res, grid_res, grid_res, &bounds));
+            tess.set_generators(&sphere_points).unwrap();
+            b.iter(|| {
+                tess.calculate();
+            })
+        });
     }
-    points
+    group.finish();
 }
 
 fn plot_distribution_results() -> Result<(), Box<dyn std::error::Error>> {
     let distributions = ["uniform", "trefoil", "axes", "central", "sphere"];
-    let methods = ["grid", "moctree"];
+    let methods = ["grid", "moctree", "adaptive", "bvh"];
     let root = Path::new("target/criterion/distributions");
 
     if !root.exists() {
@@ -230,7 +230,7 @@ fn plot_distribution_results() -> Result<(), Box<dyn std::error::Error>> {
     std::fs::create_dir_all(out_dir)?;
     
     let output = Command::new("git")
-        .args(&["rev-parse", "--short", "HEAD"])
+        .args(["rev-parse", "--short", "HEAD"])
         .output()
         .expect("Failed to execute git command");
     let git_hash = String::from_utf8(output.stdout).expect("Invalid UTF-8").trim().to_string();
@@ -287,7 +287,7 @@ fn plot_distribution_results() -> Result<(), Box<dyn std::error::Error>> {
             .y_desc("Time (ms)")
             .draw()?;
 
-        let colors = [RED, GREEN, BLUE];
+        let colors = [RED, GREEN, BLUE, MAGENTA];
 
         for (i, method) in methods.iter().enumerate() {
             let color = colors[i % colors.len()];
@@ -297,9 +297,9 @@ fn plot_distribution_results() -> Result<(), Box<dyn std::error::Error>> {
                     let val = *method_map.get(*method).unwrap_or(&0.0);
                     
                     let group_center = dist_idx as f64;
-                    let offset = if i == 0 { -0.2 } else { 0.2 };
+                    let offset = (i as f64 - (methods.len() - 1) as f64 / 2.0) * 0.21;
                     let bar_center = group_center + offset;
-                    let width = 0.35;
+                    let width = 0.19;
                     
                     Rectangle::new(
                         [(bar_center - width / 2.0, 0.0), (bar_center + width / 2.0, val)],
@@ -312,8 +312,8 @@ fn plot_distribution_results() -> Result<(), Box<dyn std::error::Error>> {
         }
         
         chart.configure_series_labels()
-            .background_style(&WHITE.mix(0.8))
-            .border_style(&BLACK)
+            .background_style(WHITE.mix(0.8))
+            .border_style(BLACK)
             .draw()?;
             
         println!("Plot saved to {:?}", out_file);
@@ -40,7 +40,7 @@ fn benchmark_parallelism(c: &mut Criterion) {
         cores_list.push(cores);
         cores *= 2;
     }
-    if cores_list.last().map_or(false, |&last| last < max_cores) {
+    if cores_list.last().is_some_and(|&last| last < max_cores) {
         cores_list.push(max_cores);
     }
     
@@ -96,7 +96,7 @@ fn plot_parallelism_results() -> Result<(), Box<dyn std::error::Error>> {
         cores_list.push(cores);
         cores *= 2;
     }
-    if cores_list.last().map_or(false, |&last| last < max_cores) {
+    if cores_list.last().is_some_and(|&last| last < max_cores) {
         cores_list.push(max_cores);
     }
 
@@ -134,7 +134,7 @@ fn plot_parallelism_results() -> Result<(), Box<dyn std::error::Error>> {
     std::fs::create_dir_all(out_dir)?;
     
     let output = Command::new("git")
-        .args(&["rev-parse", "--short", "HEAD"])
+        .args(["rev-parse", "--short", "HEAD"])
         .output()
         .ok();
     let git_hash = output
@@ -193,22 +193,22 @@ fn plot_parallelism_results() -> Result<(), Box<dyn std::error::Error>> {
                 &color,
             ))?
             .label(*method)
-            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
 
         chart.draw_series(PointSeries::of_element(
             points.iter().map(|(x, y, _, _)| (*x as f64, *y)),
             5,
             &color,
             &|c, s, st| {
-                return EmptyElement::at(c) + Circle::new((0, 0), s, st.filled());
+                EmptyElement::at(c) + Circle::new((0, 0), s, st.filled())
             },
         ))?;
     }
     
     chart
         .configure_series_labels()
-        .background_style(&WHITE.mix(0.8))
-        .border_style(&BLACK)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
         .draw()?;
 
     println!("Plot saved to {:?}", out_file);
@@ -105,7 +105,7 @@ fn plot_scaling_results() -> Result<(), Box<dyn std::error::Error>> {
     let out_dir = Path::new("benches/results");
     std::fs::create_dir_all(out_dir)?;
     let output = Command::new("git")
-        .args(&["rev-parse", "--short", "HEAD"])
+        .args(["rev-parse", "--short", "HEAD"])
         .output()
         .expect("Failed to execute git command");
     let git_hash = String::from_utf8(output.stdout).expect("Invalid UTF-8").trim().to_string();
@@ -132,50 +132,50 @@ fn plot_scaling_results() -> Result<(), Box<dyn std::error::Error>> {
         .draw()?;
 
     // Draw Linear and Quadratic Scaling References (Dotted Lines)
-    if let Some(first_series) = data.values().next() {
-        if let Some(&(start_n, start_t, _, _)) = first_series.first() {
-            let start_n = start_n as f64;
-            let end_n = *SIZES.last().unwrap() as f64;
-            
-            // Logarithmic steps for uniform dots on log-scale
-            let step = 10.0f64.powf(0.05); 
-
-            // Linear: y = x * (start_t / start_n)
-            let mut linear_points = Vec::new();
-            let mut n = SIZES[0] as f64;
-            while n <= end_n * 1.1 {
-                let t = start_t * (n / start_n);
-                linear_points.push((n, t));
-                n *= step;
-            }
-
-            chart.draw_series(PointSeries::of_element(
-                linear_points,
-                1,
-                &BLACK,
-                &|c, s, st| Circle::new(c, s, st.filled()),
-            ))?
-            .label("Linear")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLACK));
-
-            // Quadratic: y = x^2 * (start_t / start_n^2)
-            let mut quadratic_points = Vec::new();
-            let mut n = SIZES[0] as f64;
-            while n <= end_n * 1.1 {
-                let t = start_t * (n / start_n).powi(2);
-                quadratic_points.push((n, t));
-                n *= step;
-            }
+    if let Some(first_series) = data.values().next()
+        && let Some(&(start_n, start_t, _, _)) = first_series.first()
+    {
+        let start_n = start_n as f64;
+        let end_n = *SIZES.last().unwrap() as f64;
+        
+        // Logarithmic steps for uniform dots on log-scale
+        let step = 10.0f64.powf(0.05); 
+
+        // Linear: y = x * (start_t / start_n)
+        let mut linear_points = Vec::new();
+        let mut n = SIZES[0] as f64;
+        while n <= end_n * 1.1 {
+            let t = start_t * (n / start_n);
+            linear_points.push((n, t));
+            n *= step;
+        }
 
-            chart.draw_series(PointSeries::of_element(
-                quadratic_points,
-                1,
-                &BLACK,
-                &|c, s, st| Circle::new(c, s, st.filled()),
-            ))?
-            .label("Quadratic")
-            .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &BLACK));
+        chart.draw_series(PointSeries::of_element(
+            linear_points,
+            1,
+            &BLACK,
+            &|c, s, st| Circle::new(c, s, st.filled()),
+        ))?
+        .label("Linear")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
+
+        // Quadratic: y = x^2 * (start_t / start_n^2)
+        let mut quadratic_points = Vec::new();
+        let mut n = SIZES[0] as f64;
+        while n <= end_n * 1.1 {
+            let t = start_t * (n / start_n).powi(2);
+            quadratic_points.push((n, t));
+            n *= step;
         }
+
+        chart.draw_series(PointSeries::of_element(
+            quadratic_points,
+            1,
+            &BLACK,
+            &|c, s, st| Circle::new(c, s, st.filled()),
+        ))?
+        .label("Quadratic")
+        .legend(|(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], BLACK));
     }
 
     let colors = [RED, BLUE, GREEN, MAGENTA, CYAN];
@@ -202,22 +202,22 @@ fn plot_scaling_results() -> Result<(), Box<dyn std::error::Error>> {
                 &color,
             ))?
             .label(*method)
-            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], &color));
+            .legend(move |(x, y)| PathElement::new(vec![(x, y), (x + 20, y)], color));
 
         chart.draw_series(PointSeries::of_element(
             points.iter().map(|(x, y, _, _)| (*x as f64, *y)),
             5,
             &color,
             &|c, s, st| {
-                return EmptyElement::at(c) + Circle::new((0, 0), s, st.filled());
+                EmptyElement::at(c) + Circle::new((0, 0), s, st.filled())
             },
         ))?;
     }
 
     chart
         .configure_series_labels()
-        .background_style(&WHITE.mix(0.8))
-        .border_style(&BLACK)
+        .background_style(WHITE.mix(0.8))
+        .border_style(BLACK)
         .draw()?;
 
     println!("Plot saved to {:?}", out_file);
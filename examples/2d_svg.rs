@@ -6,26 +6,26 @@ use voronoid::wall_2d::{LineGeometry, CircleGeometry, ConvexPolygonGeometry2D, A
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     run_example("2d_tessellation_line.svg", |tess| {
-        tess.add_wall(Wall::new(-1000, Box::new(LineGeometry::new([50.0, 50.0], [1.0, 1.0]))));
+        tess.add_wall(Wall::try_new(-1000, Box::new(LineGeometry::new([50.0, 50.0], [1.0, 1.0]))).unwrap());
     })?;
 
     run_example("2d_tessellation_circle.svg", |tess| {
-        tess.add_wall(Wall::new(-1000, Box::new(CircleGeometry::new([50.0, 50.0], 40.0))));
+        tess.add_wall(Wall::try_new(-1000, Box::new(CircleGeometry::new([50.0, 50.0], 40.0))).unwrap());
     })?;
 
     run_example("2d_tessellation_polygon.svg", |tess| {
-        tess.add_wall(Wall::new(-1000, Box::new(ConvexPolygonGeometry2D::new_regular([50.0, 50.0], 40.0, 6))));
+        tess.add_wall(Wall::try_new(-1000, Box::new(ConvexPolygonGeometry2D::new_regular([50.0, 50.0], 40.0, 6))).unwrap());
     })?;
 
     run_example("2d_tessellation_washer.svg", |tess| {
-        tess.add_wall(Wall::new(-1000, Box::new(AnnulusGeometry::new([50.0, 50.0], 15.0, 45.0))));
+        tess.add_wall(Wall::try_new(-1000, Box::new(AnnulusGeometry::new([50.0, 50.0], 15.0, 45.0))).unwrap());
     })?;
 
     run_example("2d_tessellation_spline.svg", |tess| {
-        tess.add_wall(Wall::new(-1000, Box::new(CubicBezierGeometry2D::new(
+        tess.add_wall(Wall::try_new(-1000, Box::new(CubicBezierGeometry2D::new(
             [10.0, 50.0], [10.0, 10.0], [90.0, 90.0], [90.0, 50.0], 
             15.0, 20, false
-        ))));
+        ))).unwrap());
     })?;
 
     Ok(())
@@ -49,7 +49,7 @@ where F: Fn(&mut Tessellation<2, Cell2D, Algorithm2DGrid>) {
         generators.push(rng.gen_range(0.0..100.0));
         generators.push(rng.gen_range(0.0..100.0));
     }
-    tess.set_generators(&generators);
+    tess.set_generators(&generators).unwrap();
     
     setup_walls(&mut tess);
     
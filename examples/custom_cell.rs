@@ -0,0 +1,73 @@
+use voronoid::{BoundingBox, Cell, Cell3DFaces, Tessellation, Algorithm3DGrid};
+
+/// A cell that counts the planes that cut it, delegating the geometry to a `Cell3DFaces`.
+#[derive(Clone)]
+struct CountingCell {
+    geometry: Cell3DFaces,
+    cuts: usize,
+}
+
+impl Cell<3> for CountingCell {
+    // Reuse the scratch buffers of the wrapped cell.
+    type Scratch = <Cell3DFaces as Cell<3>>::Scratch;
+
+    fn new(id: usize, bounds: BoundingBox<3>) -> Self {
+        CountingCell { geometry: Cell3DFaces::new(id, bounds), cuts: 0 }
+    }
+
+    fn clip(
+        &mut self,
+        point: &[f64; 3],
+        normal: &[f64; 3],
+        neighbor_id: i32,
+        scratch: &mut Self::Scratch,
+        generator: Option<&[f64; 3]>,
+    ) -> (bool, f64) {
+        let result = Cell::clip(&mut self.geometry, point, normal, neighbor_id, scratch, generator);
+        if result.0 {
+            self.cuts += 1;
+        }
+        result
+    }
+
+    fn max_radius_sq(&self, center: &[f64; 3]) -> f64 {
+        Cell::max_radius_sq(&self.geometry, center)
+    }
+
+    fn centroid(&self) -> [f64; 3] {
+        Cell::centroid(&self.geometry)
+    }
+
+    fn is_empty(&self) -> bool {
+        Cell::is_empty(&self.geometry)
+    }
+
+    fn vertices(&self) -> &[f64] {
+        Cell::vertices(&self.geometry)
+    }
+
+    fn neighbors(&self) -> &[i32] {
+        Cell::neighbors(&self.geometry)
+    }
+
+    fn face_area(&self, face_index: usize) -> f64 {
+        Cell::face_area(&self.geometry, face_index)
+    }
+
+    fn for_each_simplex(&self, f: &mut dyn FnMut(&[[f64; 3]], f64)) {
+        Cell::for_each_simplex(&self.geometry, f)
+    }
+}
+
+fn main() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, CountingCell, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+    tess.random_generators_seeded(1000, 1);
+
+    // `map` computes the custom cells without storing them.
+    let stats = tess.map(|cell| (cell.volume(), cell.cuts));
+    let total_volume: f64 = stats.iter().map(|s| s.0).sum();
+    let mean_cuts = stats.iter().map(|s| s.1).sum::<usize>() as f64 / stats.len() as f64;
+    println!("total volume: {:.6} (box volume: {})", total_volume, bounds.volume());
+    println!("mean number of cuts per cell: {:.2}", mean_cuts);
+}
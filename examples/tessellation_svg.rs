@@ -21,7 +21,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     // Setup the tessellation
     let bounds = BoundingBox::new([0.0, 0.0, 0.0], [100.0, 100.0, 100.0]);
-    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
 
     // Generate random points
     let mut rng = rand::thread_rng();
@@ -32,7 +32,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
         generators.push(rng.gen_range(0.0..100.0));
     }
 
-    tess.set_generators(&generators);
+    tess.set_generators(&generators).unwrap();
     tess.calculate();
 
     // Draw the cells with transparency
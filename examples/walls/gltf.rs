@@ -2,7 +2,7 @@ use std::fs::File;
 use std::io::Write;
 use std::collections::BTreeMap;
 use rand::Rng;
-use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Wall, Cell3DFaces};
+use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Wall, WallMesh, Cell3DFaces};
 use gltf::json;
 use gltf::json::validation::{Checked, USize64};
 use voronoid::wall_3d::{ConeGeometry, TrefoilKnotGeometry, PlaneGeometry, SphereGeometry, CylinderGeometry, TorusGeometry};
@@ -13,6 +13,8 @@ struct GltfBuilder {
     edge_indices: Vec<u32>,
     gen_positions: Vec<[f32; 3]>,
     gen_indices: Vec<u32>,
+    wall_positions: Vec<[f32; 3]>,
+    wall_indices: Vec<u32>,
 }
 
 impl GltfBuilder {
@@ -23,6 +25,8 @@ impl GltfBuilder {
             edge_indices: Vec::new(),
             gen_positions: Vec::new(),
             gen_indices: Vec::new(),
+            wall_positions: Vec::new(),
+            wall_indices: Vec::new(),
         }
     }
 
@@ -87,6 +91,14 @@ impl GltfBuilder {
         }
     }
 
+    fn add_wall_mesh(&mut self, mesh: &WallMesh<3>) {
+        let base = self.wall_positions.len() as u32;
+        for p in mesh.vertices.chunks_exact(3) {
+            self.wall_positions.push([p[0] as f32, p[1] as f32, p[2] as f32]);
+        }
+        self.wall_indices.extend(mesh.elements.iter().flatten().map(|&idx| base + idx as u32));
+    }
+
     fn save(&self, filename: &str) -> Result<(), Box<dyn std::error::Error>> {
         // Prepare buffers
         let mut buffer_data = Vec::new();
@@ -150,6 +162,22 @@ impl GltfBuilder {
         }
         let gen_ind_len = buffer_data.len() - gen_ind_offset;
 
+        // Wall Positions (Vec3 f32), already aligned
+        let wall_pos_offset = buffer_data.len();
+        for p in &self.wall_positions {
+            for c in p {
+                buffer_data.write_all(&c.to_le_bytes())?;
+            }
+        }
+        let wall_pos_len = buffer_data.len() - wall_pos_offset;
+
+        // Wall Indices (Scalar u32)
+        let wall_ind_offset = buffer_data.len();
+        for i in &self.wall_indices {
+            buffer_data.write_all(&i.to_le_bytes())?;
+        }
+        let wall_ind_len = buffer_data.len() - wall_ind_offset;
+
         // Min/Max for positions
         let mut min = [f32::MAX; 3];
         let mut max = [f32::MIN; 3];
@@ -170,6 +198,16 @@ impl GltfBuilder {
             }
         }
 
+        // Min/Max for walls
+        let mut wall_min = [f32::MAX; 3];
+        let mut wall_max = [f32::MIN; 3];
+        for p in &self.wall_positions {
+            for i in 0..3 {
+                if p[i] < wall_min[i] { wall_min[i] = p[i]; }
+                if p[i] > wall_max[i] { wall_max[i] = p[i]; }
+            }
+        }
+
         let buffer = json::Buffer {
             byte_length: USize64(buffer_data.len() as u64),
             uri: None,
@@ -233,6 +271,28 @@ impl GltfBuilder {
             extras: Default::default(),
         };
 
+        let buffer_view_wall_pos = json::buffer::View {
+            buffer: json::Index::new(0),
+            byte_length: USize64(wall_pos_len as u64),
+            byte_offset: Some(USize64(wall_pos_offset as u64)),
+            byte_stride: Some(json::buffer::Stride(12)),
+            name: None,
+            target: Some(Checked::Valid(json::buffer::Target::ArrayBuffer)),
+            extensions: Default::default(),
+            extras: Default::default(),
+        };
+
+        let buffer_view_wall_ind = json::buffer::View {
+            buffer: json::Index::new(0),
+            byte_length: USize64(wall_ind_len as u64),
+            byte_offset: Some(USize64(wall_ind_offset as u64)),
+            byte_stride: None,
+            name: None,
+            target: Some(Checked::Valid(json::buffer::Target::ElementArrayBuffer)),
+            extensions: Default::default(),
+            extras: Default::default(),
+        };
+
         let accessor_pos = json::Accessor {
             buffer_view: Some(json::Index::new(0)),
             byte_offset: Some(USize64(0)),
@@ -308,6 +368,36 @@ impl GltfBuilder {
             sparse: None,
         };
 
+        let accessor_wall_pos = json::Accessor {
+            buffer_view: Some(json::Index::new(5)),
+            byte_offset: Some(USize64(0)),
+            count: USize64(self.wall_positions.len() as u64),
+            component_type: Checked::Valid(json::accessor::GenericComponentType(json::accessor::ComponentType::F32)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Checked::Valid(json::accessor::Type::Vec3),
+            min: Some(json::Value::from(Vec::from(wall_min))),
+            max: Some(json::Value::from(Vec::from(wall_max))),
+            name: None,
+            normalized: false,
+            sparse: None,
+        };
+
+        let accessor_wall_ind = json::Accessor {
+            buffer_view: Some(json::Index::new(6)),
+            byte_offset: Some(USize64(0)),
+            count: USize64(self.wall_indices.len() as u64),
+            component_type: Checked::Valid(json::accessor::GenericComponentType(json::accessor::ComponentType::U32)),
+            extensions: Default::default(),
+            extras: Default::default(),
+            type_: Checked::Valid(json::accessor::Type::Scalar),
+            min: None,
+            max: None,
+            name: None,
+            normalized: false,
+            sparse: None,
+        };
+
         let material = json::Material {
             alpha_cutoff: None,
             alpha_mode: Checked::Valid(json::material::AlphaMode::Blend),
@@ -340,6 +430,17 @@ impl GltfBuilder {
             ..Default::default()
         };
 
+        let material_walls = json::Material {
+            alpha_mode: Checked::Valid(json::material::AlphaMode::Blend),
+            double_sided: true,
+            pbr_metallic_roughness: json::material::PbrMetallicRoughness {
+                base_color_factor: json::material::PbrBaseColorFactor([0.5, 0.5, 0.5, 0.2]),
+                ..Default::default()
+            },
+            name: Some("TransparentGrayWalls".to_string()),
+            ..Default::default()
+        };
+
         let primitive = json::mesh::Primitive {
             attributes: {
                 let mut map = BTreeMap::new();
@@ -382,11 +483,35 @@ impl GltfBuilder {
             targets: None,
         };
 
+        let primitive_walls = json::mesh::Primitive {
+            attributes: {
+                let mut map = BTreeMap::new();
+                map.insert(Checked::Valid(json::mesh::Semantic::Positions), json::Index::new(5));
+                map
+            },
+            extensions: Default::default(),
+            extras: Default::default(),
+            indices: Some(json::Index::new(6)),
+            material: Some(json::Index::new(3)),
+            mode: Checked::Valid(json::mesh::Mode::Triangles),
+            targets: None,
+        };
+
+        // Accessors must not be empty, so the walls are only added if they have triangles
+        let mut primitives = vec![primitive, primitive_edges, primitive_points];
+        let mut accessors = vec![accessor_pos, accessor_ind, accessor_edge_ind, accessor_gen_pos, accessor_gen_ind];
+        let mut buffer_views = vec![buffer_view_pos, buffer_view_ind, buffer_view_edge_ind, buffer_view_gen_pos, buffer_view_gen_ind];
+        if !self.wall_indices.is_empty() {
+            primitives.push(primitive_walls);
+            accessors.extend([accessor_wall_pos, accessor_wall_ind]);
+            buffer_views.extend([buffer_view_wall_pos, buffer_view_wall_ind]);
+        }
+
         let mesh = json::Mesh {
             extensions: Default::default(),
             extras: Default::default(),
             name: None,
-            primitives: vec![primitive, primitive_edges, primitive_points],
+            primitives,
             weights: None,
         };
 
@@ -406,7 +531,7 @@ impl GltfBuilder {
         };
 
         let root = json::Root {
-            accessors: vec![accessor_pos, accessor_ind, accessor_edge_ind, accessor_gen_pos, accessor_gen_ind],
+            accessors,
             animations: vec![],
             asset: json::Asset {
                 generator: Some("voronoid example".to_string()),
@@ -414,14 +539,14 @@ impl GltfBuilder {
                 ..Default::default()
             },
             buffers: vec![buffer],
-            buffer_views: vec![buffer_view_pos, buffer_view_ind, buffer_view_edge_ind, buffer_view_gen_pos, buffer_view_gen_ind],
+            buffer_views,
             cameras: vec![],
             extensions: Default::default(),
             extensions_used: vec![],
             extensions_required: vec![],
             extras: Default::default(),
             images: vec![],
-            materials: vec![material, material_edges, material_points],
+            materials: vec![material, material_edges, material_points, material_walls],
             meshes: vec![mesh],
             nodes: vec![node],
             samplers: vec![],
@@ -480,6 +605,9 @@ fn generate_gltf(
         }
     }
     builder.add_generators(generators);
+    for wall in &tess.walls {
+        builder.add_wall_mesh(&wall.preview_mesh(&tess.bounds, 48));
+    }
 
     builder.save(filename)?;
     println!("Output saved to {}", filename);
@@ -501,67 +629,67 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
 
     // Run 1: Plane Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
 
-        tess.add_wall(Wall::new(
+        tess.add_wall(Wall::try_new(
             -10,
             Box::new(PlaneGeometry::new([40.0, 40.0, 40.0], [1.0, 1.0, 1.0]))
-        ));
+        )?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_plane.glb")?;
     }
 
     // Run 2: Sphere Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-11, Box::new(SphereGeometry::new([50.0, 50.0, 50.0], 40.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-11, Box::new(SphereGeometry::new([50.0, 50.0, 50.0], 40.0)))?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_sphere.glb")?;
     }
     
     // Run 3: Cylinder Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-12, Box::new(CylinderGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 40.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-12, Box::new(CylinderGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 40.0)))?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_cylinder.glb")?;
     }
 
     // Run 4: Torus Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-13, Box::new(TorusGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 35.0, 10.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-13, Box::new(TorusGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 35.0, 10.0)))?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_torus.glb")?;
     }
 
     // Run 5: Cone Wall (Custom)
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-14, Box::new(ConeGeometry::new(
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-14, Box::new(ConeGeometry::new(
             [50.0, 50.0, 10.0],
             [0.0, 0.0, 1.0],
             30.0f64.to_radians(),
-        ))));
+        )))?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_cone.glb")?;
     }
 
     // Run 6: Trefoil Knot Wall (Custom)
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-15, Box::new(TrefoilKnotGeometry::new(
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-15, Box::new(TrefoilKnotGeometry::new(
             [50.0, 50.0, 50.0],
             12.0,
             8.0,
             200
-        ))));
+        )))?);
         tess.calculate();
         generate_gltf(&tess, &generators, "wall_knot.glb")?;
     }
@@ -62,54 +62,54 @@ pub fn run() -> Result<(), Box<dyn std::error::Error>> {
 
     // Run 1: Plane Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
 
-        tess.add_wall(Wall::new(
+        tess.add_wall(Wall::try_new(
             -10,
             Box::new(PlaneGeometry::new([40.0, 40.0, 40.0], [1.0, 1.0, 1.0]))
-        ));
+        )?);
         tess.calculate();
         draw_tessellation(&tess, &generators, "wall_plane.svg")?;
     }
 
     // Run 2: Sphere Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-11, Box::new(SphereGeometry::new([50.0, 50.0, 50.0], 40.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-11, Box::new(SphereGeometry::new([50.0, 50.0, 50.0], 40.0)))?);
         tess.calculate();
         draw_tessellation(&tess, &generators, "wall_sphere.svg")?;
     }
     
     // Run 3: Cylinder Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-12, Box::new(CylinderGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 40.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-12, Box::new(CylinderGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 40.0)))?);
         tess.calculate();
         draw_tessellation(&tess, &generators, "wall_cylinder.svg")?;
     }
 
     // Run 4: Torus Wall
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-13, Box::new(TorusGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 35.0, 10.0))));
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-13, Box::new(TorusGeometry::new([50.0, 50.0, 50.0], [0.0, 0.0, 1.0], 35.0, 10.0)))?);
         tess.calculate();
         draw_tessellation(&tess, &generators, "wall_torus.svg")?;
     }
 
     // Run 6: Trefoil Knot Wall (Custom)
     {
-        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds.clone(), Algorithm3DGrid::new(10, 10, 10, &bounds));
-        tess.set_generators(&generators);
-        tess.add_wall(Wall::new(-15, Box::new(TrefoilKnotGeometry::new(
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+        tess.set_generators(&generators).unwrap();
+        tess.add_wall(Wall::try_new(-15, Box::new(TrefoilKnotGeometry::new(
             [50.0, 50.0, 50.0],
             12.0,
             8.0,
             200
-        ))));
+        )))?);
         tess.calculate();
         draw_tessellation(&tess, &generators, "wall_knot.svg")?;
     }
@@ -1,5 +1,6 @@
 use crate::bounds::BoundingBox;
-use crate::algorithm::SpatialAlgorithm;
+use crate::trace::{Counter, LocalCounter};
+use crate::algorithm::{SpatialAlgorithm, auto_resolution};
 
 /// A spatial index based on a uniform grid for 2D space.
 ///
@@ -72,6 +73,29 @@ impl Algorithm2DGrid {
         }
     }
 
+    /// Creates a new `Algorithm2DGrid` with a resolution chosen for `count` generators in `bounds`,
+    /// accounting for the aspect ratio of the bounds, see [`auto_resolution`].
+    pub fn auto(count: usize, bounds: &BoundingBox<2>) -> Self {
+        let [nx, ny] = auto_resolution(count, bounds);
+        Self::new(nx, ny, bounds)
+    }
+
+    /// Returns the number of bins along each axis.
+    pub fn resolution(&self) -> [usize; 2] {
+        [self.grid_res_x, self.grid_res_y]
+    }
+
+    /// Returns the linear index of the bin holding a generator, or `None` if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> {
+        self.generator_bin_ids.get(index).copied()
+    }
+
+    /// Returns the number of generators in each bin, in the order of the linear bin indices,
+    /// to find overfull bins and empty regions.
+    pub fn bin_occupancy(&self) -> Vec<usize> {
+        self.grid_bins.iter().map(Vec::len).collect()
+    }
+
     /// Calculates the linear index of the bin corresponding to the given coordinates.
     pub fn get_bin_index(&self, x: f64, y: f64, bounds: &BoundingBox<2>) -> usize {
         let nx = self.grid_res_x;
@@ -123,11 +147,30 @@ impl SpatialAlgorithm<2> for Algorithm2DGrid {
         index: usize,
         pos: [f64; 2],
         max_dist_sq: &mut f64,
-        mut visitor: F,
+        visitor: F,
     ) where
         F: FnMut(usize, [f64; 2], f64) -> f64,
     {
-        let bin_idx = self.generator_bin_ids[index];
+        self.visit_from_bin(generators, index, self.generator_bin_ids[index], pos, max_dist_sq, visitor);
+    }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 2], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 2], f64) -> f64,
+    {
+        let ix = ((pos[0] - self.min_x) * self.grid_scale_x).clamp(0.0, self.grid_limit_x) as usize;
+        let iy = ((pos[1] - self.min_y) * self.grid_scale_y).clamp(0.0, self.grid_limit_y) as usize;
+        self.visit_from_bin(generators, usize::MAX, ix + iy * self.grid_res_x, pos, max_dist_sq, visitor);
+    }
+}
+
+impl Algorithm2DGrid {
+    /// Visits the bins in order of distance from the bin `bin_idx` containing `pos`, or the
+    /// bin closest to it if it is outside the grid, skipping the generator `index`.
+    fn visit_from_bin<F>(&self, generators: &[f64], index: usize, bin_idx: usize, pos: [f64; 2], max_dist_sq: &mut f64, mut visitor: F)
+    where
+        F: FnMut(usize, [f64; 2], f64) -> f64,
+    {
         let idx_y = bin_idx / self.grid_res_x;
         let idx_x = bin_idx % self.grid_res_x;
 
@@ -137,6 +180,7 @@ impl SpatialAlgorithm<2> for Algorithm2DGrid {
         let rel_x = (pos[0] - self.min_x) * self.grid_scale_x - idx_x as f64;
         let rel_y = (pos[1] - self.min_y) * self.grid_scale_y - idx_y as f64;
 
+        let mut bins_visited = LocalCounter::new(Counter::BinsVisited);
         for &(dx, dy, min_d2) in &self.bin_search_order {
             if min_d2 > 4.0 * *max_dist_sq {
                 break;
@@ -155,6 +199,7 @@ impl SpatialAlgorithm<2> for Algorithm2DGrid {
                 let dy_bin = dy_dist.max(0.0);
                 
                 if dx_bin * dx_bin + dy_bin * dy_bin <= 4.0 * *max_dist_sq {
+                    bins_visited.inc();
                     let bin_index = (bx as usize) + (by as usize) * self.grid_res_x;
                     for &j in &self.grid_bins[bin_index] {
                         if index == j { continue; }
@@ -204,4 +249,18 @@ mod tests {
         });
         assert!(neighbors.contains(&1));
     }
+
+    #[test]
+    fn test_grid_bins_2d() {
+        let bounds = BoundingBox::new([0.0, 0.0], [2.0, 2.0]);
+        let mut grid = Algorithm2DGrid::new(2, 2, &bounds);
+        grid.set_generators(&[0.5, 0.5, 1.5, 0.5, 1.6, 0.4], &bounds);
+        assert_eq!(grid.bin_of_generator(1), Some(1));
+        assert_eq!(grid.bin_of_generator(3), None);
+        assert_eq!(grid.bin_occupancy(), vec![1, 2, 0, 0]);
+
+        grid.update_generator(2, &[1.6, 0.4], &[0.5, 1.5], &bounds);
+        assert_eq!(grid.bin_of_generator(2), Some(2));
+        assert_eq!(grid.bin_occupancy(), vec![1, 1, 1, 0]);
+    }
 }
\ No newline at end of file
@@ -0,0 +1,263 @@
+use crate::bounds::BoundingBox;
+use crate::trace::{Counter, LocalCounter};
+use crate::algorithm::{SpatialAlgorithm, auto_resolution};
+
+/// A spatial index based on a rectilinear grid with density-adapted bin sizes.
+///
+/// Like [`Algorithm3DGrid`](crate::Algorithm3DGrid) it divides the space into `nx * ny * nz` bins,
+/// but the bin edges along each axis are placed at the quantiles of the generator coordinates
+/// when the generators are set. Dense regions therefore get narrow bins and sparse regions wide
+/// bins, which keeps the number of generators per bin balanced for clustered distributions,
+/// where a uniform grid degrades to scanning a few overfull bins.
+pub struct AlgorithmAdaptiveGrid {
+    /// Number of bins along each axis.
+    pub grid_res: [usize; 3],
+    /// The `grid_res[k] + 1` increasing bin edges along each axis, spanning the bounds.
+    pub edges: [Vec<f64>; 3],
+    /// The grid bins, each containing a list of generator indices.
+    pub grid_bins: Vec<Vec<usize>>,
+    /// Map from generator index to its current bin index.
+    pub generator_bin_ids: Vec<usize>,
+}
+
+impl AlgorithmAdaptiveGrid {
+    /// Creates a new `AlgorithmAdaptiveGrid` with the specified resolution and bounds.
+    ///
+    /// The bins are uniform until generators are set, after which the bin edges follow
+    /// the distribution of the generators along each axis.
+    pub fn new(nx: usize, ny: usize, nz: usize, bounds: &BoundingBox<3>) -> Self {
+        let grid_res = [nx.max(1), ny.max(1), nz.max(1)];
+        let edges = std::array::from_fn(|k| {
+            let n = grid_res[k];
+            let (min, max) = (bounds.min[k], bounds.max[k]);
+            (0..=n).map(|i| min + (max - min) * i as f64 / n as f64).collect()
+        });
+        AlgorithmAdaptiveGrid {
+            grid_res,
+            edges,
+            grid_bins: vec![Vec::new(); grid_res[0] * grid_res[1] * grid_res[2]],
+            generator_bin_ids: Vec::new(),
+        }
+    }
+
+    /// Creates a new `AlgorithmAdaptiveGrid` with a resolution chosen for `count` generators in
+    /// `bounds`, accounting for the aspect ratio of the bounds, see [`auto_resolution`].
+    pub fn auto(count: usize, bounds: &BoundingBox<3>) -> Self {
+        let [nx, ny, nz] = auto_resolution(count, bounds);
+        Self::new(nx, ny, nz, bounds)
+    }
+
+    /// Returns the number of bins along each axis.
+    pub fn resolution(&self) -> [usize; 3] {
+        self.grid_res
+    }
+
+    /// Returns the linear index of the bin holding a generator, or `None` if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> {
+        self.generator_bin_ids.get(index).copied()
+    }
+
+    /// Returns the number of generators in each bin, in the order of the linear bin indices,
+    /// to find overfull bins and empty regions.
+    pub fn bin_occupancy(&self) -> Vec<usize> {
+        self.grid_bins.iter().map(Vec::len).collect()
+    }
+
+    /// Places the bin edges along each axis at the quantiles of the generator coordinates.
+    fn adapt_edges(&mut self, generators: &[f64], bounds: &BoundingBox<3>) {
+        let count = generators.len() / 3;
+        if count == 0 {
+            return;
+        }
+        let mut coords = Vec::with_capacity(count);
+        for k in 0..3 {
+            coords.clear();
+            coords.extend(generators.iter().skip(k).step_by(3).copied());
+            coords.sort_unstable_by(|a, b| a.total_cmp(b));
+
+            let n = self.grid_res[k];
+            let edges = &mut self.edges[k];
+            edges.clear();
+            edges.push(bounds.min[k]);
+            for i in 1..n {
+                // The midpoint between two consecutive coordinates at the quantile, which
+                // keeps generators away from the bin edges.
+                let q = i * count / n;
+                let lo = coords[q.saturating_sub(1)];
+                let hi = coords[q.min(count - 1)];
+                let edge = (0.5 * (lo + hi)).clamp(bounds.min[k], bounds.max[k]);
+                edges.push(edge.max(*edges.last().unwrap_or(&edge)));
+            }
+            edges.push(bounds.max[k]);
+        }
+    }
+
+    /// Calculates the bin coordinate along `axis` for the given coordinate.
+    fn axis_index(&self, axis: usize, x: f64) -> usize {
+        let edges = &self.edges[axis];
+        edges[1..self.grid_res[axis]].partition_point(|&e| e <= x)
+    }
+
+    /// Calculates the linear index of the bin corresponding to the given coordinates.
+    pub fn get_bin_index(&self, x: f64, y: f64, z: f64) -> usize {
+        let [nx, ny, _] = self.grid_res;
+        self.axis_index(0, x) + self.axis_index(1, y) * nx + self.axis_index(2, z) * nx * ny
+    }
+
+    /// The squared distance from `pos` to the bin with the given bin coordinates.
+    fn bin_dist_sq(&self, pos: &[f64; 3], bin: [usize; 3]) -> f64 {
+        let mut d2 = 0.0;
+        for k in 0..3 {
+            let lo = self.edges[k][bin[k]];
+            let hi = self.edges[k][bin[k] + 1];
+            let d = (lo - pos[k]).max(pos[k] - hi).max(0.0);
+            d2 += d * d;
+        }
+        d2
+    }
+
+    /// A lower bound for the distance from `pos` to any bin at Chebyshev distance `ring`
+    /// from the bin `home`, or `None` if there are no such bins.
+    fn ring_dist(&self, pos: &[f64; 3], home: [usize; 3], ring: usize) -> Option<f64> {
+        let mut dist = f64::INFINITY;
+        for k in 0..3 {
+            if home[k] >= ring {
+                dist = dist.min(pos[k] - self.edges[k][home[k] + 1 - ring]);
+            }
+            if home[k] + ring < self.grid_res[k] {
+                dist = dist.min(self.edges[k][home[k] + ring] - pos[k]);
+            }
+        }
+        dist.is_finite().then_some(dist.max(0.0))
+    }
+}
+
+impl SpatialAlgorithm<3> for AlgorithmAdaptiveGrid {
+    fn set_generators(&mut self, generators: &[f64], bounds: &BoundingBox<3>) {
+        self.adapt_edges(generators, bounds);
+        self.grid_bins.iter_mut().for_each(|bin| bin.clear());
+
+        let count = generators.len() / 3;
+        self.generator_bin_ids = vec![0; count];
+
+        for i in 0..count {
+            let bin_idx = self.get_bin_index(generators[i * 3], generators[i * 3 + 1], generators[i * 3 + 2]);
+            self.grid_bins[bin_idx].push(i);
+            self.generator_bin_ids[i] = bin_idx;
+        }
+    }
+
+    fn update_generator(&mut self, index: usize, _old_pos: &[f64; 3], new_pos: &[f64; 3], _bounds: &BoundingBox<3>) {
+        let new_bin_idx = self.get_bin_index(new_pos[0], new_pos[1], new_pos[2]);
+        let old_bin_idx = self.generator_bin_ids[index];
+
+        if new_bin_idx != old_bin_idx {
+            if let Some(pos) = self.grid_bins[old_bin_idx].iter().position(|&id| id == index) {
+                self.grid_bins[old_bin_idx].swap_remove(pos);
+            }
+            self.grid_bins[new_bin_idx].push(index);
+            self.generator_bin_ids[index] = new_bin_idx;
+        }
+    }
+
+    fn visit_neighbors<F>(
+        &self,
+        generators: &[f64],
+        index: usize,
+        pos: [f64; 3],
+        max_dist_sq: &mut f64,
+        visitor: F,
+    ) where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        self.visit_from_bin(generators, index, self.generator_bin_ids[index], pos, max_dist_sq, visitor);
+    }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        let bin_idx = self.get_bin_index(pos[0], pos[1], pos[2]);
+        self.visit_from_bin(generators, usize::MAX, bin_idx, pos, max_dist_sq, visitor);
+    }
+}
+
+impl AlgorithmAdaptiveGrid {
+    /// Visits the bins in rings around the bin `bin_idx` containing `pos`, or the bin closest
+    /// to it if it is outside the grid, skipping the generator `index`.
+    fn visit_from_bin<F>(&self, generators: &[f64], index: usize, bin_idx: usize, pos: [f64; 3], max_dist_sq: &mut f64, mut visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        let [nx, ny, nz] = self.grid_res;
+        let home = [bin_idx % nx, (bin_idx / nx) % ny, bin_idx / (nx * ny)];
+
+        // Visit the bins in rings of increasing Chebyshev distance around the home bin,
+        // until the ring lies entirely beyond the search radius.
+        let mut bins_visited = LocalCounter::new(Counter::BinsVisited);
+        let mut ring = 0;
+        loop {
+            if ring > 0 {
+                match self.ring_dist(&pos, home, ring) {
+                    Some(d) if d * d <= 4.0 * *max_dist_sq => {}
+                    _ => break,
+                }
+            }
+
+            let r = ring as isize;
+            let range = |k: usize| {
+                let lo = (home[k] as isize - r).max(0) as usize;
+                let hi = (home[k] + ring).min(self.grid_res[k] - 1);
+                lo..=hi
+            };
+            for bz in range(2) {
+                let on_z = bz.abs_diff(home[2]) == ring;
+                for by in range(1) {
+                    let on_y = on_z || by.abs_diff(home[1]) == ring;
+                    for bx in range(0) {
+                        if !on_y && bx.abs_diff(home[0]) != ring {
+                            continue;
+                        }
+                        if self.bin_dist_sq(&pos, [bx, by, bz]) > 4.0 * *max_dist_sq {
+                            continue;
+                        }
+                        bins_visited.inc();
+                        for &j in &self.grid_bins[bx + by * nx + bz * nx * ny] {
+                            if index == j { continue; }
+                            let ox = generators[j * 3];
+                            let oy = generators[j * 3 + 1];
+                            let oz = generators[j * 3 + 2];
+                            *max_dist_sq = visitor(j, [ox, oy, oz], *max_dist_sq);
+                        }
+                    }
+                }
+            }
+            ring += 1;
+            if ring >= nx.max(ny).max(nz) {
+                break;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adaptive_grid_edges() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+        let mut grid = AlgorithmAdaptiveGrid::new(4, 4, 4, &bounds);
+        assert_eq!(grid.edges[0], vec![0.0, 2.5, 5.0, 7.5, 10.0]);
+
+        // All generators in the lower corner: the edges concentrate there.
+        let generators: Vec<f64> = (0..64).flat_map(|i| {
+            let t = 0.01 * i as f64;
+            [t, t, t]
+        }).collect();
+        grid.set_generators(&generators, &bounds);
+        assert!(grid.edges[0][3] < 1.0);
+        assert_eq!(grid.edges[0][4], 10.0);
+        assert!(grid.grid_bins.iter().all(|bin| bin.len() <= 16));
+    }
+}
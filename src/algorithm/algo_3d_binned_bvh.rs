@@ -0,0 +1,343 @@
+use crate::bounds::BoundingBox;
+use crate::trace::{Counter, LocalCounter};
+use crate::algorithm::{SpatialAlgorithm, auto_resolution};
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+
+/// The maximum number of bins in a leaf of the hierarchy.
+const LEAF_BINS: usize = 4;
+
+/// A node of the bounding volume hierarchy of an [`AlgorithmBinnedBvh`].
+#[derive(Clone, Debug)]
+pub struct BvhNode {
+    /// The minimum corner of the union of the bins below the node.
+    pub min: [f64; 3],
+    /// The maximum corner of the union of the bins below the node.
+    pub max: [f64; 3],
+    /// The indices of the two child nodes, or `None` for a leaf.
+    pub children: Option<[usize; 2]>,
+    /// The range of `leaf_bins` below the node.
+    pub bins: std::ops::Range<usize>,
+}
+
+/// A spatial index that stores the generators in uniform grid bins, like
+/// [`Algorithm3DGrid`](crate::Algorithm3DGrid), but searches them through a coarse bounding volume
+/// hierarchy over the occupied bins only.
+///
+/// The grid walks all bins in order of distance until the search radius is exhausted, including
+/// the empty ones, which dominates for the large cells at the edge of clustered data such as the
+/// central box distribution. The hierarchy skips empty regions as a whole, while insertion and
+/// updates remain O(1) bin operations: the nodes bound whole bins, so moving a generator within
+/// or into an occupied bin keeps them valid, and bins that become occupied after the hierarchy
+/// was built are kept in a short list until it is rebuilt.
+pub struct AlgorithmBinnedBvh {
+    /// Number of bins along each axis.
+    pub grid_res: [usize; 3],
+    /// The minimum corner of the grid bounds.
+    pub min: [f64; 3],
+    /// The size of a bin along each axis.
+    pub bin_size: [f64; 3],
+    /// The grid bins, each containing a list of generator indices.
+    pub grid_bins: Vec<Vec<usize>>,
+    /// Map from generator index to its current bin index.
+    pub generator_bin_ids: Vec<usize>,
+    /// The nodes of the hierarchy, with the root first, or none if no bin is occupied.
+    pub nodes: Vec<BvhNode>,
+    /// The bins in the hierarchy, ordered so that the bins of each node are contiguous.
+    pub leaf_bins: Vec<usize>,
+    /// The bins that became occupied after the hierarchy was built, searched linearly.
+    pub pending_bins: Vec<usize>,
+    /// Whether each bin is part of the hierarchy or of `pending_bins`.
+    indexed: Vec<bool>,
+}
+
+impl AlgorithmBinnedBvh {
+    /// Creates a new `AlgorithmBinnedBvh` with the specified grid resolution and bounds.
+    pub fn new(nx: usize, ny: usize, nz: usize, bounds: &BoundingBox<3>) -> Self {
+        let grid_res = [nx.max(1), ny.max(1), nz.max(1)];
+        let total = grid_res[0] * grid_res[1] * grid_res[2];
+        AlgorithmBinnedBvh {
+            grid_res,
+            min: bounds.min,
+            bin_size: std::array::from_fn(|k| (bounds.max[k] - bounds.min[k]) / grid_res[k] as f64),
+            grid_bins: vec![Vec::new(); total],
+            generator_bin_ids: Vec::new(),
+            nodes: Vec::new(),
+            leaf_bins: Vec::new(),
+            pending_bins: Vec::new(),
+            indexed: vec![false; total],
+        }
+    }
+
+    /// Creates a new `AlgorithmBinnedBvh` with a resolution chosen for `count` generators in
+    /// `bounds`, accounting for the aspect ratio of the bounds, see [`auto_resolution`].
+    pub fn auto(count: usize, bounds: &BoundingBox<3>) -> Self {
+        let [nx, ny, nz] = auto_resolution(count, bounds);
+        Self::new(nx, ny, nz, bounds)
+    }
+
+    /// Returns the number of bins along each axis.
+    pub fn resolution(&self) -> [usize; 3] {
+        self.grid_res
+    }
+
+    /// Returns the linear index of the bin holding a generator, or `None` if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> {
+        self.generator_bin_ids.get(index).copied()
+    }
+
+    /// Returns the number of generators in each bin, in the order of the linear bin indices,
+    /// to find overfull bins and empty regions.
+    pub fn bin_occupancy(&self) -> Vec<usize> {
+        self.grid_bins.iter().map(Vec::len).collect()
+    }
+
+    /// Calculates the linear index of the bin containing a position, clamped to the grid.
+    pub fn get_bin_index(&self, pos: &[f64; 3]) -> usize {
+        let [nx, ny, _] = self.grid_res;
+        let i: [usize; 3] = std::array::from_fn(|k| {
+            ((pos[k] - self.min[k]) / self.bin_size[k]).clamp(0.0, self.grid_res[k] as f64 - 1e-5) as usize
+        });
+        i[0] + i[1] * nx + i[2] * nx * ny
+    }
+
+    /// Rebuilds the hierarchy over all occupied bins, which also absorbs the pending bins.
+    pub fn rebuild_hierarchy(&mut self) {
+        self.nodes.clear();
+        self.pending_bins.clear();
+        self.leaf_bins = (0..self.grid_bins.len()).filter(|&b| !self.grid_bins[b].is_empty()).collect();
+        self.indexed.iter_mut().for_each(|i| *i = false);
+        for &b in &self.leaf_bins {
+            self.indexed[b] = true;
+        }
+        if !self.leaf_bins.is_empty() {
+            self.build_node(0, self.leaf_bins.len());
+        }
+    }
+
+    /// Returns the grid coordinates of a bin.
+    fn bin_coords(&self, bin: usize) -> [usize; 3] {
+        let [nx, ny, _] = self.grid_res;
+        [bin % nx, (bin / nx) % ny, bin / (nx * ny)]
+    }
+
+    /// Returns the minimum and maximum corner of a bin.
+    fn bin_box(&self, bin: usize) -> ([f64; 3], [f64; 3]) {
+        let c = self.bin_coords(bin);
+        let min = std::array::from_fn(|k| self.min[k] + c[k] as f64 * self.bin_size[k]);
+        let max = std::array::from_fn(|k| self.min[k] + (c[k] + 1) as f64 * self.bin_size[k]);
+        (min, max)
+    }
+
+    /// Builds the node over `leaf_bins[start..end]` by splitting at the median bin along the
+    /// longest axis, and returns its index.
+    fn build_node(&mut self, start: usize, end: usize) -> usize {
+        let mut min = [f64::INFINITY; 3];
+        let mut max = [f64::NEG_INFINITY; 3];
+        for &b in &self.leaf_bins[start..end] {
+            let (lo, hi) = self.bin_box(b);
+            for k in 0..3 {
+                min[k] = min[k].min(lo[k]);
+                max[k] = max[k].max(hi[k]);
+            }
+        }
+        let node = self.nodes.len();
+        self.nodes.push(BvhNode { min, max, children: None, bins: start..end });
+        if end - start <= LEAF_BINS {
+            return node;
+        }
+
+        let axis = (0..3).max_by(|&a, &b| (max[a] - min[a]).total_cmp(&(max[b] - min[b]))).unwrap_or(0);
+        let [nx, ny, _] = self.grid_res;
+        let coord = |bin: &usize| [bin % nx, (bin / nx) % ny, bin / (nx * ny)][axis];
+        let mid = (start + end) / 2;
+        self.leaf_bins[start..end].select_nth_unstable_by_key(mid - start, coord);
+        let left = self.build_node(start, mid);
+        let right = self.build_node(mid, end);
+        self.nodes[node].children = Some([left, right]);
+        node
+    }
+
+    /// Visits the occupied bins through the hierarchy in order of increasing distance, like the
+    /// bin search order of the grid, and stops at the first node or bin beyond the search radius.
+    /// The generator `index` is skipped.
+    fn visit<F>(&self, generators: &[f64], index: usize, pos: [f64; 3], max_dist_sq: &mut f64, mut visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        let dist_sq = |(min, max): ([f64; 3], [f64; 3])| -> f64 {
+            (0..3).map(|k| (min[k] - pos[k]).max(pos[k] - max[k]).max(0.0).powi(2)).sum()
+        };
+        let node_bounds = |node: &BvhNode| (node.min, node.max);
+
+        let mut queue = BinaryHeap::new();
+        if let Some(root) = self.nodes.first() {
+            queue.push(Entry { dist_sq: dist_sq(node_bounds(root)), item: Item::Node(0) });
+        }
+        for &bin in &self.pending_bins {
+            queue.push(Entry { dist_sq: dist_sq(self.bin_box(bin)), item: Item::Bin(bin) });
+        }
+
+        let mut bins_visited = LocalCounter::new(Counter::BinsVisited);
+        while let Some(entry) = queue.pop() {
+            if entry.dist_sq > 4.0 * *max_dist_sq {
+                break;
+            }
+            match entry.item {
+                Item::Bin(bin) => {
+                    bins_visited.inc();
+                    for &j in &self.grid_bins[bin] {
+                        if j == index {
+                            continue;
+                        }
+                        let p = [generators[j * 3], generators[j * 3 + 1], generators[j * 3 + 2]];
+                        *max_dist_sq = visitor(j, p, *max_dist_sq);
+                    }
+                }
+                Item::Node(node) => match self.nodes[node].children {
+                    Some(children) => {
+                        for child in children {
+                            queue.push(Entry { dist_sq: dist_sq(node_bounds(&self.nodes[child])), item: Item::Node(child) });
+                        }
+                    }
+                    None => {
+                        for &bin in &self.leaf_bins[self.nodes[node].bins.clone()] {
+                            queue.push(Entry { dist_sq: dist_sq(self.bin_box(bin)), item: Item::Bin(bin) });
+                        }
+                    }
+                },
+            }
+        }
+    }
+}
+
+/// A node or bin in the queue of the search, ordered so that the nearest is popped first.
+struct Entry {
+    dist_sq: f64,
+    item: Item,
+}
+
+enum Item {
+    Node(usize),
+    Bin(usize),
+}
+
+impl PartialEq for Entry {
+    fn eq(&self, other: &Self) -> bool {
+        self.dist_sq.total_cmp(&other.dist_sq).is_eq()
+    }
+}
+
+impl Eq for Entry {}
+
+impl PartialOrd for Entry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Entry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        other.dist_sq.total_cmp(&self.dist_sq)
+    }
+}
+
+impl SpatialAlgorithm<3> for AlgorithmBinnedBvh {
+    fn set_generators(&mut self, generators: &[f64], _bounds: &BoundingBox<3>) {
+        self.grid_bins.iter_mut().for_each(|bin| bin.clear());
+        let count = generators.len() / 3;
+        self.generator_bin_ids = vec![0; count];
+        for i in 0..count {
+            let bin = self.get_bin_index(&[generators[i * 3], generators[i * 3 + 1], generators[i * 3 + 2]]);
+            self.grid_bins[bin].push(i);
+            self.generator_bin_ids[i] = bin;
+        }
+        self.rebuild_hierarchy();
+    }
+
+    fn update_generator(&mut self, index: usize, _old_pos: &[f64; 3], new_pos: &[f64; 3], _bounds: &BoundingBox<3>) {
+        let new_bin = self.get_bin_index(new_pos);
+        let old_bin = self.generator_bin_ids[index];
+        if new_bin == old_bin {
+            return;
+        }
+        if let Some(pos) = self.grid_bins[old_bin].iter().position(|&id| id == index) {
+            self.grid_bins[old_bin].swap_remove(pos);
+        }
+        self.grid_bins[new_bin].push(index);
+        self.generator_bin_ids[index] = new_bin;
+
+        // Emptied bins stay in the hierarchy, newly occupied ones wait for the next rebuild.
+        if !self.indexed[new_bin] {
+            self.indexed[new_bin] = true;
+            self.pending_bins.push(new_bin);
+            if self.pending_bins.len() > (self.leaf_bins.len() / 8).max(16) {
+                self.rebuild_hierarchy();
+            }
+        }
+    }
+
+    fn visit_neighbors<F>(&self, generators: &[f64], index: usize, pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        self.visit(generators, index, pos, max_dist_sq, visitor);
+    }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        self.visit(generators, usize::MAX, pos, max_dist_sq, visitor);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{Rng, SeedableRng};
+
+    #[test]
+    fn test_bvh_visits_all_within_radius() {
+        let bounds = BoundingBox::new([0.0; 3], [10.0; 3]);
+        let mut rng = rand::rngs::StdRng::seed_from_u64(1);
+        // A dense cluster in a corner and a few scattered generators.
+        let mut generators: Vec<f64> = (0..600).map(|_| rng.gen_range(1.0..2.5)).collect();
+        generators.extend((0..30).map(|_| rng.gen_range(0.0..10.0)));
+        let mut bvh = AlgorithmBinnedBvh::new(8, 8, 8, &bounds);
+        bvh.set_generators(&generators, &bounds);
+
+        let check = |bvh: &AlgorithmBinnedBvh, generators: &[f64]| {
+            for (i, p) in generators.chunks_exact(3).enumerate() {
+                let pos = [p[0], p[1], p[2]];
+                let mut visited = Vec::new();
+                let mut radius = 0.8;
+                bvh.visit_neighbors(generators, i, pos, &mut radius, |j, _, r| {
+                    visited.push(j);
+                    r
+                });
+                for (j, q) in generators.chunks_exact(3).enumerate() {
+                    let d2: f64 = (0..3).map(|k| (q[k] - pos[k]).powi(2)).sum();
+                    if j != i && d2 <= 3.2 {
+                        assert!(visited.contains(&j), "{i} misses {j}");
+                    }
+                }
+                assert!(!visited.contains(&i));
+            }
+        };
+        check(&bvh, &generators);
+
+        // Moving generators into empty bins first queues the bins and then rebuilds the hierarchy.
+        for i in 0..40 {
+            let old = [generators[i * 3], generators[i * 3 + 1], generators[i * 3 + 2]];
+            let new = [rng.gen_range(5.0..10.0), rng.gen_range(5.0..10.0), rng.gen_range(5.0..10.0)];
+            generators[i * 3..i * 3 + 3].copy_from_slice(&new);
+            bvh.update_generator(i, &old, &new, &bounds);
+            if i == 3 {
+                assert!(!bvh.pending_bins.is_empty());
+                check(&bvh, &generators);
+            }
+        }
+        check(&bvh, &generators);
+    }
+}
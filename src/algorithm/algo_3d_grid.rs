@@ -1,5 +1,6 @@
 use crate::bounds::BoundingBox;
-use crate::algorithm::SpatialAlgorithm;
+use crate::trace::{Counter, LocalCounter};
+use crate::algorithm::{SpatialAlgorithm, auto_resolution};
 
 /// A spatial index based on a uniform grid.
 ///
@@ -94,6 +95,29 @@ impl Algorithm3DGrid {
         }
     }
 
+    /// Creates a new `Algorithm3DGrid` with a resolution chosen for `count` generators in `bounds`,
+    /// accounting for the aspect ratio of the bounds, see [`auto_resolution`].
+    pub fn auto(count: usize, bounds: &BoundingBox<3>) -> Self {
+        let [nx, ny, nz] = auto_resolution(count, bounds);
+        Self::new(nx, ny, nz, bounds)
+    }
+
+    /// Returns the number of bins along each axis.
+    pub fn resolution(&self) -> [usize; 3] {
+        [self.grid_res_x, self.grid_res_y, self.grid_res_z]
+    }
+
+    /// Returns the linear index of the bin holding a generator, or `None` if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> {
+        self.generator_bin_ids.get(index).copied()
+    }
+
+    /// Returns the number of generators in each bin, in the order of the linear bin indices,
+    /// to find overfull bins and empty regions.
+    pub fn bin_occupancy(&self) -> Vec<usize> {
+        self.grid_bins.iter().map(Vec::len).collect()
+    }
+
     /// Calculates the linear index of the bin corresponding to the given coordinates.
     pub fn get_bin_index(&self, x: f64, y: f64, z: f64, bounds: &BoundingBox<3>) -> usize {
         let nx = self.grid_res_x;
@@ -148,11 +172,32 @@ impl SpatialAlgorithm<3> for Algorithm3DGrid {
         index: usize,
         pos: [f64; 3],
         max_dist_sq: &mut f64,
-        mut visitor: F,
+        visitor: F,
     ) where
         F: FnMut(usize, [f64; 3], f64) -> f64,
     {
-        let bin_idx = self.generator_bin_ids[index];
+        self.visit_from_bin(generators, index, self.generator_bin_ids[index], pos, max_dist_sq, visitor);
+    }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        let ix = ((pos[0] - self.min_x) * self.grid_scale_x).clamp(0.0, self.grid_limit_x) as usize;
+        let iy = ((pos[1] - self.min_y) * self.grid_scale_y).clamp(0.0, self.grid_limit_y) as usize;
+        let iz = ((pos[2] - self.min_z) * self.grid_scale_z).clamp(0.0, self.grid_limit_z) as usize;
+        let bin_idx = ix + iy * self.grid_res_x + iz * self.grid_res_x * self.grid_res_y;
+        self.visit_from_bin(generators, usize::MAX, bin_idx, pos, max_dist_sq, visitor);
+    }
+}
+
+impl Algorithm3DGrid {
+    /// Visits the bins in order of distance from the bin `bin_idx` containing `pos`, or the
+    /// bin closest to it if it is outside the grid, skipping the generator `index`.
+    fn visit_from_bin<F>(&self, generators: &[f64], index: usize, bin_idx: usize, pos: [f64; 3], max_dist_sq: &mut f64, mut visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
         let idx_z = bin_idx / (self.grid_res_x * self.grid_res_y);
         let rem_z = bin_idx % (self.grid_res_x * self.grid_res_y);
         let idx_y = rem_z / self.grid_res_x;
@@ -166,6 +211,7 @@ impl SpatialAlgorithm<3> for Algorithm3DGrid {
         let rel_y = (pos[1] - self.min_y) * self.grid_scale_y - idx_y as f64;
         let rel_z = (pos[2] - self.min_z) * self.grid_scale_z - idx_z as f64;
 
+        let mut bins_visited = LocalCounter::new(Counter::BinsVisited);
         for &(dx, dy, dz, min_d2) in &self.bin_search_order {
             if min_d2 > 4.0 * *max_dist_sq {
                 break;
@@ -188,6 +234,7 @@ impl SpatialAlgorithm<3> for Algorithm3DGrid {
                 let dz_bin = dz_dist.max(0.0);
                 
                 if dx_bin * dx_bin + dy_bin * dy_bin + dz_bin * dz_bin <= 4.0 * *max_dist_sq {
+                    bins_visited.inc();
                     let bin_index = (bx as usize) + (by as usize) * self.grid_res_x + (bz as usize) * self.grid_res_x * self.grid_res_y;
                     for &j in &self.grid_bins[bin_index] {
                         if index == j { continue; }
@@ -210,6 +210,13 @@ impl SpatialAlgorithm<3> for Algorithm3DOctree {
             *max_dist_sq = visitor(j, [ox, oy, oz], *max_dist_sq);
         }
     }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        self.visit_neighbors(generators, usize::MAX, pos, max_dist_sq, visitor);
+    }
 }
 
 impl Algorithm3DOctree {
@@ -237,14 +244,14 @@ impl<'a> Eq for SearchItem<'a> {}
 
 impl<'a> PartialOrd for SearchItem<'a> {
     fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
-        // Reverse ordering for Min-Heap behavior
-        other.dist_sq.partial_cmp(&self.dist_sq)
+        Some(self.cmp(other))
     }
 }
 
 impl<'a> Ord for SearchItem<'a> {
     fn cmp(&self, other: &Self) -> Ordering {
-        self.partial_cmp(other).unwrap_or(Ordering::Equal)
+        // Reverse ordering for Min-Heap behavior
+        other.dist_sq.partial_cmp(&self.dist_sq).unwrap_or(Ordering::Equal)
     }
 }
 
@@ -1,14 +1,46 @@
 use crate::bounds::BoundingBox;
 
 pub mod algo_2d_grid;
+pub mod algo_3d_adaptive_grid;
+pub mod algo_3d_binned_bvh;
 pub mod algo_3d_grid;
 pub mod algo_3d_octree;
 
+/// Chooses the number of grid bins along each axis for `count` generators in `bounds`.
+///
+/// The bins are close to cubic and hold about one generator on average, which reduces to
+/// `cbrt(count)` bins per axis for a cube. Axes whose extent is smaller than the bin size
+/// get a single bin and the generators are spread over the remaining axes, so thin slabs
+/// are binned like a 2D domain instead of with flat bins.
+pub fn auto_resolution<const D: usize>(count: usize, bounds: &BoundingBox<D>) -> [usize; D] {
+    let extent: [f64; D] = std::array::from_fn(|k| bounds.max[k] - bounds.min[k]);
+    let mut active: [bool; D] = std::array::from_fn(|k| extent[k] > 0.0);
+    loop {
+        let dims = active.iter().filter(|&&a| a).count();
+        if dims == 0 {
+            return [1; D];
+        }
+        let volume: f64 = (0..D).filter(|&k| active[k]).map(|k| extent[k]).product();
+        let size = (volume / count.max(1) as f64).powf(1.0 / dims as f64);
+        let thin: Vec<usize> = (0..D).filter(|&k| active[k] && extent[k] < size).collect();
+        if thin.is_empty() || thin.len() == dims {
+            return std::array::from_fn(|k| if active[k] { ((extent[k] / size).round() as usize).max(1) } else { 1 });
+        }
+        thin.into_iter().for_each(|k| active[k] = false);
+    }
+}
 
 /// Trait defining a spatial acceleration structure.
-/// This allows swapping between Grid, Octree, or Linear Octree algorithms.
+///
+/// This is the extension point for the neighbor search of a [`Tessellation`](crate::Tessellation),
+/// implemented by [`Algorithm2DGrid`](crate::Algorithm2DGrid), [`Algorithm3DGrid`](crate::Algorithm3DGrid),
+/// [`AlgorithmAdaptiveGrid`](crate::AlgorithmAdaptiveGrid), [`Algorithm3DOctree`](crate::Algorithm3DOctree)
+/// and [`AlgorithmBinnedBvh`](crate::AlgorithmBinnedBvh).
+/// User provided implementations can be passed to [`Tessellation::new`](crate::Tessellation::new),
+/// which keeps the index up to date through [`SpatialAlgorithm::set_generators`] and
+/// [`SpatialAlgorithm::update_generator`].
 pub trait SpatialAlgorithm<const D: usize>: Send + Sync {
-    /// Rebuild the index with new generators.
+    /// Rebuild the index with new generators, given as a flat array of `D` coordinates per generator.
     fn set_generators(&mut self, generators: &[f64], bounds: &BoundingBox<D>);
 
     /// Update the position of a single generator.
@@ -16,15 +48,81 @@ pub trait SpatialAlgorithm<const D: usize>: Send + Sync {
 
     /// Visit potential neighbors for a given generator.
     ///
+    /// `max_dist_sq` is the squared radius of the current cell around `pos`: only generators within
+    /// twice that radius, so with a squared distance of at most `4.0 * max_dist_sq`, can cut the cell.
+    /// The visitor returns the updated radius after clipping the cell with a candidate, which never
+    /// increases, and implementations must store it in `max_dist_sq` and may stop the search once no
+    /// unvisited generator lies within `4.0 * max_dist_sq`. All generators within that distance must
+    /// be visited, excluding `index` itself; visiting them in order of increasing distance shrinks
+    /// the cell fastest and so keeps the search short.
+    ///
     /// # Arguments
     /// * `generators` - The full list of generators (needed to retrieve neighbor positions).
     /// * `index` - The index of the generator we are processing.
     /// * `pos` - The position of the generator (array of size D).
     /// * `max_dist_sq` - A mutable reference to the current maximum search radius squared.
-    ///                   The visitor can update this if the cell shrinks.
-    /// * `visitor` - A closure called for each candidate neighbor. It receives the neighbor's index
-    ///               and its position.
+    ///   The visitor can update this if the cell shrinks.
+    /// * `visitor` - A closure called for each candidate neighbor. It receives the neighbor's index,
+    ///   its position and the current `max_dist_sq`, and returns the new `max_dist_sq`.
     fn visit_neighbors<F>(&self, generators: &[f64], index: usize, pos: [f64; D], max_dist_sq: &mut f64, visitor: F)
     where
         F: FnMut(usize, [f64; D], f64) -> f64;
-}
\ No newline at end of file
+
+    /// Visit potential neighbors of an arbitrary point, such as a cursor position, which need not
+    /// be a generator. The contract is that of [`SpatialAlgorithm::visit_neighbors`], except that
+    /// no generator is excluded.
+    ///
+    /// The default implementation visits all generators in the search radius by a linear scan.
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; D], max_dist_sq: &mut f64, mut visitor: F)
+    where
+        F: FnMut(usize, [f64; D], f64) -> f64,
+    {
+        for (j, p) in generators.chunks_exact(D).enumerate() {
+            let p: [f64; D] = std::array::from_fn(|k| p[k]);
+            let d2: f64 = (0..D).map(|k| (p[k] - pos[k]).powi(2)).sum();
+            if d2 <= 4.0 * *max_dist_sq {
+                *max_dist_sq = visitor(j, p, *max_dist_sq);
+            }
+        }
+    }
+
+    /// The number of candidates the tessellation holds back from the visitor of
+    /// [`SpatialAlgorithm::visit_neighbors`] to clip the one with the lowest
+    /// [`SpatialAlgorithm::candidate_priority`] first, see
+    /// [`Tessellation::set_candidate_buffer`](crate::Tessellation::set_candidate_buffer).
+    ///
+    /// The default of 0 clips the candidates in the order they are visited, which suits
+    /// algorithms that already visit them roughly nearest first. Algorithms visiting in bin or
+    /// storage order can return a small buffer, such as 8, so that closer generators shrink the
+    /// cell first and fewer candidates need clipping.
+    fn candidate_buffer(&self) -> usize {
+        0
+    }
+
+    /// The priority of a held back candidate at `candidate` for the cell of the generator at `pos`,
+    /// where candidates with lower priorities are clipped first.
+    ///
+    /// The default is the squared distance, so the nearest held back candidate is clipped first.
+    fn candidate_priority(&self, pos: &[f64; D], candidate: &[f64; D]) -> f64 {
+        (0..D).map(|k| (candidate[k] - pos[k]).powi(2)).sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_auto_resolution() {
+        let cube = BoundingBox::new([0.0; 3], [10.0; 3]);
+        assert_eq!(auto_resolution(1000, &cube), [10, 10, 10]);
+        assert_eq!(auto_resolution(0, &cube), [1, 1, 1]);
+
+        // A thin slab is binned along its two long axes only.
+        let slab = BoundingBox::new([0.0; 3], [1000.0, 1000.0, 1.0]);
+        assert_eq!(auto_resolution(10_000, &slab), [100, 100, 1]);
+
+        let strip = BoundingBox::new([0.0; 2], [100.0, 1.0]);
+        assert_eq!(auto_resolution(10, &strip), [10, 1]);
+    }
+}
@@ -9,6 +9,11 @@ impl<const D: usize> BoundingBox<D> {
     pub fn new(min: [f64; D], max: [f64; D]) -> Self {
         Self { min, max }
     }
+
+    /// Returns the D-dimensional volume of the box (the area in 2D).
+    pub fn volume(&self) -> f64 {
+        (0..D).map(|i| self.max[i] - self.min[i]).product()
+    }
 }
 
 /// Calculates the ID for a bounding box wall based on the axis and direction.
@@ -1,6 +1,8 @@
 use crate::bounds::BoundingBox;
 use crate::bounds::box_side;
-use crate::cell::Cell;
+use crate::cell::{Cell, CLIP_EPSILON};
+#[cfg(feature = "clip-log")]
+use crate::cell::ClipRecord;
 
 /// Scratch buffer to reuse allocations during clipping.
 #[derive(Default, Clone)]
@@ -18,6 +20,9 @@ pub struct Cell2D {
     pub(crate) vertices: Vec<f64>,
     // Neighbor ID for each edge. edge_neighbors[i] corresponds to edge starting at vertices[2*i]
     pub(crate) edge_neighbors: Vec<i32>,
+    // Sequence of clipping planes applied to the cell.
+    #[cfg(feature = "clip-log")]
+    pub(crate) clip_log: Vec<ClipRecord<2>>,
 }
 
 impl Cell2D {
@@ -40,6 +45,8 @@ impl Cell2D {
             id,
             vertices,
             edge_neighbors,
+            #[cfg(feature = "clip-log")]
+            clip_log: Vec::new(),
         }
     }
 
@@ -71,6 +78,50 @@ impl Cell2D {
         (area * 0.5).abs()
     }
 
+    /// Calculates the length of the edge starting at vertex `edge_index`.
+    pub fn edge_length(&self, edge_index: usize) -> f64 {
+        let n = self.vertices.len() / 2;
+        if edge_index >= n { return 0.0; }
+        let j = (edge_index + 1) % n;
+        let dx = self.vertices[j * 2] - self.vertices[edge_index * 2];
+        let dy = self.vertices[j * 2 + 1] - self.vertices[edge_index * 2 + 1];
+        (dx * dx + dy * dy).sqrt()
+    }
+
+    /// Calculates the interior angle (in radians) at each vertex of the polygon.
+    pub fn vertex_angles(&self) -> Vec<f64> {
+        let n = self.vertices.len() / 2;
+        if n < 3 { return Vec::new(); }
+        (0..n)
+            .map(|i| {
+                let p = (i + n - 1) % n;
+                let q = (i + 1) % n;
+                let (ax, ay) = (self.vertices[p * 2] - self.vertices[i * 2], self.vertices[p * 2 + 1] - self.vertices[i * 2 + 1]);
+                let (bx, by) = (self.vertices[q * 2] - self.vertices[i * 2], self.vertices[q * 2 + 1] - self.vertices[i * 2 + 1]);
+                (ax * by - ay * bx).abs().atan2(ax * bx + ay * by)
+            })
+            .collect()
+    }
+
+    /// Collapses the edge starting at vertex `edge_index` into its midpoint.
+    /// Returns `false` if the polygon would degenerate.
+    pub fn collapse_edge(&mut self, edge_index: usize) -> bool {
+        let n = self.vertices.len() / 2;
+        if n <= 3 || edge_index >= n { return false; }
+        let j = (edge_index + 1) % n;
+        let mx = 0.5 * (self.vertices[edge_index * 2] + self.vertices[j * 2]);
+        let my = 0.5 * (self.vertices[edge_index * 2 + 1] + self.vertices[j * 2 + 1]);
+
+        // The midpoint takes the place of the first vertex of the edge, unless the
+        // edge wraps around, in which case it replaces vertex 0 so the edge order is kept.
+        let (keep, remove) = if j == 0 { (0, n - 1) } else { (edge_index, j) };
+        self.vertices[keep * 2] = mx;
+        self.vertices[keep * 2 + 1] = my;
+        self.vertices.drain(remove * 2..remove * 2 + 2);
+        self.edge_neighbors.remove(edge_index);
+        true
+    }
+
     pub fn centroid(&self) -> [f64; 2] {
         let n = self.vertices.len() / 2;
         if n < 3 { return [0.0, 0.0]; }
@@ -101,6 +152,13 @@ impl Cell2D {
     }
     
     fn clip_with_scratch(&mut self, point: &[f64; 2], normal: &[f64; 2], neighbor_id: i32, scratch: &mut Cell2DScratch, generator: Option<&[f64; 2]>) -> (bool, f64) {
+        let result = self.clip_lines(point, normal, neighbor_id, scratch, generator);
+        #[cfg(feature = "clip-log")]
+        self.clip_log.push(ClipRecord { neighbor_id, point: *point, normal: *normal, modified: result.0 });
+        result
+    }
+
+    fn clip_lines(&mut self, point: &[f64; 2], normal: &[f64; 2], neighbor_id: i32, scratch: &mut Cell2DScratch, generator: Option<&[f64; 2]>) -> (bool, f64) {
         let px = point[0];
         let py = point[1];
         let nx = normal[0];
@@ -114,16 +172,20 @@ impl Cell2D {
         
         let mut all_inside = true;
         let mut all_outside = true;
+        let mut scale = px.abs().max(py.abs());
 
         for i in 0..num_verts {
             let vx = self.vertices[i * 2];
             let vy = self.vertices[i * 2 + 1];
-            let d = (vx - px) * nx + (vy - py) * ny;
-            scratch.dists.push(d);
+            scratch.dists.push((vx - px) * nx + (vy - py) * ny);
+            scale = f64::max(scale, vx.abs().max(vy.abs()));
+        }
 
-            if d > 1e-9 {
+        let eps = CLIP_EPSILON * scale * (nx * nx + ny * ny).sqrt();
+        for &d in &scratch.dists {
+            if d > eps {
                 all_inside = false;
-            } else if d < -1e-9 {
+            } else if d < -eps {
                 all_outside = false;
             }
         }
@@ -146,7 +208,7 @@ impl Cell2D {
             let d_j = scratch.dists[j];
             let neighbor = self.edge_neighbors[i];
             
-            if d_i <= 1e-9 {
+            if d_i <= eps {
                 // V_i is inside
                 scratch.vertices.push(self.vertices[i * 2]);
                 scratch.vertices.push(self.vertices[i * 2 + 1]);
@@ -158,7 +220,7 @@ impl Cell2D {
                     if d2 > max_d2 { max_d2 = d2; }
                 }
 
-                if d_j <= 1e-9 {
+                if d_j <= eps {
                     // V_j is inside: Keep edge
                     scratch.neighbors.push(neighbor);
                 } else {
@@ -192,7 +254,7 @@ impl Cell2D {
                 }
             } else {
                 // V_i is outside
-                if d_j <= 1e-9 {
+                if d_j <= eps {
                     // V_j is inside: Entering
                     let t = d_i / (d_i - d_j);
                     let xi = self.vertices[i * 2];
@@ -263,14 +325,65 @@ impl Cell<2> for Cell2D {
         self.centroid()
     }
 
+    fn volume(&self) -> f64 {
+        self.area()
+    }
+
+    fn for_each_simplex(&self, f: &mut dyn FnMut(&[[f64; 2]], f64)) {
+        // Fan of triangles around the vertex average, which lies inside the convex polygon.
+        let n = self.vertices.len() / 2;
+        if n < 3 { return; }
+        let vertex = |i: usize| [self.vertices[i * 2], self.vertices[i * 2 + 1]];
+        let apex = [0, 1].map(|k| (0..n).map(|i| self.vertices[i * 2 + k]).sum::<f64>() / n as f64);
+        for i in 0..n {
+            let (a, b) = (vertex(i), vertex((i + 1) % n));
+            let area = 0.5 * ((a[0] - apex[0]) * (b[1] - apex[1]) - (a[1] - apex[1]) * (b[0] - apex[0])).abs();
+            f(&[apex, a, b], area);
+        }
+    }
+
     fn is_empty(&self) -> bool {
         self.vertices.is_empty()
     }
 
+    fn vertices(&self) -> &[f64] {
+        &self.vertices
+    }
+
     fn neighbors(&self) -> &[i32] {
         &self.edge_neighbors
     }
 
+    #[cfg(feature = "clip-log")]
+    fn clip_log(&self) -> &[ClipRecord<2>] {
+        &self.clip_log
+    }
+
+    fn face_area(&self, face_index: usize) -> f64 {
+        self.edge_length(face_index)
+    }
+
+    fn faces(&self) -> Vec<Vec<usize>> {
+        let n = self.edge_neighbors.len();
+        (0..n).map(|i| vec![i, (i + 1) % n]).collect()
+    }
+
+    fn edge_lengths(&self) -> Vec<f64> {
+        (0..self.edge_neighbors.len()).map(|i| self.edge_length(i)).collect()
+    }
+
+    fn dihedral_angles(&self) -> Vec<f64> {
+        self.vertex_angles()
+    }
+
+    fn solid_angles(&self) -> Vec<f64> {
+        self.vertex_angles()
+    }
+
+    fn collapse_face(&mut self, face_index: usize) -> bool {
+        self.collapse_edge(face_index)
+    }
+
     fn shared_vertices(&self, neighbor_a: i32, neighbor_b: i32) -> Vec<f64> {
         let mut result = Vec::new();
         let n = self.vertices.len() / 2;
@@ -318,4 +431,91 @@ mod tests {
         let c = cell.centroid();
         assert!((c[0] - 0.25).abs() < 1e-6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_cell2d_interface_tensor() {
+        let bounds = BoundingBox::new([0.0, 0.0], [2.0, 1.0]);
+        let mut cell = Cell2D::new(0, bounds);
+        assert_eq!(cell.interface_tensor(), [[2.0, 0.0], [0.0, 4.0]]);
+
+        // Cutting a corner adds the diagonal edge, whose normal mixes both axes.
+        let mut scratch = Cell2DScratch::default();
+        cell.clip_with_scratch(&[1.5, 0.0], &[1.0, -1.0], 10, &mut scratch, None);
+        let tensor = cell.interface_tensor();
+        let perimeter: f64 = (0..cell.edge_neighbors.len()).map(|i| cell.edge_length(i)).sum();
+        assert!((tensor[0][0] + tensor[1][1] - perimeter).abs() < 1e-12);
+        assert!(tensor[0][1] < 0.0 && tensor[0][1] == tensor[1][0]);
+    }
+
+    #[test]
+    fn test_cell2d_clip_scale() {
+        let mut scratch = Cell2DScratch::default();
+        for (offset, size) in [(0.0, 1e-10), (1e8, 1.0), (-1e3, 1e-4), (1e6, 1e-3)] {
+            // Bisector normals are not normalized and scale with the generator spacing.
+            for length in [1.0, size, 1e3] {
+                let bounds = BoundingBox::new([offset; 2], [offset + size; 2]);
+                let mut cell = Cell2D::new(0, bounds);
+                let mid = offset + 0.5 * size;
+                cell.clip_with_scratch(&[mid, mid], &[length, 0.0], 10, &mut scratch, None);
+                assert_eq!(cell.edge_neighbors.len(), 4);
+                let max_x = cell.vertices.chunks_exact(2).map(|v| v[0]).fold(f64::MIN, f64::max);
+                assert!((max_x - mid).abs() < 1e-6 * size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell2d_clip_scale_lattice() {
+        use crate::{Algorithm2DGrid, Tessellation};
+        for (offset, spacing) in [(1e4, 1e-4), (1e6, 1e-3)] {
+            let bounds = BoundingBox::new([offset; 2], [offset + 4.0 * spacing; 2]);
+            let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(4, 4, &bounds));
+            let mut generators = Vec::new();
+            for i in 0..16 {
+                for k in [i % 4, i / 4] {
+                    generators.push(offset + (k as f64 + 0.5) * spacing);
+                }
+            }
+            tess.set_generators(&generators).unwrap();
+            tess.calculate();
+            for i in 0..16 {
+                let cell = tess.get_cell(i).unwrap();
+                for k in 0..2 {
+                    let coords = || cell.vertices.chunks_exact(2).map(|v| v[k]);
+                    let width = coords().fold(f64::MIN, f64::max) - coords().fold(f64::MAX, f64::min);
+                    assert!((width / spacing - 1.0).abs() < 1e-4, "cell {i} is {width:e} wide along axis {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell2d_collapse_edge() {
+        let bounds = BoundingBox::new([0.0, 0.0], [1.0, 1.0]);
+        let mut cell = Cell2D::new(0, bounds);
+        let mut scratch = Cell2DScratch::default();
+
+        // Cut off a tiny corner, creating a short edge with neighbor 10.
+        cell.clip_with_scratch(&[1.0 - 1e-6, 1.0], &[1.0, 1.0], 10, &mut scratch, None);
+        assert_eq!(cell.edge_neighbors.len(), 5);
+        let edge = cell.edge_neighbors.iter().position(|&n| n == 10).unwrap();
+
+        assert!(cell.collapse_edge(edge));
+        assert_eq!(cell.edge_neighbors.len(), 4);
+        assert!(!cell.edge_neighbors.contains(&10));
+        assert!((cell.area() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cell2d_weighted_centroid() {
+        use crate::cell::Quadrature;
+        let bounds = BoundingBox::new([0.0, 0.0], [2.0, 2.0]);
+        let cell = Cell2D::new(0, bounds);
+
+        assert!((cell.integrate(|p| p[1], Quadrature::Degree1) - 4.0).abs() < 1e-12);
+        let c = cell.weighted_centroid(|p| p[1], Quadrature::Degree2);
+        assert!((c[0] - 1.0).abs() < 1e-12 && (c[1] - 4.0 / 3.0).abs() < 1e-12);
+        let c = cell.weighted_centroid(|p| p[1] * p[1], Quadrature::Degree3);
+        assert!((c[1] - 1.5).abs() < 1e-12);
+    }
+}
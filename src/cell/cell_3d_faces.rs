@@ -1,6 +1,8 @@
 use crate::bounds::BoundingBox;
 use crate::bounds::box_side;
-use crate::cell::Cell;
+use crate::cell::{polygon_normal, tetrahedralize_polyhedron, Cell, CellNet, NetEdge, TetMesh, Tetrahedralization, CLIP_EPSILON};
+#[cfg(feature = "clip-log")]
+use crate::cell::ClipRecord;
 
 /// Scratch buffer to reuse allocations during clipping.
 #[derive(Default, Clone)]
@@ -31,6 +33,11 @@ pub struct Cell3DFaces {
     pub(crate) face_indices: Vec<u16>,
     // Neighbor ID for each face. Negative values indicate walls/boundaries.
     pub(crate) face_neighbors: Vec<i32>,
+    // Set if a clip was rejected because the cell exceeded its index capacity.
+    pub(crate) overflowed: bool,
+    // Sequence of clipping planes applied to the cell.
+    #[cfg(feature = "clip-log")]
+    pub(crate) clip_log: Vec<ClipRecord<3>>,
 }
 
 impl Cell3DFaces {
@@ -70,14 +77,52 @@ impl Cell3DFaces {
                 box_side(0, false), // x- (Left)
                 box_side(0, true),  // x+ (Right)
             ],
+            overflowed: false,
+            #[cfg(feature = "clip-log")]
+            clip_log: Vec::new(),
         }
     }
 
+    /// Builds a cell from the flat `vertices` and the vertex indices and neighbor of each face, to
+    /// evaluate the geometry of other [`Cell`] implementations in the default methods of [`Cell`].
+    /// Returns `None` if a face refers to a missing vertex or exceeds the index capacity, whose
+    /// largest index `u16::MAX` is reserved as a sentinel.
+    pub(crate) fn from_faces(vertices: &[f64], faces: &[Vec<usize>], neighbors: &[i32]) -> Option<Cell3DFaces> {
+        let n = vertices.len() / 3;
+        let mut face_counts = Vec::with_capacity(faces.len());
+        let mut face_indices = Vec::new();
+        for face in faces {
+            face_counts.push(u8::try_from(face.len()).ok()?);
+            for &v in face {
+                if v >= n || v >= u16::MAX as usize {
+                    return None;
+                }
+                face_indices.push(v as u16);
+            }
+        }
+        Some(Cell3DFaces {
+            id: 0,
+            vertices: vertices[..n * 3].to_vec(),
+            face_counts,
+            face_indices,
+            face_neighbors: (0..faces.len()).map(|f| neighbors.get(f).copied().unwrap_or(-1)).collect(),
+            overflowed: false,
+            #[cfg(feature = "clip-log")]
+            clip_log: Vec::new(),
+        })
+    }
+
     /// The ID of the generator associated with this cell.
     pub fn id(&self) -> usize {
         self.id
     }
 
+    /// Returns `true` if a clip was rejected because the cell would reach `u16::MAX` vertices
+    /// or a face would exceed `u8::MAX` vertices. The cell keeps the geometry from before that clip.
+    pub fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
     /// Flat array of vertices [x, y, z, x, y, z, ...].
     pub fn vertices(&self) -> Vec<f64> {
         self.vertices.clone()
@@ -253,6 +298,337 @@ impl Cell3DFaces {
         area
     }
 
+    /// Collapses the face at `face_index` into the centroid of its vertices and removes it.
+    ///
+    /// Faces that share vertices with the collapsed face are reconnected to the new vertex,
+    /// and faces that degenerate to fewer than 3 vertices are removed.
+    /// Returns `false` if the face does not exist or the cell would degenerate.
+    pub fn collapse_face(&mut self, face_index: usize) -> bool {
+        if face_index >= self.face_counts.len() || self.face_counts.len() <= 4 {
+            return false;
+        }
+        let offset: usize = self.face_counts[..face_index].iter().map(|&c| c as usize).sum();
+        let count = self.face_counts[face_index] as usize;
+        let face: Vec<u16> = self.face_indices[offset..offset + count].to_vec();
+        if face.is_empty() {
+            return false;
+        }
+
+        let mut center = [0.0; 3];
+        for &v in &face {
+            let v = v as usize;
+            center[0] += self.vertices[v * 3];
+            center[1] += self.vertices[v * 3 + 1];
+            center[2] += self.vertices[v * 3 + 2];
+        }
+        let target = face[0];
+        let t = target as usize;
+        self.vertices[t * 3] = center[0] / count as f64;
+        self.vertices[t * 3 + 1] = center[1] / count as f64;
+        self.vertices[t * 3 + 2] = center[2] / count as f64;
+
+        let mut face_counts = Vec::with_capacity(self.face_counts.len());
+        let mut face_indices = Vec::with_capacity(self.face_indices.len());
+        let mut face_neighbors = Vec::with_capacity(self.face_neighbors.len());
+        let mut buffer: Vec<u16> = Vec::new();
+        let mut index_offset = 0;
+        for (f, &c) in self.face_counts.iter().enumerate() {
+            let c = c as usize;
+            let indices = &self.face_indices[index_offset..index_offset + c];
+            index_offset += c;
+            if f == face_index {
+                continue;
+            }
+            buffer.clear();
+            for &v in indices {
+                let v = if face.contains(&v) { target } else { v };
+                if buffer.last() != Some(&v) {
+                    buffer.push(v);
+                }
+            }
+            while buffer.len() > 1 && buffer.first() == buffer.last() {
+                buffer.pop();
+            }
+            if buffer.len() >= 3 {
+                face_counts.push(buffer.len() as u8);
+                face_indices.extend_from_slice(&buffer);
+                face_neighbors.push(self.face_neighbors[f]);
+            }
+        }
+
+        // Remove vertices that are no longer referenced by any face.
+        let num_verts = self.vertices.len() / 3;
+        let mut remap = vec![u16::MAX; num_verts];
+        let mut vertices = Vec::with_capacity(self.vertices.len());
+        for &v in &face_indices {
+            let v = v as usize;
+            if remap[v] == u16::MAX {
+                remap[v] = (vertices.len() / 3) as u16;
+                vertices.extend_from_slice(&self.vertices[v * 3..v * 3 + 3]);
+            }
+        }
+        for v in face_indices.iter_mut() {
+            *v = remap[*v as usize];
+        }
+
+        self.vertices = vertices;
+        self.face_counts = face_counts;
+        self.face_indices = face_indices;
+        self.face_neighbors = face_neighbors;
+        true
+    }
+
+    /// Calculates the unit normal of the face at `face_index` using Newell's method.
+    /// The orientation follows the winding order of the face vertices.
+    pub fn face_normal(&self, face_index: usize) -> [f64; 3] {
+        self.faces().get(face_index).map_or([0.0; 3], |face| polygon_normal(&self.vertices, face))
+    }
+
+    /// Returns each edge of the cell once, as a pair of vertex indices
+    /// together with the indices of the faces on either side.
+    fn edges(&self) -> Vec<((usize, usize), [usize; 2])> {
+        let mut edges: Vec<((usize, usize), [usize; 2])> = Vec::new();
+        for (f, face) in self.faces().iter().enumerate() {
+            for (k, &a) in face.iter().enumerate() {
+                let b = face[(k + 1) % face.len()];
+                let key = (a.min(b), a.max(b));
+                match edges.iter_mut().find(|(e, _)| *e == key) {
+                    Some((_, sides)) => sides[1] = f,
+                    None => edges.push((key, [f, usize::MAX])),
+                }
+            }
+        }
+        edges
+    }
+
+    /// Calculates the length of each edge of the cell.
+    pub fn edge_lengths(&self) -> Vec<f64> {
+        self.edges()
+            .iter()
+            .map(|&((a, b), _)| {
+                let dx = self.vertices[a * 3] - self.vertices[b * 3];
+                let dy = self.vertices[a * 3 + 1] - self.vertices[b * 3 + 1];
+                let dz = self.vertices[a * 3 + 2] - self.vertices[b * 3 + 2];
+                (dx * dx + dy * dy + dz * dz).sqrt()
+            })
+            .collect()
+    }
+
+    /// Calculates the interior dihedral angle (in radians) between the two faces at each edge.
+    pub fn dihedral_angles(&self) -> Vec<f64> {
+        let n = self.vertices.len() / 3;
+        if n == 0 {
+            return Vec::new();
+        }
+        // The mean of the vertices lies inside the convex cell, which fixes the normal orientation.
+        let mut center = [0.0; 3];
+        for v in self.vertices.chunks_exact(3) {
+            for k in 0..3 {
+                center[k] += v[k] / n as f64;
+            }
+        }
+        let faces = self.faces();
+        let normals: Vec<[f64; 3]> = (0..faces.len())
+            .map(|f| {
+                let normal = self.face_normal(f);
+                let p = faces[f][0] * 3;
+                let outward: f64 = (0..3).map(|k| normal[k] * (self.vertices[p + k] - center[k])).sum();
+                if outward < 0.0 { [-normal[0], -normal[1], -normal[2]] } else { normal }
+            })
+            .collect();
+
+        self.edges()
+            .iter()
+            .filter(|(_, [_, g])| *g != usize::MAX)
+            .map(|&(_, [f, g])| {
+                let dot: f64 = (0..3).map(|k| normals[f][k] * normals[g][k]).sum();
+                std::f64::consts::PI - dot.clamp(-1.0, 1.0).acos()
+            })
+            .collect()
+    }
+
+    /// Calculates texture coordinates for the vertices of each face by projecting them onto a frame
+    /// in the plane of the face. The frame is anchored at the world origin with its `u` axis
+    /// horizontal (perpendicular to z, or to y for horizontal faces), so coordinates are in world
+    /// units, textures are not mirrored seen from outside, and coplanar faces of different cells
+    /// continue each other's texture.
+    pub fn face_uvs(&self) -> Vec<Vec<[f64; 2]>> {
+        let faces = self.faces();
+        let n = self.vertices.len() / 3;
+        if n == 0 {
+            return vec![Vec::new(); faces.len()];
+        }
+        let mut center = [0.0; 3];
+        for v in self.vertices.chunks_exact(3) {
+            for k in 0..3 {
+                center[k] += v[k] / n as f64;
+            }
+        }
+        faces
+            .iter()
+            .enumerate()
+            .map(|(f, face)| {
+                let mut normal = self.face_normal(f);
+                let p = face[0] * 3;
+                if (0..3).map(|k| normal[k] * (self.vertices[p + k] - center[k])).sum::<f64>() < 0.0 {
+                    normal = normal.map(|x| -x);
+                }
+                let up = if normal[2].abs() < 0.9 { [0.0, 0.0, 1.0] } else { [0.0, 1.0, 0.0] };
+                let u = [up[1] * normal[2] - up[2] * normal[1], up[2] * normal[0] - up[0] * normal[2], up[0] * normal[1] - up[1] * normal[0]];
+                let length = (u[0] * u[0] + u[1] * u[1] + u[2] * u[2]).sqrt();
+                let u = if length > 0.0 { u.map(|x| x / length) } else { [1.0, 0.0, 0.0] };
+                let v = [normal[1] * u[2] - normal[2] * u[1], normal[2] * u[0] - normal[0] * u[2], normal[0] * u[1] - normal[1] * u[0]];
+                face.iter()
+                    .map(|&i| {
+                        let p = &self.vertices[i * 3..i * 3 + 3];
+                        [(0..3).map(|k| p[k] * u[k]).sum(), (0..3).map(|k| p[k] * v[k]).sum()]
+                    })
+                    .collect()
+            })
+            .collect()
+    }
+
+    /// Unfolds the faces into a planar net by rotating them around a spanning tree of edges,
+    /// built breadth-first from the largest face. Each face is laid out on the far side of
+    /// the edge it is joined by, but the net is not checked for overlapping faces.
+    pub fn unfold_net(&self) -> CellNet {
+        let faces = self.faces();
+        let mut net = CellNet::default();
+        if self.vertices.is_empty() || faces.is_empty() {
+            return net;
+        }
+        let point = |v: usize| [self.vertices[v * 3], self.vertices[v * 3 + 1], self.vertices[v * 3 + 2]];
+        let sub = |a: [f64; 3], b: [f64; 3]| [a[0] - b[0], a[1] - b[1], a[2] - b[2]];
+        let dot = |a: [f64; 3], b: [f64; 3]| a[0] * b[0] + a[1] * b[1] + a[2] * b[2];
+        let centers: Vec<[f64; 3]> = faces
+            .iter()
+            .map(|face| {
+                let sum = face.iter().fold([0.0; 3], |c, &v| {
+                    let p = point(v);
+                    [c[0] + p[0], c[1] + p[1], c[2] + p[2]]
+                });
+                sum.map(|x| x / face.len() as f64)
+            })
+            .collect();
+
+        // Places a face in the net with the edge from `a` to `b` at `pa` to `pb`, extending to
+        // the side of `side` (the left side if `None`).
+        let place = |f: usize, a: usize, b: usize, pa: [f64; 2], pb: [f64; 2], side: Option<[f64; 2]>| -> Vec<[f64; 2]> {
+            let (origin, edge) = (point(a), sub(point(b), point(a)));
+            let length = dot(edge, edge).sqrt();
+            let u = edge.map(|x| x / length);
+            let c = sub(centers[f], origin);
+            let w = sub(c, u.map(|x| x * dot(c, u)));
+            let w = w.map(|x| x / dot(w, w).sqrt().max(f64::MIN_POSITIVE));
+            let net_length = ((pb[0] - pa[0]).powi(2) + (pb[1] - pa[1]).powi(2)).sqrt().max(f64::MIN_POSITIVE);
+            let nu = [(pb[0] - pa[0]) / net_length, (pb[1] - pa[1]) / net_length];
+            let mut nw = [-nu[1], nu[0]];
+            if let Some(s) = side
+                && (s[0] - pa[0]) * nw[0] + (s[1] - pa[1]) * nw[1] > 0.0
+            {
+                nw = [-nw[0], -nw[1]];
+            }
+            faces[f]
+                .iter()
+                .map(|&v| {
+                    let d = sub(point(v), origin);
+                    let (x, y) = (dot(d, u), dot(d, w));
+                    [pa[0] + x * nu[0] + y * nw[0], pa[1] + x * nu[1] + y * nw[1]]
+                })
+                .collect()
+        };
+
+        let edges = self.edges();
+        let angles = self.dihedral_angles();
+        let mut layout: Vec<Option<Vec<[f64; 2]>>> = vec![None; faces.len()];
+        let mut folded = vec![false; edges.len()];
+        let edge_of = |a: usize, b: usize| edges.iter().position(|(e, _)| *e == (a.min(b), a.max(b)));
+
+        let mut roots: Vec<usize> = (0..faces.len()).collect();
+        roots.sort_by(|&f, &g| self.face_area(g).total_cmp(&self.face_area(f)));
+        let mut shift = 0.0;
+        for root in roots {
+            if layout[root].is_some() || faces[root].len() < 3 {
+                continue;
+            }
+            // Each further component (only for degenerate cells) is placed to the right of the previous.
+            let (a, b) = (faces[root][0], faces[root][1]);
+            let length = dot(sub(point(b), point(a)), sub(point(b), point(a))).sqrt();
+            layout[root] = Some(place(root, a, b, [shift, 0.0], [shift + length, 0.0], None));
+            let mut queue = std::collections::VecDeque::from([root]);
+            while let Some(f) = queue.pop_front() {
+                for k in 0..faces[f].len() {
+                    let (a, b) = (faces[f][k], faces[f][(k + 1) % faces[f].len()]);
+                    let Some(e) = edge_of(a, b) else { continue };
+                    let g = if edges[e].1[0] == f { edges[e].1[1] } else { edges[e].1[0] };
+                    if g == usize::MAX || layout[g].is_some() || faces[g].len() < 3 {
+                        continue;
+                    }
+                    let Some(parent) = layout[f].as_ref() else { continue };
+                    let (pa, pb) = (parent[k], parent[(k + 1) % faces[f].len()]);
+                    let n = parent.len() as f64;
+                    let side = parent.iter().fold([0.0; 2], |c, p| [c[0] + p[0] / n, c[1] + p[1] / n]);
+                    layout[g] = Some(place(g, a, b, pa, pb, Some(side)));
+                    folded[e] = true;
+                    queue.push_back(g);
+                }
+            }
+            shift = layout.iter().flatten().flatten().fold(shift, |m, p| m.max(p[0])) + length;
+        }
+
+        let mut offsets = Vec::with_capacity(faces.len());
+        for positions in &layout {
+            let offset = net.vertices.len() / 2;
+            offsets.push(offset);
+            let positions = positions.as_deref().unwrap_or(&[]);
+            net.faces.push((0..positions.len()).map(|k| offset + k).collect());
+            net.vertices.extend(positions.iter().flatten());
+        }
+        let mut angle = angles.iter();
+        for (e, &((a, b), [f, g])) in edges.iter().enumerate() {
+            if g == usize::MAX {
+                continue;
+            }
+            let dihedral_angle = angle.next().copied().unwrap_or(0.0);
+            if layout[f].is_none() || layout[g].is_none() {
+                continue;
+            }
+            let net_vertex = |face: usize, v: usize| Some(offsets[face] + faces[face].iter().position(|&x| x == v)?);
+            let (Some(fa), Some(fb), Some(ga), Some(gb)) = (net_vertex(f, a), net_vertex(f, b), net_vertex(g, a), net_vertex(g, b))
+            else {
+                continue;
+            };
+            let edge = NetEdge { faces: [f, g], net_vertices: [[fa, fb], [ga, gb]], dihedral_angle };
+            if folded[e] { net.folds.push(edge) } else { net.cuts.push(edge) }
+        }
+        net
+    }
+
+    /// Calculates the interior solid angle (in steradians) at each vertex. The directions of the
+    /// edges at a vertex span a spherical polygon whose angles are the dihedral angles at these
+    /// edges, so its area is the sum of the angles minus `(k - 2) * PI` for `k` edges.
+    pub fn solid_angles(&self) -> Vec<f64> {
+        let mut sums = vec![0.0; self.vertices.len() / 3];
+        let mut counts = vec![0usize; sums.len()];
+        let closed = self.edges().into_iter().filter(|(_, [_, g])| *g != usize::MAX);
+        for (((a, b), _), angle) in closed.zip(self.dihedral_angles()) {
+            for v in [a, b] {
+                sums[v] += angle;
+                counts[v] += 1;
+            }
+        }
+        sums.iter()
+            .zip(counts)
+            .map(|(&sum, k)| if k < 3 { 0.0 } else { sum - (k - 2) as f64 * std::f64::consts::PI })
+            .collect()
+    }
+
+    /// Decomposes the cell into positively oriented tetrahedra. Each face is triangulated from its
+    /// lexicographically smallest vertex, so the faces shared with neighboring cells match.
+    pub fn tetrahedralize(&self, method: Tetrahedralization) -> TetMesh {
+        tetrahedralize_polyhedron(&self.vertices, &self.faces(), self.id, method)
+    }
+
     pub fn faces(&self) -> Vec<Vec<usize>> {
         let mut faces: Vec<Vec<usize>> = Vec::with_capacity(self.face_counts.len());
         let mut offset: usize = 0;
@@ -286,6 +662,13 @@ impl Cell3DFaces {
     }
 
     pub fn clip_with_scratch(&mut self, point: &[f64; 3], normal: &[f64; 3], neighbor_id: i32, scratch: &mut Cell3DFacesScratch, generator: Option<&[f64; 3]>) -> (bool, f64) {
+        let result = self.clip_planes(point, normal, neighbor_id, scratch, generator);
+        #[cfg(feature = "clip-log")]
+        self.clip_log.push(ClipRecord { neighbor_id, point: *point, normal: *normal, modified: result.0 });
+        result
+    }
+
+    fn clip_planes(&mut self, point: &[f64; 3], normal: &[f64; 3], neighbor_id: i32, scratch: &mut Cell3DFacesScratch, generator: Option<&[f64; 3]>) -> (bool, f64) {
         let px = point[0];
         let py = point[1];
         let pz = point[2];
@@ -298,18 +681,22 @@ impl Cell3DFaces {
         scratch.dists.reserve(num_verts);
         let mut all_inside = true;
         let mut all_outside = true;
+        let mut scale = px.abs().max(py.abs()).max(pz.abs());
 
         // 1. Calculate distances
         for i in 0..num_verts {
             let vx = self.vertices[i * 3];
             let vy = self.vertices[i * 3 + 1];
             let vz = self.vertices[i * 3 + 2];
-            let d = (vx - px) * nx + (vy - py) * ny + (vz - pz) * nz;
-            scratch.dists.push(d);
+            scratch.dists.push((vx - px) * nx + (vy - py) * ny + (vz - pz) * nz);
+            scale = f64::max(scale, vx.abs().max(vy.abs()).max(vz.abs()));
+        }
 
-            if d > 1e-9 {
+        let eps = CLIP_EPSILON * scale * (nx * nx + ny * ny + nz * nz).sqrt();
+        for &d in &scratch.dists {
+            if d > eps {
                 all_inside = false;
-            } else if d < -1e-9 {
+            } else if d < -eps {
                 all_outside = false;
             }
         }
@@ -340,10 +727,11 @@ impl Cell3DFaces {
         scratch.lid_map.clear();
 
         let mut max_d2 = 0.0;
+        let mut overflow = false;
 
         // Keep existing vertices that are inside
         for i in 0..num_verts {
-            if scratch.dists[i] <= 1e-9 {
+            if scratch.dists[i] <= eps {
                 let new_idx = (scratch.vertices.len() / 3) as u16;
                 scratch.vertices.push(self.vertices[i * 3]);
                 scratch.vertices.push(self.vertices[i * 3 + 1]);
@@ -380,8 +768,8 @@ impl Cell3DFaces {
                 let idx_e = current_indices[(i + 1) % count] as usize;
                 let d_s = scratch.dists[idx_s];
                 let d_e = scratch.dists[idx_e];
-                let s_in = d_s <= 1e-9;
-                let e_in = d_e <= 1e-9;
+                let s_in = d_s <= eps;
+                let e_in = d_e <= eps;
 
                 if s_in {
                     if e_in {
@@ -451,6 +839,9 @@ impl Cell3DFaces {
                 }
             }
 
+            if scratch.face_buffer.len() > u8::MAX as usize {
+                overflow = true;
+            }
             if scratch.face_buffer.len() >= 3 {
                 scratch.face_counts.push(scratch.face_buffer.len() as u8);
                 scratch.face_neighbors.push(face_neighbor);
@@ -487,6 +878,9 @@ impl Cell3DFaces {
                 if current == u16::MAX { break; } // Should not happen for convex poly
             }
             
+            if scratch.lid_buffer.len() > u8::MAX as usize {
+                overflow = true;
+            }
             if scratch.lid_buffer.len() >= 3 {
                 scratch.face_counts.push(scratch.lid_buffer.len() as u8);
                 scratch.face_indices.extend_from_slice(&scratch.lid_buffer);
@@ -494,6 +888,13 @@ impl Cell3DFaces {
             }
         }
 
+        // Vertex indices are stored as u16 (with u16::MAX as sentinel) and face sizes as u8.
+        // Rather than storing wrapped indices, reject the clip and keep the previous geometry.
+        if overflow || scratch.vertices.len() / 3 >= u16::MAX as usize {
+            self.overflowed = true;
+            return (false, 0.0);
+        }
+
         std::mem::swap(&mut self.vertices, &mut scratch.vertices);
         std::mem::swap(&mut self.face_counts, &mut scratch.face_counts);
         std::mem::swap(&mut self.face_indices, &mut scratch.face_indices);
@@ -525,14 +926,100 @@ impl Cell<3> for Cell3DFaces {
         self.centroid()
     }
 
+    fn volume(&self) -> f64 {
+        self.volume()
+    }
+
+    fn for_each_simplex(&self, f: &mut dyn FnMut(&[[f64; 3]], f64)) {
+        // Tetrahedra between the vertex average, which lies inside the convex cell,
+        // and a fan triangulation of each face.
+        let n = self.vertices.len() / 3;
+        if n < 4 { return; }
+        let vertex = |i: u16| {
+            let i = i as usize;
+            [self.vertices[i * 3], self.vertices[i * 3 + 1], self.vertices[i * 3 + 2]]
+        };
+        let apex = [0, 1, 2].map(|k| (0..n).map(|i| self.vertices[i * 3 + k]).sum::<f64>() / n as f64);
+        let mut offset = 0;
+        for &count in &self.face_counts {
+            let face = &self.face_indices[offset..offset + count as usize];
+            offset += count as usize;
+            if face.len() < 3 { continue; }
+            let v0 = vertex(face[0]);
+            for w in face[1..].windows(2) {
+                let (v1, v2) = (vertex(w[0]), vertex(w[1]));
+                let a = [v0[0] - apex[0], v0[1] - apex[1], v0[2] - apex[2]];
+                let b = [v1[0] - apex[0], v1[1] - apex[1], v1[2] - apex[2]];
+                let c = [v2[0] - apex[0], v2[1] - apex[1], v2[2] - apex[2]];
+                let det = a[0] * (b[1] * c[2] - b[2] * c[1])
+                    + a[1] * (b[2] * c[0] - b[0] * c[2])
+                    + a[2] * (b[0] * c[1] - b[1] * c[0]);
+                f(&[apex, v0, v1, v2], det.abs() / 6.0);
+            }
+        }
+    }
+
     fn is_empty(&self) -> bool {
         self.vertices.is_empty()
     }
 
+    fn vertices(&self) -> &[f64] {
+        &self.vertices
+    }
+
     fn neighbors(&self) -> &[i32] {
         &self.face_neighbors
     }
 
+    fn overflowed(&self) -> bool {
+        self.overflowed
+    }
+
+    #[cfg(feature = "clip-log")]
+    fn clip_log(&self) -> &[ClipRecord<3>] {
+        &self.clip_log
+    }
+
+    fn face_area(&self, face_index: usize) -> f64 {
+        self.face_area(face_index)
+    }
+
+    fn collapse_face(&mut self, face_index: usize) -> bool {
+        self.collapse_face(face_index)
+    }
+
+    fn faces(&self) -> Vec<Vec<usize>> {
+        self.faces()
+    }
+
+    fn edge_lengths(&self) -> Vec<f64> {
+        self.edge_lengths()
+    }
+
+    fn dihedral_angles(&self) -> Vec<f64> {
+        self.dihedral_angles()
+    }
+
+    fn solid_angles(&self) -> Vec<f64> {
+        self.solid_angles()
+    }
+
+    fn face_uvs(&self) -> Vec<Vec<[f64; 2]>> {
+        self.face_uvs()
+    }
+
+    fn face_normal(&self, face_index: usize) -> [f64; 3] {
+        self.face_normal(face_index)
+    }
+
+    fn tetrahedralize(&self, method: Tetrahedralization) -> TetMesh {
+        self.tetrahedralize(method)
+    }
+
+    fn unfold_net(&self) -> CellNet {
+        self.unfold_net()
+    }
+
     fn shared_vertices(&self, neighbor_a: i32, neighbor_b: i32) -> Vec<f64> {
         let mut a_verts = Vec::new();
         let mut b_verts = Vec::new();
@@ -582,6 +1069,51 @@ mod tests {
         assert!((c[0] - 0.5).abs() < 1e-6);
     }
 
+    #[test]
+    fn test_cell_faces_weighted_centroid() {
+        use crate::cell::Quadrature;
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        cell.clip(&[1.5, 1.5, 1.5], &[1.0, 1.0, 1.0], 10);
+
+        let volume = Cell::volume(&cell);
+        assert!((cell.integrate(|_| 1.0, Quadrature::Degree1) - volume).abs() < 1e-12);
+        let c = cell.weighted_centroid(|_| 1.0, Quadrature::Degree1);
+        let expected = Cell::centroid(&cell);
+        assert!((0..3).all(|k| (c[k] - expected[k]).abs() < 1e-12));
+
+        // The exact center of mass of the unclipped box for a linear and a quadratic density.
+        let cube = Cell3DFaces::new(0, bounds);
+        assert!((cube.integrate(|p| p[0], Quadrature::Degree1) - 8.0).abs() < 1e-12);
+        let c = cube.weighted_centroid(|p| p[0], Quadrature::Degree2);
+        assert!((c[0] - 4.0 / 3.0).abs() < 1e-12 && (c[1] - 1.0).abs() < 1e-12);
+        let c = cube.weighted_centroid(|p| p[0] * p[0], Quadrature::Degree3);
+        assert!((c[0] - 1.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cell_faces_sample_points() {
+        use rand::SeedableRng;
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        cell.clip(&[1.5, 1.5, 1.5], &[1.0, 1.0, 1.0], 10);
+
+        let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+        let points = cell.sample_points(4000, &mut rng);
+        assert_eq!(points.len(), 4000 * 3);
+        let mut mean = [0.0; 3];
+        for p in points.chunks_exact(3) {
+            assert!(p.iter().all(|&x| (0.0..=2.0).contains(&x)));
+            assert!(p[0] + p[1] + p[2] <= 4.5 + 1e-12);
+            (0..3).for_each(|k| mean[k] += p[k] / 4000.0);
+        }
+        let c = Cell::centroid(&cell);
+        assert!((0..3).all(|k| (mean[k] - c[k]).abs() < 0.05));
+
+        let empty = Cell3DFaces { vertices: Vec::new(), ..cell };
+        assert!(empty.sample_points(10, &mut rng).is_empty());
+    }
+
     #[test]
     fn test_cell_faces_clip() {
         let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
@@ -591,4 +1123,256 @@ mod tests {
         cell.clip_with_scratch(&[0.5, 0.5, 0.5], &[1.0, 0.0, 0.0], 10, &mut scratch, None);
         assert!((cell.volume() - 0.5).abs() < 1e-6);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_cell_faces_clip_scale() {
+        let mut scratch = Cell3DFacesScratch::default();
+        for (offset, size) in [(0.0, 1e-10), (1e8, 1.0), (-1e3, 1e-4), (1e6, 1e-3)] {
+            // Bisector normals are not normalized and scale with the generator spacing.
+            for length in [1.0, size, 1e3] {
+                let bounds = BoundingBox::new([offset; 3], [offset + size; 3]);
+                let mut cell = Cell3DFaces::new(0, bounds);
+                let mid = offset + 0.5 * size;
+                cell.clip_with_scratch(&[mid, mid, mid], &[length, 0.0, 0.0], 10, &mut scratch, None);
+                assert_eq!(cell.face_neighbors.len(), 6);
+                let max_x = cell.vertices.chunks_exact(3).map(|v| v[0]).fold(f64::MIN, f64::max);
+                assert!((max_x - mid).abs() < 1e-6 * size);
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell_faces_clip_scale_lattice() {
+        use crate::{Algorithm3DGrid, Tessellation};
+        for (offset, spacing) in [(1e4, 1e-4), (1e6, 1e-3)] {
+            let bounds = BoundingBox::new([offset; 3], [offset + 4.0 * spacing; 3]);
+            let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+            let mut generators = Vec::new();
+            for i in 0..64 {
+                for k in [i % 4, (i / 4) % 4, i / 16] {
+                    generators.push(offset + (k as f64 + 0.5) * spacing);
+                }
+            }
+            tess.set_generators(&generators).unwrap();
+            tess.calculate();
+            for i in 0..64 {
+                let cell = tess.get_cell(i).unwrap();
+                for k in 0..3 {
+                    let coords = || cell.vertices.chunks_exact(3).map(|v| v[k]);
+                    let width = coords().fold(f64::MIN, f64::max) - coords().fold(f64::MAX, f64::min);
+                    assert!((width / spacing - 1.0).abs() < 1e-4, "cell {i} is {width:e} wide along axis {k}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_cell_faces_overflow() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        let mut scratch = Cell3DFacesScratch::default();
+
+        // Vertical planes tangent to a cylinder turn the top and bottom faces into 300-gons.
+        for i in 0..300 {
+            let angle = i as f64 * std::f64::consts::TAU / 300.0;
+            let normal = [angle.cos(), angle.sin(), 0.0];
+            let point = [0.5 + 0.4 * normal[0], 0.5 + 0.4 * normal[1], 0.5];
+            cell.clip_with_scratch(&point, &normal, i, &mut scratch, None);
+        }
+
+        assert!(cell.overflowed());
+        assert!(cell.face_counts.iter().all(|&c| c >= 3));
+        let max_index = cell.face_indices.iter().copied().max().unwrap() as usize;
+        assert!(max_index < cell.vertices.len() / 3);
+        assert!(cell.volume() > std::f64::consts::PI * 0.16);
+
+        // The largest index is the sentinel of unset entries, so no face may refer to it.
+        let last = u16::MAX as usize;
+        let vertices = vec![0.0; 3 * (last + 1)];
+        assert!(Cell3DFaces::from_faces(&vertices, &[vec![0, 1, last - 1]], &[]).is_some());
+        assert!(Cell3DFaces::from_faces(&vertices, &[vec![0, 1, last]], &[]).is_none());
+    }
+
+    #[cfg(feature = "clip-log")]
+    #[test]
+    fn test_cell_faces_clip_log() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        let mut scratch = Cell3DFacesScratch::default();
+
+        cell.clip_with_scratch(&[0.5, 0.5, 0.5], &[1.0, 0.0, 0.0], 10, &mut scratch, None);
+        cell.clip_with_scratch(&[2.0, 0.5, 0.5], &[1.0, 0.0, 0.0], 11, &mut scratch, None);
+
+        let log = Cell::clip_log(&cell);
+        assert_eq!(log.len(), 2);
+        assert_eq!(log[0].neighbor_id, 10);
+        assert!(log[0].modified);
+        assert_eq!(log[1].neighbor_id, 11);
+        assert!(!log[1].modified);
+    }
+
+    #[test]
+    fn test_cell_faces_collapse() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        let mut scratch = Cell3DFacesScratch::default();
+
+        // Cut off a tiny corner, creating a small triangular face with neighbor 10.
+        cell.clip_with_scratch(&[1.0 - 1e-6, 1.0, 1.0], &[1.0, 1.0, 1.0], 10, &mut scratch, None);
+        assert_eq!(cell.face_counts.len(), 7);
+        let face = cell.face_neighbors.iter().position(|&n| n == 10).unwrap();
+
+        assert!(cell.collapse_face(face));
+        assert_eq!(cell.face_counts.len(), 6);
+        assert_eq!(cell.vertices.len(), 8 * 3);
+        assert!(!cell.face_neighbors.contains(&10));
+        assert!((cell.volume() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_cell_faces_unfold_net() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        let mut scratch = Cell3DFacesScratch::default();
+        cell.clip_with_scratch(&[0.8, 0.8, 0.8], &[1.0, 1.0, 1.0], 10, &mut scratch, None);
+        cell.clip_with_scratch(&[0.5, 0.0, 0.2], &[0.2, -1.0, 0.3], 11, &mut scratch, None);
+
+        let net = cell.unfold_net();
+        let faces = cell.faces();
+        assert_eq!(net.faces.len(), faces.len());
+        assert_eq!(net.folds.len(), faces.len() - 1);
+        assert_eq!(net.folds.len() + net.cuts.len(), cell.edge_lengths().len());
+
+        let p = |v: usize| [net.vertices[v * 2], net.vertices[v * 2 + 1]];
+        let dist = |a: [f64; 2], b: [f64; 2]| ((a[0] - b[0]).powi(2) + (a[1] - b[1]).powi(2)).sqrt();
+        // The faces keep their shape and area.
+        for (f, face) in net.faces.iter().enumerate() {
+            let area = (0..face.len())
+                .map(|k| {
+                    let (a, b) = (p(face[k]), p(face[(k + 1) % face.len()]));
+                    a[0] * b[1] - b[0] * a[1]
+                })
+                .sum::<f64>()
+                .abs()
+                * 0.5;
+            assert!((area - cell.face_area(f)).abs() < 1e-12);
+        }
+        // Folded faces meet at their shared edge, and cut edges have the same length on both sides.
+        for edge in &net.folds {
+            let [[a, b], [c, d]] = edge.net_vertices;
+            assert!(dist(p(a), p(c)) < 1e-12 && dist(p(b), p(d)) < 1e-12);
+        }
+        for edge in &net.cuts {
+            let [[a, b], [c, d]] = edge.net_vertices;
+            assert!((dist(p(a), p(b)) - dist(p(c), p(d))).abs() < 1e-12);
+        }
+        let cube = Cell3DFaces::new(0, bounds).unfold_net();
+        assert!(cube.folds.iter().chain(&cube.cuts).all(|e| (e.dihedral_angle - std::f64::consts::FRAC_PI_2).abs() < 1e-12));
+        assert_eq!(Cell3DFaces { vertices: Vec::new(), ..cell }.unfold_net(), CellNet::default());
+    }
+
+    #[test]
+    fn test_cell_faces_face_uvs() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        cell.clip(&[1.5, 1.5, 1.5], &[1.0, 1.0, 1.0], 10);
+
+        let faces = cell.faces();
+        let uvs = cell.face_uvs();
+        assert_eq!(uvs.len(), faces.len());
+        for (f, (face, uv)) in faces.iter().zip(&uvs).enumerate() {
+            assert_eq!(face.len(), uv.len());
+            // The projection keeps distances and the area, counter-clockwise seen from outside.
+            let area: f64 = (0..uv.len())
+                .map(|k| {
+                    let (a, b) = (uv[k], uv[(k + 1) % uv.len()]);
+                    a[0] * b[1] - b[0] * a[1]
+                })
+                .sum::<f64>()
+                * 0.5;
+            let outward = {
+                let normal = cell.face_normal(f);
+                let p = &cell.vertices[face[0] * 3..face[0] * 3 + 3];
+                (0..3).map(|k| normal[k] * (p[k] - 1.0)).sum::<f64>() > 0.0
+            };
+            assert!((area.abs() - cell.face_area(f)).abs() < 1e-12);
+            assert_eq!(area > 0.0, outward);
+        }
+        // The u axis of a vertical face is horizontal.
+        let side = cell.face_neighbors.iter().position(|&n| n == box_side(0, false)).unwrap();
+        let (a, b) = (faces[side][0], faces[side][1]);
+        let dz = cell.vertices[a * 3 + 2] - cell.vertices[b * 3 + 2];
+        let (ua, ub) = (uvs[side][0], uvs[side][1]);
+        assert!((dz.abs() - (ua[1] - ub[1]).abs()).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cell_faces_solid_angles() {
+        use std::f64::consts::PI;
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+        let cube = Cell3DFaces::new(0, bounds);
+        let angles = cube.solid_angles();
+        assert_eq!(angles.len(), 8);
+        assert!(angles.iter().all(|a| (a - PI / 2.0).abs() < 1e-12));
+
+        // Cutting a corner tetrahedron keeps the right angle at the origin. The dihedral angles sum
+        // to half of the solid angles plus 4 pi, which gives the angles at the three cut vertices.
+        let mut cell = cube.clone();
+        cell.clip(&[0.5, 0.0, 0.0], &[1.0, 1.0, 1.0], 10);
+        let angles = cell.solid_angles();
+        assert_eq!(angles.len(), 4);
+        let origin = cell.vertices.chunks_exact(3).position(|v| v.iter().all(|&x| x == 0.0)).unwrap();
+        assert!((angles[origin] - PI / 2.0).abs() < 1e-12);
+        let cut: f64 = angles.iter().sum::<f64>() - angles[origin];
+        let alpha = (1.0 / 3.0f64.sqrt()).acos();
+        assert!((cut - (6.0 * alpha - 1.5 * PI)).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_cell_faces_interface_tensor() {
+        // Each pair of opposite faces of a box contributes twice its area along its axis.
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 2.0, 3.0]);
+        let cell = Cell3DFaces::new(0, bounds);
+        let tensor = cell.interface_tensor();
+        let expected = [[12.0, 0.0, 0.0], [0.0, 6.0, 0.0], [0.0, 0.0, 4.0]];
+        for (row, expected) in tensor.iter().zip(&expected) {
+            assert!(row.iter().zip(expected).all(|(t, e)| (t - e).abs() < 1e-12));
+        }
+
+        // The trace is the surface area, and the tensor stays symmetric after an oblique cut.
+        let mut cell = cell.clone();
+        cell.clip(&[0.5, 1.0, 1.5], &[1.0, 0.5, 0.2], 10);
+        let tensor = cell.interface_tensor();
+        let area: f64 = (0..cell.face_counts.len()).map(|f| cell.face_area(f)).sum();
+        assert!((tensor[0][0] + tensor[1][1] + tensor[2][2] - area).abs() < 1e-12);
+        assert!((tensor[0][1] - tensor[1][0]).abs() < 1e-12 && tensor[0][1] != 0.0);
+    }
+
+    #[test]
+    fn test_cell_faces_tetrahedralize() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let mut cell = Cell3DFaces::new(3, bounds);
+        cell.clip(&[1.5, 1.5, 1.5], &[1.0, 1.0, 1.0], 10);
+        let volume = Cell::volume(&cell);
+
+        for method in [Tetrahedralization::Centroid, Tetrahedralization::Fan] {
+            let mesh = cell.tetrahedralize(method);
+            assert_eq!(mesh.cells, vec![3; mesh.tetrahedra.len()]);
+            let p = |v: usize| [mesh.vertices[v * 3], mesh.vertices[v * 3 + 1], mesh.vertices[v * 3 + 2]];
+            let mut total = 0.0;
+            for &[a, b, c, d] in &mesh.tetrahedra {
+                let (a, b, c, d) = (p(a), p(b), p(c), p(d));
+                let (u, v, w) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]], [d[0] - a[0], d[1] - a[1], d[2] - a[2]]);
+                let det = u[0] * (v[1] * w[2] - v[2] * w[1]) + u[1] * (v[2] * w[0] - v[0] * w[2]) + u[2] * (v[0] * w[1] - v[1] * w[0]);
+                assert!(det > 0.0);
+                total += det / 6.0;
+            }
+            assert!((total - volume).abs() < 1e-12);
+        }
+        // The fan needs no extra vertex and fewer tetrahedra.
+        let (centroid, fan) = (cell.tetrahedralize(Tetrahedralization::Centroid), cell.tetrahedralize(Tetrahedralization::Fan));
+        assert_eq!(centroid.vertices.len(), cell.vertices.len() + 3);
+        assert_eq!(fan.vertices.len(), cell.vertices.len());
+        assert!(fan.tetrahedra.len() < centroid.tetrahedra.len());
+    }
+}
@@ -1,19 +1,278 @@
 use crate::bounds::BoundingBox;
+use rand::Rng;
 
 pub mod cell_2d;
 pub mod cell_3d_faces;
 
+/// Relative tolerance used to classify vertices against a clipping plane.
+///
+/// The signed distances are compared against this value scaled by the largest coordinate
+/// magnitude of the cell and the plane point and by the length of the plane normal, which
+/// bounds the rounding error of the distance computation independently of the coordinate
+/// range and of whether the normal is normalized.
+pub(crate) const CLIP_EPSILON: f64 = 1e-12;
+
+/// A quadrature rule for integrating a field over the simplices (triangles in 2D, tetrahedra in 3D)
+/// of a cell, see [`Cell::integrate`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Quadrature {
+    /// Evaluates the field at the centroid of each simplex, exact for linear fields.
+    Degree1,
+    /// Evaluates the field at `D + 1` points per simplex, exact for quadratic fields.
+    #[default]
+    Degree2,
+    /// Evaluates the field at `D + 2` points per simplex, exact for cubic fields.
+    Degree3,
+}
+
+impl Quadrature {
+    /// Calls `f` with the weight and position of each quadrature point of the simplex with the
+    /// given `D + 1` vertices. The weights sum to one.
+    fn for_each_point<const D: usize>(self, simplex: &[[f64; D]], mut f: impl FnMut(f64, [f64; D])) {
+        let n = simplex.len() as f64;
+        // The rules are symmetric: a weight at the centroid, and a weight at the points with
+        // barycentric coordinate `a` for one vertex and `b` for the others.
+        let (center, orbit, a, b) = match (self, D) {
+            (Quadrature::Degree1, _) => (1.0, 0.0, 0.0, 0.0),
+            (Quadrature::Degree2, 2) => (0.0, 1.0 / 3.0, 2.0 / 3.0, 1.0 / 6.0),
+            (Quadrature::Degree2, _) => (0.0, 0.25, 0.585_410_196_624_968_5, 0.138_196_601_125_010_5),
+            (Quadrature::Degree3, 2) => (-27.0 / 48.0, 25.0 / 48.0, 0.6, 0.2),
+            (Quadrature::Degree3, _) => (-0.8, 0.45, 0.5, 1.0 / 6.0),
+        };
+        if center != 0.0 {
+            f(center, std::array::from_fn(|k| simplex.iter().map(|v| v[k]).sum::<f64>() / n));
+        }
+        if orbit != 0.0 {
+            for i in 0..simplex.len() {
+                let point = std::array::from_fn(|k| {
+                    simplex.iter().enumerate().map(|(j, v)| if i == j { a } else { b } * v[k]).sum()
+                });
+                f(orbit, point);
+            }
+        }
+    }
+}
+
+/// An edge of a cell in a [`CellNet`], shared by two faces.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct NetEdge {
+    /// The faces on either side of the edge, in the order of [`Cell::neighbors`].
+    pub faces: [usize; 2],
+    /// The net vertices of the edge in each of the two faces, in the same order in both.
+    /// For a fold both sides lie at the same position in the net.
+    pub net_vertices: [[usize; 2]; 2],
+    /// The interior dihedral angle of the cell at the edge, in radians.
+    pub dihedral_angle: f64,
+}
+
+/// A planar unfolding of the faces of a cell, as returned by [`Cell::unfold_net`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CellNet {
+    /// The net vertex coordinates as a flat array `[x, y, ...]`. Each face has its own vertices.
+    pub vertices: Vec<f64>,
+    /// The net vertex indices of each face, in the order of [`Cell::neighbors`].
+    pub faces: Vec<Vec<usize>>,
+    /// The edges along which adjacent faces stay joined and are folded.
+    pub folds: Vec<NetEdge>,
+    /// The edges that are cut open, to be glued when assembling the cell.
+    pub cuts: Vec<NetEdge>,
+}
+
+/// How [`Cell::tetrahedralize`] decomposes a cell into tetrahedra.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Tetrahedralization {
+    /// Connects the vertex average to a triangulation of every face. This adds a vertex,
+    /// but gives better shaped tetrahedra.
+    #[default]
+    Centroid,
+    /// Connects one vertex of the cell to a triangulation of every face not containing it,
+    /// without adding vertices.
+    Fan,
+}
+
+/// A decomposition into tetrahedra, as returned by [`Cell::tetrahedralize`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TetMesh {
+    /// The vertex coordinates as a flat array `[x, y, z, ...]`.
+    pub vertices: Vec<f64>,
+    /// The tetrahedra as indices into the vertices, with a positive orientation:
+    /// the fourth vertex lies on the side of the first three the normal of `(b - a) x (c - a)` points to.
+    pub tetrahedra: Vec<[usize; 4]>,
+    /// The cell each tetrahedron belongs to.
+    pub cells: Vec<usize>,
+}
+
+/// The relative volume below which [`tetrahedralize_faces`] drops degenerate tetrahedra,
+/// as from collinear face vertices.
+const TET_TOLERANCE: f64 = 1e-12;
+
+/// Decomposes the convex polyhedron with the given faces into positively oriented tetrahedra.
+/// Each face is triangulated as a fan from its vertex with the smallest `key`, so that faces shared
+/// by two cells with consistent keys are triangulated alike. With [`Tetrahedralization::Centroid`],
+/// the index `points.len()` refers to the vertex average, which the caller has to add.
+pub(crate) fn tetrahedralize_faces(points: &[[f64; 3]], faces: &[Vec<usize>], keys: &[usize], method: Tetrahedralization) -> Vec<[usize; 4]> {
+    if points.len() < 4 {
+        return Vec::new();
+    }
+    let average: [f64; 3] = std::array::from_fn(|k| points.iter().map(|p| p[k]).sum::<f64>() / points.len() as f64);
+    let apex = match method {
+        Tetrahedralization::Centroid => points.len(),
+        Tetrahedralization::Fan => (0..points.len()).min_by_key(|&v| keys[v]).unwrap_or(0),
+    };
+    let point = |v: usize| if v == points.len() { average } else { points[v] };
+    let scale = (0..3)
+        .map(|k| points.iter().map(|p| p[k]).fold(f64::NEG_INFINITY, f64::max) - points.iter().map(|p| p[k]).fold(f64::INFINITY, f64::min))
+        .fold(0.0, f64::max);
+
+    let mut tetrahedra = Vec::new();
+    for face in faces.iter().filter(|f| f.len() >= 3 && !f.contains(&apex)) {
+        let start = (0..face.len()).min_by_key(|&k| keys[face[k]]).unwrap_or(0);
+        let v0 = face[start];
+        for k in 1..face.len() - 1 {
+            let (v1, v2) = (face[(start + k) % face.len()], face[(start + k + 1) % face.len()]);
+            let [a, b, c, d] = [apex, v0, v1, v2].map(point);
+            let (b, c, d) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]], [d[0] - a[0], d[1] - a[1], d[2] - a[2]]);
+            let det = b[0] * (c[1] * d[2] - c[2] * d[1]) + b[1] * (c[2] * d[0] - c[0] * d[2]) + b[2] * (c[0] * d[1] - c[1] * d[0]);
+            if det.abs() <= TET_TOLERANCE * scale.powi(3) {
+                continue;
+            }
+            tetrahedra.push(if det > 0.0 { [apex, v0, v1, v2] } else { [apex, v0, v2, v1] });
+        }
+    }
+    tetrahedra
+}
+
+/// Decomposes the convex polyhedron with the flat `vertices` and the given faces into a [`TetMesh`]
+/// of the cell `id`. Each face is triangulated from its lexicographically smallest vertex, so the
+/// faces shared with neighboring cells match.
+pub(crate) fn tetrahedralize_polyhedron(vertices: &[f64], faces: &[Vec<usize>], id: usize, method: Tetrahedralization) -> TetMesh {
+    let points: Vec<[f64; 3]> = vertices.chunks_exact(3).map(|v| [v[0], v[1], v[2]]).collect();
+    let mut order: Vec<usize> = (0..points.len()).collect();
+    order.sort_by(|&a, &b| points[a].partial_cmp(&points[b]).unwrap_or(std::cmp::Ordering::Equal));
+    let mut keys = vec![0; points.len()];
+    for (rank, &v) in order.iter().enumerate() {
+        keys[v] = rank;
+    }
+    let tetrahedra = tetrahedralize_faces(&points, faces, &keys, method);
+    let mut vertices = vertices[..points.len() * 3].to_vec();
+    if method == Tetrahedralization::Centroid && !tetrahedra.is_empty() {
+        let n = points.len() as f64;
+        vertices.extend((0..3).map(|k| points.iter().map(|p| p[k]).sum::<f64>() / n));
+    }
+    TetMesh { vertices, cells: vec![id; tetrahedra.len()], tetrahedra }
+}
+
+/// Converts a 3D cell into a [`Cell3DFaces`](cell_3d_faces::Cell3DFaces) with the same vertices
+/// and faces, whose methods provide the default polyhedron geometry of [`Cell`].
+/// Returns `None` in 2D, or if the faces do not fit a `Cell3DFaces`.
+fn as_polyhedron<const D: usize, C: Cell<D>>(cell: &C) -> Option<cell_3d_faces::Cell3DFaces> {
+    if D != 3 {
+        return None;
+    }
+    cell_3d_faces::Cell3DFaces::from_faces(cell.vertices(), &cell.faces(), cell.neighbors())
+}
+
+/// Calculates the unit normal of a face given by its indices into the flat `vertices`, following
+/// its winding order: the normal of the edge in 2D and Newell's normal of the polygon in 3D.
+/// Returns zero for degenerate faces.
+pub(crate) fn polygon_normal<const D: usize>(vertices: &[f64], face: &[usize]) -> [f64; D] {
+    let points: Vec<&[f64]> = face.iter().filter_map(|&v| vertices.get(v * D..(v + 1) * D)).collect();
+    let normal = match points.as_slice() {
+        [a, b] if D == 2 => vec![b[1] - a[1], a[0] - b[0]],
+        [_, _, _, ..] if D == 3 => {
+            let mut n = vec![0.0; 3];
+            for (k, a) in points.iter().enumerate() {
+                let b = points[(k + 1) % points.len()];
+                n[0] += (a[1] - b[1]) * (a[2] + b[2]);
+                n[1] += (a[2] - b[2]) * (a[0] + b[0]);
+                n[2] += (a[0] - b[0]) * (a[1] + b[1]);
+            }
+            n
+        }
+        _ => return [0.0; D],
+    };
+    let length = normal.iter().map(|x| x * x).sum::<f64>().sqrt();
+    if length == 0.0 {
+        return [0.0; D];
+    }
+    std::array::from_fn(|k| normal[k] / length)
+}
+
+/// Calculates the interior angle (in radians) at each vertex of a polygon with the flat 2D
+/// `vertices` and edges as `faces`. It is zero at vertices not shared by exactly two edges.
+fn polygon_angles(vertices: &[f64], faces: &[Vec<usize>]) -> Vec<f64> {
+    let n = vertices.len() / 2;
+    let mut ends = vec![Vec::new(); n];
+    for face in faces {
+        if let [a, b] = face[..]
+            && a < n
+            && b < n
+        {
+            ends[a].push(b);
+            ends[b].push(a);
+        }
+    }
+    ends.iter()
+        .enumerate()
+        .map(|(v, e)| match e[..] {
+            [p, q] => {
+                let (ax, ay) = (vertices[p * 2] - vertices[v * 2], vertices[p * 2 + 1] - vertices[v * 2 + 1]);
+                let (bx, by) = (vertices[q * 2] - vertices[v * 2], vertices[q * 2 + 1] - vertices[v * 2 + 1]);
+                (ax * by - ay * bx).abs().atan2(ax * bx + ay * by)
+            }
+            _ => 0.0,
+        })
+        .collect()
+}
+
+/// A single clipping operation applied to a cell, recorded with the `clip-log` feature.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct ClipRecord<const D: usize> {
+    /// The ID of the generator or wall that produced the clipping plane.
+    pub neighbor_id: i32,
+    /// A point on the clipping plane.
+    pub point: [f64; D],
+    /// The normal of the clipping plane, pointing out of the cell.
+    pub normal: [f64; D],
+    /// Whether the clipping plane modified the cell.
+    pub modified: bool,
+}
+
 /// Trait defining the behavior of a Voronoi cell.
-/// This allows swapping between simple Polygon cells (`Cell`) and Graph-based cells (`CellEdges`).
+///
+/// This is the extension point for the cell representation of a [`Tessellation`](crate::Tessellation),
+/// implemented by [`Cell2D`](crate::Cell2D) and [`Cell3DFaces`](crate::Cell3DFaces). Custom cell types,
+/// for example cells that only accumulate a volume or store `f32` vertices, can be plugged in by
+/// implementing it; see `examples/custom_cell.rs` for a minimal implementation.
+///
+/// A tessellation computes each cell by calling [`Cell::new`] with the bounding box, and then
+/// [`Cell::clip`] with the planes of the walls and of the bisectors with the neighboring generators.
+/// The neighbor search stops once the candidates are further away than twice the radius returned
+/// by [`Cell::max_radius_sq`] and [`Cell::clip`], so these must bound the cell for the result to be
+/// correct. Cells are computed in parallel, each thread reusing one [`Cell::Scratch`] for all its cells.
+///
+/// Besides the clipping, implementations must provide the decomposition into simplices of
+/// [`Cell::for_each_simplex`], from which the volume and the integrals over the cell are derived.
+/// The geometric measures, such as [`Cell::face_area`], [`Cell::dihedral_angles`] or
+/// [`Cell::tetrahedralize`], default to evaluating [`Cell::vertices`] and [`Cell::faces`], so
+/// implementations should provide these as well.
 pub trait Cell<const D: usize>: Send + Sync + Sized + Clone {
     /// Scratch buffer used to avoid allocations during clipping.
+    ///
+    /// A scratch buffer is shared by consecutive clips of different cells on the same thread,
+    /// so it must not carry state from one clip to the next.
     type Scratch: Default + Clone + Send;
 
     /// Initialize a new cell for the given generator index and bounds.
     fn new(id: usize, bounds: BoundingBox<D>) -> Self;
 
-    /// Clip the cell by a plane defined by `point` and `normal`.
+    /// Clip the cell by a plane defined by `point` and `normal`, keeping the half-space
+    /// on the opposite side of the normal. The face created by the plane is labeled `neighbor_id`,
+    /// which is a generator index or a negative wall ID.
+    ///
     /// Returns `(true, new_max_radius_sq)` if the cell was modified, or `(false, 0.0)` if not.
+    /// When `generator` is given, `new_max_radius_sq` is the [`Cell::max_radius_sq`] around it
+    /// after the clip, which tightens the neighbor search; otherwise it may be `0.0`.
     fn clip(
         &mut self,
         point: &[f64; D],
@@ -29,12 +288,264 @@ pub trait Cell<const D: usize>: Send + Sync + Sized + Clone {
     /// Calculate the centroid of the cell.
     fn centroid(&self) -> [f64; D];
 
+    /// Calculate the D-dimensional volume of the cell (the area in 2D).
+    /// The default implementation sums the simplices of [`Cell::for_each_simplex`].
+    fn volume(&self) -> f64 {
+        let mut total = 0.0;
+        self.for_each_simplex(&mut |_, volume| total += volume);
+        total
+    }
+
     /// Check if the cell is empty (collapsed).
     fn is_empty(&self) -> bool;
 
+    /// Get the vertex coordinates as a flat array `[x, y, (z), ...]`.
+    /// The default implementation returns no vertices.
+    fn vertices(&self) -> &[f64] {
+        &[]
+    }
+
     /// Get the indices of neighboring cells. Negative values indicate bounding box or wall boundaries.
     fn neighbors(&self) -> &[i32];
 
+    /// Calculate the area of the face at `face_index` (the length of the edge in 2D).
+    /// The face index corresponds to the position in [`Cell::neighbors`].
+    /// The default implementation measures the polygon of [`Cell::faces`] by a fan of triangles,
+    /// and returns zero for faces without vertices.
+    fn face_area(&self, face_index: usize) -> f64 {
+        let vertices = self.vertices();
+        let Some(face) = self.faces().into_iter().nth(face_index) else { return 0.0 };
+        let point = |v: usize| vertices.get(v * D..(v + 1) * D);
+        let points: Vec<&[f64]> = face.iter().filter_map(|&v| point(v)).collect();
+        let diff = |a: &[f64], b: &[f64]| -> Vec<f64> { a.iter().zip(b).map(|(x, y)| x - y).collect() };
+        let dot = |a: &[f64], b: &[f64]| -> f64 { a.iter().zip(b).map(|(x, y)| x * y).sum() };
+        match points.as_slice() {
+            [a, b] => dot(&diff(b, a), &diff(b, a)).sqrt(),
+            [a, rest @ ..] => rest
+                .windows(2)
+                .map(|w| {
+                    let (u, v) = (diff(w[0], a), diff(w[1], a));
+                    0.5 * (dot(&u, &u) * dot(&v, &v) - dot(&u, &v).powi(2)).max(0.0).sqrt()
+                })
+                .sum(),
+            [] => 0.0,
+        }
+    }
+
+    /// Calculate the unit normal of the face at `face_index`, following the winding order of its
+    /// vertices, or zero for a missing or degenerate face.
+    /// The default implementation takes the normal of the edge of [`Cell::faces`] in 2D and
+    /// Newell's normal of its polygon in 3D.
+    fn face_normal(&self, face_index: usize) -> [f64; D] {
+        self.faces().get(face_index).map_or([0.0; D], |face| polygon_normal(self.vertices(), face))
+    }
+
+    /// Get the vertex indices of each face, in the order of [`Cell::neighbors`].
+    /// The default implementation returns an empty list.
+    fn faces(&self) -> Vec<Vec<usize>> {
+        Vec::new()
+    }
+
+    /// Calculate the diameter of the cell, the largest distance between two of its vertices,
+    /// which bounds the distance between any two points of the cell. It is zero for an empty cell.
+    /// The default implementation compares all pairs of vertices.
+    fn diameter(&self) -> f64 {
+        let vertices: Vec<&[f64]> = self.vertices().chunks_exact(D).collect();
+        let mut max_sq: f64 = 0.0;
+        for (i, a) in vertices.iter().enumerate() {
+            for b in &vertices[i + 1..] {
+                max_sq = max_sq.max(a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum());
+            }
+        }
+        max_sq.sqrt()
+    }
+
+    /// Calculate the length of each edge of the cell (the faces themselves in 2D).
+    /// The default implementation collects the edges of [`Cell::faces`] in the order they first appear.
+    fn edge_lengths(&self) -> Vec<f64> {
+        let vertices = self.vertices();
+        let mut edges: Vec<(usize, usize)> = Vec::new();
+        for face in self.faces() {
+            for (k, &a) in face.iter().enumerate() {
+                let b = face[(k + 1) % face.len()];
+                let key = (a.min(b), a.max(b));
+                if !edges.contains(&key) {
+                    edges.push(key);
+                }
+            }
+        }
+        edges
+            .iter()
+            .filter_map(|&(a, b)| {
+                let (p, q) = (vertices.get(a * D..(a + 1) * D)?, vertices.get(b * D..(b + 1) * D)?);
+                Some(p.iter().zip(q).map(|(x, y)| (x - y) * (x - y)).sum::<f64>().sqrt())
+            })
+            .collect()
+    }
+
+    /// Calculate the interior angle (in radians) between adjacent faces: the dihedral angle
+    /// at each edge in 3D and the angle at each vertex in 2D.
+    /// The default implementation measures the faces of [`Cell::faces`] as in [`Cell2D`](crate::Cell2D)
+    /// and [`Cell3DFaces`](crate::Cell3DFaces).
+    fn dihedral_angles(&self) -> Vec<f64> {
+        if D == 2 {
+            return polygon_angles(self.vertices(), &self.faces());
+        }
+        as_polyhedron(self).map(|cell| cell.dihedral_angles()).unwrap_or_default()
+    }
+
+    /// Calculates texture coordinates for the vertices of each face, in the order of [`Cell::faces`],
+    /// by projecting them onto a planar frame of the face in world units.
+    /// The default implementation projects the faces of [`Cell::faces`] as in [`Cell3DFaces`](crate::Cell3DFaces).
+    /// Faces have no texture coordinates in 2D, where it returns an empty list.
+    fn face_uvs(&self) -> Vec<Vec<[f64; 2]>> {
+        as_polyhedron(self).map(|cell| cell.face_uvs()).unwrap_or_default()
+    }
+
+    /// Unfolds the faces of the cell into a planar net, for example to fabricate cells from paper
+    /// or sheet metal. The default implementation unfolds the faces of [`Cell::faces`] as in
+    /// [`Cell3DFaces`](crate::Cell3DFaces). Cells have no net in 2D, where it returns an empty net.
+    fn unfold_net(&self) -> CellNet {
+        as_polyhedron(self).map(|cell| cell.unfold_net()).unwrap_or_default()
+    }
+
+    /// Calculate the interior solid angle (in steradians) of the cell at each vertex, in the order of
+    /// [`Cell::vertices`]. In 2D this is the interior angle at each vertex, as in [`Cell::dihedral_angles`].
+    /// The default implementation measures the faces of [`Cell::faces`] as in [`Cell2D`](crate::Cell2D)
+    /// and [`Cell3DFaces`](crate::Cell3DFaces).
+    fn solid_angles(&self) -> Vec<f64> {
+        if D == 2 {
+            return polygon_angles(self.vertices(), &self.faces());
+        }
+        as_polyhedron(self).map(|cell| cell.solid_angles()).unwrap_or_default()
+    }
+
+    /// Calculates the interface tensor of the cell, the sum over its faces of the face area times
+    /// the outer product of the unit face normal with itself, which measures the structural
+    /// anisotropy of cellular materials. Its trace is the surface area (the perimeter in 2D).
+    /// The default implementation sums [`Cell::face_area`] and [`Cell::face_normal`] over the faces.
+    fn interface_tensor(&self) -> [[f64; D]; D] {
+        let mut tensor = [[0.0; D]; D];
+        for f in 0..self.neighbors().len() {
+            let (normal, area) = (self.face_normal(f), self.face_area(f));
+            for (row, ni) in tensor.iter_mut().zip(&normal) {
+                for (t, nj) in row.iter_mut().zip(&normal) {
+                    *t += area * ni * nj;
+                }
+            }
+        }
+        tensor
+    }
+
+    /// Decomposes the cell into positively oriented tetrahedra for volume meshing, see
+    /// [`Tetrahedralization`]. Degenerate tetrahedra, as from collinear face vertices, are left out.
+    /// The default implementation triangulates the faces of [`Cell::faces`] like [`Cell3DFaces`](crate::Cell3DFaces),
+    /// with all tetrahedra assigned to cell 0. It returns an empty mesh in 2D.
+    fn tetrahedralize(&self, method: Tetrahedralization) -> TetMesh {
+        if D != 3 {
+            return TetMesh::default();
+        }
+        tetrahedralize_polyhedron(self.vertices(), &self.faces(), 0, method)
+    }
+
+    /// Calls `f` with the vertices and the volume (the area in 2D) of each simplex in a decomposition
+    /// of the cell into triangles in 2D or tetrahedra in 3D.
+    ///
+    /// This is required, as [`Cell::volume`], [`Cell::integrate`] and [`Cell::sample_points`] are
+    /// built on it and would otherwise silently see an empty cell.
+    fn for_each_simplex(&self, f: &mut dyn FnMut(&[[f64; D]], f64));
+
+    /// Integrates the scalar `field` over the cell with the given quadrature rule,
+    /// applied to the simplices of [`Cell::for_each_simplex`].
+    fn integrate<F: Fn([f64; D]) -> f64>(&self, field: F, quadrature: Quadrature) -> f64 {
+        let mut total = 0.0;
+        self.for_each_simplex(&mut |simplex, volume| {
+            quadrature.for_each_point(simplex, |w, p| total += volume * w * field(p));
+        });
+        total
+    }
+
+    /// Calculate the centroid of the cell weighted by the scalar `field`, like a center of mass
+    /// for a density. Falls back to [`Cell::centroid`] if the integrated field vanishes.
+    fn weighted_centroid<F: Fn([f64; D]) -> f64>(&self, field: F, quadrature: Quadrature) -> [f64; D] {
+        let mut mass = 0.0;
+        let mut moment = [0.0; D];
+        self.for_each_simplex(&mut |simplex, volume| {
+            quadrature.for_each_point(simplex, |w, p| {
+                let m = volume * w * field(p);
+                mass += m;
+                for (moment, x) in moment.iter_mut().zip(p) {
+                    *moment += m * x;
+                }
+            });
+        });
+        if mass.abs() < f64::MIN_POSITIVE {
+            return self.centroid();
+        }
+        moment.map(|m| m / mass)
+    }
+
+    /// Generates `n` random points uniformly distributed inside the cell, as a flat array `[x, y, (z), ...]`.
+    ///
+    /// A simplex of [`Cell::for_each_simplex`] is selected with a probability proportional to its
+    /// volume, and a point is drawn uniformly from it. Returns an empty array for an empty cell.
+    fn sample_points<R: Rng + ?Sized>(&self, n: usize, rng: &mut R) -> Vec<f64> {
+        let mut simplices: Vec<[f64; D]> = Vec::new();
+        let mut cumulative = Vec::new();
+        let mut total = 0.0;
+        self.for_each_simplex(&mut |simplex, volume| {
+            total += volume;
+            cumulative.push(total);
+            simplices.extend_from_slice(simplex);
+        });
+        if total <= 0.0 {
+            return Vec::new();
+        }
+
+        let mut points = Vec::with_capacity(n * D);
+        let mut cuts = [0.0; D];
+        for _ in 0..n {
+            let t = rng.r#gen::<f64>() * total;
+            let s = cumulative.partition_point(|&c| c <= t).min(cumulative.len() - 1);
+            let simplex = &simplices[s * (D + 1)..(s + 1) * (D + 1)];
+
+            // The gaps between sorted uniform values are uniform barycentric coordinates.
+            cuts.iter_mut().for_each(|c| *c = rng.r#gen::<f64>());
+            cuts.sort_unstable_by(f64::total_cmp);
+            let mut previous = 0.0;
+            let mut point = [0.0; D];
+            for (i, vertex) in simplex.iter().enumerate() {
+                let next = if i < D { cuts[i] } else { 1.0 };
+                let weight = next - previous;
+                previous = next;
+                for (x, v) in point.iter_mut().zip(vertex) {
+                    *x += weight * v;
+                }
+            }
+            points.extend_from_slice(&point);
+        }
+        points
+    }
+
+    /// Returns `true` if a clip was rejected because the cell exceeded the capacity of its
+    /// index representation. Such a cell is incomplete, as it misses the rejected clip.
+    fn overflowed(&self) -> bool {
+        false
+    }
+
+    /// Collapses the face at `face_index` into a single point, removing it from the cell.
+    /// Returns `true` if the face was removed.
+    fn collapse_face(&mut self, face_index: usize) -> bool {
+        let _ = face_index;
+        false
+    }
+
+    /// Returns the sequence of clipping planes applied to this cell.
+    /// This is only recorded when the `clip-log` feature is enabled, and empty otherwise.
+    fn clip_log(&self) -> &[ClipRecord<D>] {
+        &[]
+    }
+
     /// Returns the vertices shared by two neighboring boundaries.
     fn shared_vertices(&self, neighbor_a: i32, neighbor_b: i32) -> Vec<f64> {
         let _ = neighbor_a;
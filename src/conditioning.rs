@@ -0,0 +1,115 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+
+/// The distance, relative to the largest extent of the bounding box, below which
+/// two vertices of a cell are considered to be near-degenerate.
+const DEGENERATE_TOLERANCE: f64 = 1e-9;
+
+/// Summary of the geometric quantities that indicate the numerical conditioning of a tessellation,
+/// as returned by [`Tessellation::conditioning`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct ConditioningReport {
+    /// The smallest face area (edge length in 2D) over all cells.
+    pub min_face_area: f64,
+    /// The median face area (edge length in 2D) over all cells.
+    pub median_face_area: f64,
+    /// The shortest edge over all cells.
+    pub min_edge_length: f64,
+    /// The smallest interior angle between adjacent faces, in radians.
+    pub min_dihedral_angle: f64,
+    /// The number of vertices that lie within a relative distance of `1e-9`
+    /// of another vertex of the same cell.
+    pub degenerate_vertices: usize,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Collects the smallest geometric features of the computed cells into a [`ConditioningReport`].
+    ///
+    /// Features that approach the clipping tolerance indicate that the tolerance
+    /// is inadequate for the scale of the input and the computed volumes should not be trusted.
+    /// The minima are infinite (and the median is zero) if there are no non-empty cells.
+    pub fn conditioning(&self) -> ConditioningReport {
+        let extent = (0..D)
+            .map(|k| self.bounds.max[k] - self.bounds.min[k])
+            .fold(0.0, f64::max);
+        let threshold_sq = (extent * DEGENERATE_TOLERANCE).powi(2);
+
+        let mut report = ConditioningReport {
+            min_face_area: f64::INFINITY,
+            min_edge_length: f64::INFINITY,
+            min_dihedral_angle: f64::INFINITY,
+            ..Default::default()
+        };
+        let mut areas = Vec::new();
+
+        for cell in self.cells.iter().filter(|c| !c.is_empty()) {
+            areas.extend((0..cell.neighbors().len()).map(|f| cell.face_area(f)));
+            report.min_edge_length = cell.edge_lengths().into_iter().fold(report.min_edge_length, f64::min);
+            report.min_dihedral_angle = cell.dihedral_angles().into_iter().fold(report.min_dihedral_angle, f64::min);
+
+            let vertices: Vec<&[f64]> = cell.vertices().chunks_exact(D).collect();
+            report.degenerate_vertices += vertices
+                .iter()
+                .enumerate()
+                .filter(|&(i, a)| {
+                    vertices.iter().enumerate().any(|(j, b)| {
+                        i != j && a.iter().zip(b.iter()).map(|(x, y)| (x - y) * (x - y)).sum::<f64>() < threshold_sq
+                    })
+                })
+                .count();
+        }
+
+        if !areas.is_empty() {
+            areas.sort_unstable_by(f64::total_cmp);
+            report.min_face_area = areas[0];
+            report.median_face_area = areas[areas.len() / 2];
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Algorithm2DGrid, Algorithm3DGrid, BoundingBox, Cell2D, Cell3DFaces, Tessellation};
+    use std::f64::consts::FRAC_PI_2;
+
+    #[test]
+    fn test_conditioning_3d() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 2.0]);
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+        tess.set_generators(&[0.5, 1.0, 1.0, 1.5, 1.0, 1.0]).unwrap();
+        tess.calculate();
+
+        let report = tess.conditioning();
+        assert!((report.min_face_area - 2.0).abs() < 1e-9);
+        assert!((report.median_face_area - 2.0).abs() < 1e-9);
+        assert!((report.min_edge_length - 1.0).abs() < 1e-9);
+        assert!((report.min_dihedral_angle - FRAC_PI_2).abs() < 1e-9);
+        assert_eq!(report.degenerate_vertices, 0);
+
+        // A cubic lattice has four cells meeting at each edge, which produces degenerate faces.
+        let mut points = Vec::new();
+        for i in 0..8 {
+            points.extend_from_slice(&[0.5 + (i & 1) as f64, 0.5 + ((i >> 1) & 1) as f64, 0.5 + (i >> 2) as f64]);
+        }
+        tess.set_generators(&points).unwrap();
+        tess.calculate();
+
+        let report = tess.conditioning();
+        assert!(report.min_edge_length < 1e-9);
+        assert!(report.degenerate_vertices > 0);
+    }
+
+    #[test]
+    fn test_conditioning_2d() {
+        let bounds = BoundingBox::new([0.0, 0.0], [2.0, 1.0]);
+        let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(2, 1, &bounds));
+        tess.set_generators(&[0.5, 0.5, 1.5, 0.5]).unwrap();
+        tess.calculate();
+
+        let report = tess.conditioning();
+        assert!((report.min_edge_length - 1.0).abs() < 1e-9);
+        assert!((report.min_dihedral_angle - FRAC_PI_2).abs() < 1e-9);
+    }
+}
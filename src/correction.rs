@@ -0,0 +1,96 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::{Tessellation, point_at};
+use rayon::prelude::*;
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Estimates, for each cell, the second-order volume correction (area in 2D) for the
+    /// single-plane approximation of curved walls. Add it to [`Cell::volume`] for the estimated
+    /// volume of the cell bounded by the curved surface.
+    ///
+    /// Each cell is clipped by the tangent plane of a curved wall at the point closest to its
+    /// generator. Close to that point, the surface deviates from the plane by `0.5 * d^T S d`,
+    /// with the curvature `S` from [`crate::WallGeometry::curvature`], so integrating the
+    /// deviation over the wall face estimates the volume the plane cuts off too little
+    /// (or too much on concave walls). Walls without curvature and cells that do not provide
+    /// [`Cell::faces`] have no correction.
+    pub fn volume_corrections(&self) -> Vec<f64> {
+        self.cells
+            .par_iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                if cell.is_empty() {
+                    return 0.0;
+                }
+                let g_pos: [f64; D] = point_at(&self.generators, i);
+                let vertices = cell.vertices();
+                let faces = cell.faces();
+                let mut correction = 0.0;
+
+                for wall in self.walls.iter().filter(|w| !w.is_planar()) {
+                    let mut planes = Vec::new();
+                    wall.cut(&g_pos, &mut |point, normal| planes.push((point, normal)));
+
+                    for (face, _) in faces.iter().zip(cell.neighbors()).filter(|(_, n)| **n == wall.id()) {
+                        let points: Vec<[f64; D]> = face.iter().map(|&v| std::array::from_fn(|k| vertices[v * D + k])).collect();
+                        // A wall may cut with several planes, take the one the face lies in.
+                        let center: [f64; D] = std::array::from_fn(|k| points.iter().map(|p| p[k]).sum::<f64>() / points.len() as f64);
+                        let offset = |(point, normal): &([f64; D], [f64; D])| (0..D).map(|k| (center[k] - point[k]) * normal[k]).sum::<f64>().abs();
+                        let Some((point, _)) = planes.iter().min_by(|a, b| offset(a).total_cmp(&offset(b))) else { continue };
+                        let Some(curvature) = wall.curvature(point) else { continue };
+
+                        let deviation = |x: &[f64; D]| {
+                            let d: [f64; D] = std::array::from_fn(|k| x[k] - point[k]);
+                            0.5 * (0..D).map(|r| (0..D).map(|c| d[r] * curvature[r][c] * d[c]).sum::<f64>()).sum::<f64>()
+                        };
+                        correction -= integrate_quadratic(&points, deviation);
+                    }
+                }
+                correction
+            })
+            .collect()
+    }
+}
+
+/// Integrates a quadratic function exactly over a segment or a planar convex polygon.
+fn integrate_quadratic<const D: usize>(points: &[[f64; D]], f: impl Fn(&[f64; D]) -> f64) -> f64 {
+    let midpoint = |a: &[f64; D], b: &[f64; D]| -> [f64; D] { std::array::from_fn(|k| 0.5 * (a[k] + b[k])) };
+    match points {
+        [] | [_] => 0.0,
+        // Simpson's rule.
+        [a, b] => {
+            let length = (0..D).map(|k| (b[k] - a[k]).powi(2)).sum::<f64>().sqrt();
+            length / 6.0 * (f(a) + 4.0 * f(&midpoint(a, b)) + f(b))
+        }
+        // A fan of triangles, each with the edge midpoint rule.
+        [a, rest @ ..] => rest
+            .windows(2)
+            .map(|pair| {
+                let (b, c) = (&pair[0], &pair[1]);
+                let u: [f64; D] = std::array::from_fn(|k| b[k] - a[k]);
+                let v: [f64; D] = std::array::from_fn(|k| c[k] - a[k]);
+                let (uu, vv, uv) = (0..D).fold((0.0, 0.0, 0.0), |(uu, vv, uv), k| (uu + u[k] * u[k], vv + v[k] * v[k], uv + u[k] * v[k]));
+                let area = 0.5 * (uu * vv - uv * uv).max(0.0).sqrt();
+                area / 3.0 * (f(&midpoint(a, b)) + f(&midpoint(b, c)) + f(&midpoint(c, a)))
+            })
+            .sum(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integrate_quadratic() {
+        let square = [[0.0, 0.0, 0.0], [2.0, 0.0, 0.0], [2.0, 2.0, 0.0], [0.0, 2.0, 0.0]];
+        // The integral of x^2 + y^2 over [0, 2]^2 is 32 / 3.
+        let value = integrate_quadratic(&square, |p| p[0] * p[0] + p[1] * p[1]);
+        assert!((value - 32.0 / 3.0).abs() < 1e-12);
+
+        let segment = [[0.0, 0.0], [3.0, 4.0]];
+        // The integral of t^2 along a segment of length 5 from the origin is 125 / 3.
+        let value = integrate_quadratic(&segment, |p| p[0] * p[0] + p[1] * p[1]);
+        assert!((value - 125.0 / 3.0).abs() < 1e-12);
+    }
+}
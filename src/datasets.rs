@@ -0,0 +1,180 @@
+//! Canonical datasets with reference statistics, enabled by the `datasets` feature.
+//!
+//! The datasets are shipped as compressed assets, so they are identical on every platform and
+//! for every version of `rand`. They serve as common inputs for benchmarks and as validation
+//! targets: a tessellation of a dataset should reproduce its [`Dataset::reference`] statistics.
+//! The script that generates the assets is `assets/datasets/generate.py`.
+
+use crate::algorithm::{SpatialAlgorithm, auto_resolution};
+use crate::algorithm::algo_3d_grid::Algorithm3DGrid;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::cell::cell_3d_faces::Cell3DFaces;
+use crate::error::GeneratorError;
+use crate::tessellation::Tessellation;
+
+const POISSON_10K: &[u8] = include_bytes!("../assets/datasets/poisson_10k.bin.z");
+const SPHERE_PACKING: &[u8] = include_bytes!("../assets/datasets/sphere_packing.bin.z");
+
+/// Statistics of the interior cells of a tessellation, which do not touch the bounding box or a
+/// wall. Excluding the truncated boundary cells makes them comparable to values for infinite
+/// space. Faces without area, as between the degenerate vertices of lattices, are not counted.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DatasetStatistics {
+    /// The number of interior cells.
+    pub cells: usize,
+    /// The mean number of faces of the interior cells.
+    pub mean_faces: f64,
+    /// The variance of the interior cell volumes divided by their squared mean.
+    pub volume_variance: f64,
+}
+
+impl DatasetStatistics {
+    /// Measures the statistics of the interior cells of a calculated tessellation.
+    pub fn measure<C: Cell<3>, A: SpatialAlgorithm<3>>(tess: &Tessellation<3, C, A>) -> Self {
+        let interior: Vec<&C> = tess
+            .cells
+            .iter()
+            .filter(|c| !c.is_empty() && c.neighbors().iter().all(|&n| n >= 0))
+            .collect();
+        let n = interior.len().max(1) as f64;
+        let faces = |c: &C| {
+            let tolerance = 1e-9 * c.volume().powf(2.0 / 3.0);
+            (0..c.neighbors().len()).filter(|&f| c.face_area(f) > tolerance).count()
+        };
+        let mean_faces = interior.iter().map(|c| faces(c) as f64).sum::<f64>() / n;
+        let mean_volume = interior.iter().map(|c| c.volume()).sum::<f64>() / n;
+        let variance = interior.iter().map(|c| (c.volume() - mean_volume).powi(2)).sum::<f64>() / n;
+        Self {
+            cells: interior.len(),
+            mean_faces,
+            volume_variance: if mean_volume > 0.0 { variance / (mean_volume * mean_volume) } else { 0.0 },
+        }
+    }
+}
+
+/// A set of generators in a bounding box, with the statistics of its Voronoi tessellation.
+#[derive(Clone, Debug)]
+pub struct Dataset {
+    /// A short name of the dataset.
+    pub name: &'static str,
+    /// The bounding box that contains the generators.
+    pub bounds: BoundingBox<3>,
+    /// The generators as a flat array `[x, y, z, x, y, z, ...]`.
+    pub generators: Vec<f64>,
+    /// The sphere radii of a sphere packing, one per generator. Voronoi cells ignore the radii.
+    pub radii: Option<Vec<f64>>,
+    /// The statistics of the tessellation of the generators within `bounds`, without walls.
+    pub reference: DatasetStatistics,
+}
+
+impl Dataset {
+    /// Sets the generators of a tessellation to the dataset, replacing any existing generators.
+    pub fn load_into<C: Cell<3>, A: SpatialAlgorithm<3>>(&self, tess: &mut Tessellation<3, C, A>) -> Result<(), GeneratorError> {
+        tess.set_generators(&self.generators)
+    }
+
+    /// Creates a tessellation of the dataset in its bounding box, with a grid resolution chosen
+    /// by [`auto_resolution`]. The cells are not calculated yet.
+    pub fn tessellation(&self) -> Result<Tessellation<3, Cell3DFaces, Algorithm3DGrid>, GeneratorError> {
+        let [nx, ny, nz] = auto_resolution(self.generators.len() / 3, &self.bounds);
+        let mut tess = Tessellation::new(self.bounds, Algorithm3DGrid::new(nx, ny, nz, &self.bounds));
+        self.load_into(&mut tess)?;
+        Ok(tess)
+    }
+}
+
+/// 10,000 uniformly random points in the unit cube.
+///
+/// The interior statistics are close to the values of the Poisson-Voronoi tessellation in infinite
+/// space, 15.535 faces per cell and a normalized volume variance of 0.1790, but the interior cells
+/// near the box are biased towards fewer faces.
+pub fn poisson_10k() -> Dataset {
+    Dataset {
+        name: "poisson_10k",
+        bounds: BoundingBox::new([0.0; 3], [1.0; 3]),
+        generators: decode(POISSON_10K),
+        radii: None,
+        reference: DatasetStatistics { cells: 7660, mean_faces: 15.316, volume_variance: 0.1883 },
+    }
+}
+
+/// The face-centered cubic lattice with a unit lattice constant, 10 conventional cells along
+/// each axis for 4,000 points in the box from 0 to 10, shifted by a quarter lattice constant.
+///
+/// The interior cells are congruent rhombic dodecahedra, with 12 faces and a volume of 1/4.
+pub fn fcc_lattice() -> Dataset {
+    let basis = [[0.0, 0.0, 0.0], [0.5, 0.5, 0.0], [0.5, 0.0, 0.5], [0.0, 0.5, 0.5]];
+    let mut generators = Vec::with_capacity(4000 * 3);
+    for i in 0..10 {
+        for j in 0..10 {
+            for k in 0..10 {
+                for b in &basis {
+                    generators.extend([i as f64 + 0.25 + b[0], j as f64 + 0.25 + b[1], k as f64 + 0.25 + b[2]]);
+                }
+            }
+        }
+    }
+    Dataset {
+        name: "fcc_lattice",
+        bounds: BoundingBox::new([0.0; 3], [10.0; 3]),
+        generators,
+        radii: None,
+        reference: DatasetStatistics { cells: 2916, mean_faces: 12.0, volume_variance: 0.0 },
+    }
+}
+
+/// 2,000 non-overlapping spheres in the unit cube with radii uniform between 0.02 and 0.04,
+/// placed by random sequential addition from the largest to the smallest sphere.
+pub fn sphere_packing() -> Dataset {
+    let values = decode(SPHERE_PACKING);
+    Dataset {
+        name: "sphere_packing",
+        bounds: BoundingBox::new([0.0; 3], [1.0; 3]),
+        generators: values.chunks_exact(4).flat_map(|s| [s[0], s[1], s[2]]).collect(),
+        radii: Some(values.chunks_exact(4).map(|s| s[3]).collect()),
+        reference: DatasetStatistics { cells: 1268, mean_faces: 14.882, volume_variance: 0.0436 },
+    }
+}
+
+/// Returns all datasets.
+pub fn all() -> Vec<Dataset> {
+    vec![poisson_10k(), fcc_lattice(), sphere_packing()]
+}
+
+/// Decompresses an asset of little-endian `u16` values that map to [0, 1].
+fn decode(asset: &[u8]) -> Vec<f64> {
+    let bytes = miniz_oxide::inflate::decompress_to_vec_zlib(asset).unwrap_or_default();
+    bytes.chunks_exact(2).map(|b| u16::from_le_bytes([b[0], b[1]]) as f64 / u16::MAX as f64).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_datasets() {
+        for dataset in all() {
+            assert!(dataset.generators.iter().all(|v| (0.0..=10.0).contains(v)));
+            let mut tess = dataset.tessellation().unwrap();
+            tess.calculate();
+            let measured = DatasetStatistics::measure(&tess);
+            assert_eq!(measured.cells, dataset.reference.cells, "{}", dataset.name);
+            assert!((measured.mean_faces - dataset.reference.mean_faces).abs() < 1e-3, "{} {measured:?}", dataset.name);
+            assert!((measured.volume_variance - dataset.reference.volume_variance).abs() < 1e-4, "{} {measured:?}", dataset.name);
+        }
+        assert_eq!(poisson_10k().generators.len(), 30000);
+
+        // The spheres of the packing do not overlap, up to the quantization of the asset.
+        let packing = sphere_packing();
+        let radii = packing.radii.unwrap();
+        assert_eq!(radii.len(), 2000);
+        let p = |i: usize| &packing.generators[i * 3..i * 3 + 3];
+        for i in 0..radii.len() {
+            for j in 0..i {
+                let d = (0..3).map(|k| (p(i)[k] - p(j)[k]).powi(2)).sum::<f64>().sqrt();
+                assert!(d > radii[i] + radii[j]);
+            }
+        }
+    }
+}
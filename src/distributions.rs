@@ -0,0 +1,121 @@
+//! Point distributions used by the benchmarks of this crate, enabled by the `test-util` feature.
+//!
+//! They are useful to stress-test custom configurations of spatial algorithms and cells
+//! with strongly non-uniform inputs, and to reproduce the published benchmark results.
+//! All functions return a flat array of coordinates `[x, y, z, x, y, z, ...]`.
+
+use crate::bounds::BoundingBox;
+use rand::Rng;
+
+/// Generates `count` points that are concentrated around the three central axes of the box.
+///
+/// Each coordinate is drawn uniformly and transformed by `0.5 + 4 (u - 0.5)^3`,
+/// which clusters the points towards the center planes while still spanning the full box.
+pub fn generate_axes_points<R: Rng>(count: usize, bounds: &BoundingBox<3>, rng: &mut R) -> Vec<f64> {
+    let mut points = Vec::with_capacity(count * 3);
+    
+    let w: f64 = bounds.max[0] - bounds.min[0];
+    let h: f64 = bounds.max[1] - bounds.min[1];
+    let d: f64 = bounds.max[2] - bounds.min[2];
+    
+    let transform = |val: f64| 0.5 + 4.0 * (val - 0.5).powi(3);
+
+    for _ in 0..count {
+        let x = transform(rng.r#gen::<f64>());
+        let y = transform(rng.r#gen::<f64>());
+        let z = transform(rng.r#gen::<f64>());
+
+        points.push(bounds.min[0] + x * w);
+        points.push(bounds.min[1] + y * h);
+        points.push(bounds.min[2] + z * d);
+    }
+    points
+}
+
+/// Generates `count` points uniformly distributed in a box at the center of `bounds`,
+/// which occupies 10% of its volume. The remaining volume is left empty.
+pub fn generate_central_box_points<R: Rng>(count: usize, bounds: &BoundingBox<3>, rng: &mut R) -> Vec<f64> {
+    let mut points = Vec::with_capacity(count * 3);
+    
+    let w = bounds.max[0] - bounds.min[0];
+    let h = bounds.max[1] - bounds.min[1];
+    let d = bounds.max[2] - bounds.min[2];
+    
+    let cx = (bounds.min[0] + bounds.max[0]) / 2.0;
+    let cy = (bounds.min[1] + bounds.max[1]) / 2.0;
+    let cz = (bounds.min[2] + bounds.max[2]) / 2.0;
+
+    // Scale factor for 10% volume: s = cbrt(0.1)
+    let s = 0.1f64.powf(1.0/3.0);
+    
+    for _ in 0..count {
+        points.push(cx + (rng.r#gen::<f64>() - 0.5) * w * s);
+        points.push(cy + (rng.r#gen::<f64>() - 0.5) * h * s);
+        points.push(cz + (rng.r#gen::<f64>() - 0.5) * d * s);
+    }
+    points
+}
+
+/// Generates `count` points on the surface of a sphere at the center of `bounds`,
+/// with a radius of a third of the smallest box extent. The first point is the sphere center,
+/// which results in a single cell with a very large number of faces.
+pub fn generate_sphere_surface_points<R: Rng>(count: usize, bounds: &BoundingBox<3>, rng: &mut R) -> Vec<f64> {
+    let mut points = Vec::with_capacity(count * 3);
+    
+    let w = bounds.max[0] - bounds.min[0];
+    let h = bounds.max[1] - bounds.min[1];
+    let d = bounds.max[2] - bounds.min[2];
+    
+    let cx = (bounds.min[0] + bounds.max[0]) / 2.0;
+    let cy = (bounds.min[1] + bounds.max[1]) / 2.0;
+    let cz = (bounds.min[2] + bounds.max[2]) / 2.0;
+
+    let radius = w.min(h).min(d) / 3.0;
+
+    // Center point
+    points.push(cx);
+    points.push(cy);
+    points.push(cz);
+    
+    for _ in 1..count {
+        loop {
+            let x = rng.r#gen::<f64>() * 2.0 - 1.0;
+            let y = rng.r#gen::<f64>() * 2.0 - 1.0;
+            let z = rng.r#gen::<f64>() * 2.0 - 1.0;
+            let len_sq = x*x + y*y + z*z;
+            if len_sq > 0.0001 && len_sq <= 1.0 {
+                let len = len_sq.sqrt();
+                points.push(cx + x / len * radius);
+                points.push(cy + y / len * radius);
+                points.push(cz + z / len * radius);
+                break;
+            }
+        }
+    }
+    points
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::SeedableRng;
+    use rand::rngs::StdRng;
+
+    #[test]
+    fn test_distributions_in_bounds() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [100.0, 50.0, 20.0]);
+        let mut rng = StdRng::seed_from_u64(7);
+        for points in [
+            generate_axes_points(500, &bounds, &mut rng),
+            generate_central_box_points(500, &bounds, &mut rng),
+            generate_sphere_surface_points(500, &bounds, &mut rng),
+        ] {
+            assert_eq!(points.len(), 1500);
+            for p in points.chunks(3) {
+                for ((&v, &min), &max) in p.iter().zip(&bounds.min).zip(&bounds.max) {
+                    assert!(v >= min && v <= max);
+                }
+            }
+        }
+    }
+}
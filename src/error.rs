@@ -0,0 +1,68 @@
+use std::fmt;
+
+/// Errors returned when generator input is rejected by the [`Tessellation`](crate::Tessellation).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GeneratorError {
+    /// Some generators have a NaN or infinite coordinate.
+    /// Contains the indices of the offending generators in the input.
+    NonFinite(Vec<usize>),
+    /// A binary buffer has a length in bytes that is not a multiple of the size of a generator.
+    InvalidLength(usize),
+    /// The parent cell to subdivide does not exist or is empty, see
+    /// [`NestedTessellation::subdivide`](crate::NestedTessellation::subdivide).
+    MissingParent(usize),
+    /// The number of positions or IDs does not match the number of generators, given as `(expected, found)`.
+    CountMismatch(usize, usize),
+    /// An external generator ID is given more than once.
+    DuplicateId(u64),
+    /// A site ID is too large to be reported as a face neighbor, see
+    /// [`Tessellation::set_sites`](crate::Tessellation::set_sites).
+    InvalidSite(usize),
+}
+
+impl fmt::Display for GeneratorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GeneratorError::NonFinite(indices) => {
+                write!(f, "{} generator(s) with non-finite coordinates at indices {:?}", indices.len(), indices)
+            }
+            GeneratorError::InvalidLength(len) => {
+                write!(f, "buffer length of {} bytes is not a multiple of the generator size", len)
+            }
+            GeneratorError::MissingParent(index) => {
+                write!(f, "parent cell {} does not exist or is empty", index)
+            }
+            GeneratorError::CountMismatch(expected, found) => {
+                write!(f, "expected entries for {} generators, got {}", expected, found)
+            }
+            GeneratorError::DuplicateId(id) => {
+                write!(f, "generator ID {} is given more than once", id)
+            }
+            GeneratorError::InvalidSite(site) => {
+                write!(f, "site ID {} exceeds the largest face neighbor ID {}", site, i32::MAX)
+            }
+        }
+    }
+}
+
+impl std::error::Error for GeneratorError {}
+
+/// Errors returned when constructing a [`Wall`](crate::Wall) or wall geometry from invalid parameters.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum WallError {
+    /// The wall ID is greater than [`WALL_ID_MAX`](crate::WALL_ID_MAX).
+    InvalidId(i32),
+    /// The geometry parameters are inconsistent, with a description of the problem.
+    InvalidGeometry(&'static str),
+}
+
+impl fmt::Display for WallError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WallError::InvalidId(id) => write!(f, "Wall ID must be <= {}, got {}", crate::WALL_ID_MAX, id),
+            WallError::InvalidGeometry(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for WallError {}
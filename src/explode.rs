@@ -0,0 +1,72 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::{BOUNDARY_TOLERANCE, Tessellation};
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Returns the translation of each cell for an exploded view, pushing the cells away from
+    /// `origin` by `factor` times the offset of their centroid. Without an origin, the cells are
+    /// pushed away from the centroid of all cells. Empty cells are not moved.
+    ///
+    /// The transforms are rigid, so they can be applied to the cell geometry as is,
+    /// or baked into it with [`Tessellation::exploded_vertices`].
+    pub fn exploded_transforms(&self, origin: Option<[f64; D]>, factor: f64) -> Vec<[f64; D]> {
+        let origin = origin.unwrap_or_else(|| {
+            let mut sum = [0.0; D];
+            let mut volume = 0.0;
+            for cell in self.cells.iter().filter(|c| !c.is_empty()) {
+                let (c, v) = (cell.centroid(), cell.volume());
+                (0..D).for_each(|k| sum[k] += c[k] * v);
+                volume += v;
+            }
+            if volume > 0.0 { sum.map(|s| s / volume) } else { sum }
+        });
+        self.cells
+            .iter()
+            .map(|cell| {
+                if cell.is_empty() {
+                    return [0.0; D];
+                }
+                let c = cell.centroid();
+                std::array::from_fn(|k| factor * (c[k] - origin[k]))
+            })
+            .collect()
+    }
+
+    /// Returns the vertices of each cell moved by [`Tessellation::exploded_transforms`],
+    /// as flat arrays `[x, y, (z), ...]` in the order of [`Cell::vertices`], for exporting
+    /// an exploded view.
+    pub fn exploded_vertices(&self, origin: Option<[f64; D]>, factor: f64) -> Vec<Vec<f64>> {
+        self.cells
+            .iter()
+            .zip(self.exploded_transforms(origin, factor))
+            .map(|(cell, offset)| cell.vertices().chunks_exact(D).flat_map(|v| (0..D).map(move |k| v[k] + offset[k])).collect())
+            .collect()
+    }
+
+    /// Returns the vertices of each cell without face data, as flat arrays `[x, y, (z), ...]`,
+    /// the minimal input for the convex hull colliders of physics engines, for example to simulate
+    /// the fragments of a fractured object as rigid bodies. Vertices that are not part of a face
+    /// and vertices within a tolerance of an earlier vertex are left out. Empty cells have no vertices.
+    pub fn convex_hull_points(&self) -> Vec<Vec<f64>> {
+        self.cells
+            .iter()
+            .map(|cell| {
+                let vertices = cell.vertices();
+                let faces = cell.faces();
+                let mut used = vec![faces.is_empty(); vertices.len() / D];
+                faces.iter().flatten().for_each(|&v| used[v] = true);
+
+                let mut points: Vec<f64> = Vec::with_capacity(vertices.len());
+                for (v, _) in vertices.chunks_exact(D).zip(&used).filter(|(_, used)| **used) {
+                    let duplicate = points.chunks_exact(D).any(|p| {
+                        (0..D).map(|k| (p[k] - v[k]).powi(2)).sum::<f64>() <= BOUNDARY_TOLERANCE * BOUNDARY_TOLERANCE
+                    });
+                    if !duplicate {
+                        points.extend_from_slice(v);
+                    }
+                }
+                points
+            })
+            .collect()
+    }
+}
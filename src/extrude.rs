@@ -0,0 +1,71 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::box_side;
+use crate::cell::Cell;
+use crate::cell::cell_3d_faces::Cell3DFaces;
+use crate::tessellation::Tessellation;
+
+impl<C: Cell<2>, A: SpatialAlgorithm<2>> Tessellation<2, C, A> {
+    /// Extrudes the computed cells into prisms from `z = 0` to `z = height`, see
+    /// [`Tessellation::extrude_layers`].
+    pub fn extrude(&self, height: f64) -> Vec<Cell3DFaces> {
+        self.extrude_layers(&[0.0, height])
+    }
+
+    /// Extrudes the computed cells into stacked layers of prisms between consecutive `levels`,
+    /// which must be increasing z coordinates. The prisms are the Voronoi cells of the generators
+    /// moved to the middle of a single layer, so they can be compared with a 3D tessellation.
+    ///
+    /// The prisms are ordered by layer, and the prism of cell `i` in layer `l` has the ID
+    /// `l * n + i` for `n` cells. Like [`Cell3DFaces::new`], each prism starts with its bottom and
+    /// top faces, which neighbor the adjacent layers or the box sides along z, followed by one side
+    /// face per edge with the neighbor of the edge in the same layer. Walls keep their IDs.
+    /// Returns no prisms for fewer than two levels.
+    pub fn extrude_layers(&self, levels: &[f64]) -> Vec<Cell3DFaces> {
+        let n = self.cells.len();
+        let layers = levels.len().saturating_sub(1);
+        let mut prisms = Vec::with_capacity(layers * n);
+        for (layer, z) in levels.windows(2).enumerate() {
+            let offset = (layer * n) as i32;
+            for (i, cell) in self.cells.iter().enumerate() {
+                let mut prism = Cell3DFaces {
+                    id: layer * n + i,
+                    vertices: Vec::new(),
+                    face_counts: Vec::new(),
+                    face_indices: Vec::new(),
+                    face_neighbors: Vec::new(),
+                    overflowed: false,
+                    #[cfg(feature = "clip-log")]
+                    clip_log: Vec::new(),
+                };
+                let corners = cell.vertices().len() / 2;
+                if cell.is_empty() || corners < 3 {
+                    prisms.push(prism);
+                    continue;
+                }
+
+                // The bottom vertices come first, followed by the top vertices in the same order.
+                for level in [z[0], z[1]] {
+                    for v in cell.vertices().chunks_exact(2) {
+                        prism.vertices.extend_from_slice(&[v[0], v[1], level]);
+                    }
+                }
+                let corners16 = corners as u16;
+                prism.face_counts.extend([corners as u8, corners as u8]);
+                prism.face_indices.extend((0..corners16).rev());
+                prism.face_indices.extend(corners16..2 * corners16);
+                prism.face_neighbors.push(if layer == 0 { box_side(2, false) } else { offset - n as i32 + i as i32 });
+                prism.face_neighbors.push(if layer + 1 == layers { box_side(2, true) } else { offset + n as i32 + i as i32 });
+
+                // The edges of the counter-clockwise polygon give outward side faces.
+                for (k, &neighbor) in cell.neighbors().iter().enumerate() {
+                    let (a, b) = (k as u16, ((k + 1) % corners) as u16);
+                    prism.face_counts.push(4);
+                    prism.face_indices.extend([a, b, b + corners16, a + corners16]);
+                    prism.face_neighbors.push(if neighbor >= 0 { offset + neighbor } else { neighbor });
+                }
+                prisms.push(prism);
+            }
+        }
+        prisms
+    }
+}
@@ -0,0 +1,136 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+
+/// The resolution of the quantization relative to the size of the bounding box.
+/// Coordinates and volumes that agree to about this relative precision produce the same fingerprint.
+const QUANTIZATION: f64 = 1e-6;
+
+/// A 64-bit FNV-1a hasher. Unlike the standard library hashers its output is
+/// guaranteed to be stable across platforms and Rust versions.
+struct Fnv64(u64);
+
+impl Fnv64 {
+    fn new() -> Self {
+        Fnv64(0xcbf2_9ce4_8422_2325)
+    }
+
+    fn write_i64(&mut self, value: i64) {
+        for byte in value.to_le_bytes() {
+            self.0 ^= byte as u64;
+            self.0 = self.0.wrapping_mul(0x0000_0100_0000_01b3);
+        }
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Computes a hash of a canonical form of the computed cells.
+    ///
+    /// The canonical form consists of the sorted cell volumes, the sorted neighbors of each cell
+    /// and the sorted vertices of each cell, all quantized relative to the bounding box size.
+    /// Faces whose area quantizes to zero are left out, as whether such degenerate faces appear,
+    /// for example at vertices shared by more than `D + 1` cells of a lattice, depends on the order
+    /// in which the cells were clipped. This makes the fingerprint independent of that order and of
+    /// the spatial algorithm, so it can be used to detect drift of the results across versions or
+    /// platforms without storing the full geometry.
+    pub fn fingerprint(&self) -> u64 {
+        let extent = (0..D)
+            .map(|k| self.bounds.max[k] - self.bounds.min[k])
+            .fold(0.0, f64::max);
+        let length_step = extent * QUANTIZATION;
+        let area_step = extent.powi(D as i32 - 1) * QUANTIZATION;
+        let volume_step = self.bounds.volume() * QUANTIZATION;
+        let quantize = |v: f64, step: f64| if step > 0.0 { (v / step).round() as i64 } else { 0 };
+
+        let mut hasher = Fnv64::new();
+        hasher.write_i64(self.cells.len() as i64);
+
+        let mut volumes: Vec<i64> = self.cells.iter().map(|c| quantize(c.volume(), volume_step)).collect();
+        volumes.sort_unstable();
+        for v in volumes {
+            hasher.write_i64(v);
+        }
+
+        for cell in &self.cells {
+            let mut neighbors: Vec<i32> = cell
+                .neighbors()
+                .iter()
+                .enumerate()
+                .filter(|&(f, _)| quantize(cell.face_area(f), area_step) > 0)
+                .map(|(_, &n)| n)
+                .collect();
+            neighbors.sort_unstable();
+            hasher.write_i64(neighbors.len() as i64);
+            for n in neighbors {
+                hasher.write_i64(n as i64);
+            }
+
+            let mut vertices: Vec<[i64; D]> = cell
+                .vertices()
+                .chunks_exact(D)
+                .map(|p| std::array::from_fn(|k| quantize(p[k] - self.bounds.min[k], length_step)))
+                .collect();
+            vertices.sort_unstable();
+            vertices.dedup();
+            hasher.write_i64(vertices.len() as i64);
+            for v in vertices {
+                for x in v {
+                    hasher.write_i64(x);
+                }
+            }
+        }
+
+        hasher.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Algorithm3DGrid, Algorithm3DOctree, AlgorithmBinnedBvh, BoundingBox, Cell3DFaces, Tessellation};
+
+    #[test]
+    fn test_fingerprint() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+        let mut grid = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+        grid.random_generators_seeded(200, 7);
+        grid.calculate();
+
+        // The same generators with a different algorithm give the same cells.
+        let mut octree = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
+        octree.set_generators(&grid.generators()).unwrap();
+        octree.calculate();
+        assert_eq!(grid.fingerprint(), octree.fingerprint());
+
+        // Moving a single generator changes the fingerprint.
+        let mut g = octree.try_get_generator(0).unwrap();
+        g[0] = (g[0] + 0.5) % 10.0;
+        octree.set_generator(0, &g);
+        octree.calculate();
+        assert_ne!(grid.fingerprint(), octree.fingerprint());
+    }
+    #[test]
+    fn test_fingerprint_lattice() {
+        // Each interior vertex of a cubic lattice is shared by eight cells, so the algorithms
+        // clip in different orders and leave different degenerate faces behind.
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [6.0, 6.0, 6.0]);
+        let mut generators = Vec::new();
+        for i in 0..216 {
+            for k in [i % 6, (i / 6) % 6, i / 36] {
+                generators.push(k as f64 + 0.5);
+            }
+        }
+
+        let mut grid = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(3, 3, 3, &bounds));
+        grid.set_generators(&generators).unwrap();
+        grid.calculate();
+        let mut octree = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
+        octree.set_generators(&generators).unwrap();
+        octree.calculate();
+        let mut bvh = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(3, 3, 3, &bounds));
+        bvh.set_generators(&generators).unwrap();
+        bvh.calculate();
+
+        assert_eq!(grid.fingerprint(), octree.fingerprint());
+        assert_eq!(grid.fingerprint(), bvh.fingerprint());
+    }
+}
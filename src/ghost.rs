@@ -0,0 +1,67 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::tessellation::Tessellation;
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Sets the ghost generators, replacing any previous ones, for computing the cells of a
+    /// subdomain in a domain decomposition.
+    ///
+    /// Ghosts are the generators of neighboring subdomains within a halo around the owned
+    /// generators. They clip the owned cells like any other generator, but get no cell themselves,
+    /// so the cells of separate subdomains can be computed independently and stitched together.
+    /// Ghosts are stored after the owned generators: ghost `k` has the generator index
+    /// `count_owned() + k`, which is the ID reported in [`Cell::neighbors`] for faces shared with it.
+    /// Unlike owned generators, ghosts are neither filtered by the walls nor the [`crate::BoundaryPolicy`],
+    /// so their indices stay aligned with the input.
+    ///
+    /// Returns a [`GeneratorError::NonFinite`] with the offending ghost indices if any coordinate
+    /// is NaN or infinite, in which case the current ghosts are left unchanged.
+    pub fn set_ghosts(&mut self, ghosts: &[f64]) -> Result<(), GeneratorError> {
+        Self::check_finite(ghosts)?;
+
+        self.set_ghosts_unchecked(ghosts);
+        Ok(())
+    }
+
+    /// Sets the ghost generators without checking for non-finite coordinates.
+    pub fn set_ghosts_unchecked(&mut self, ghosts: &[f64]) {
+        let owned = self.count_owned();
+        self.generators.truncate(owned * D);
+        self.generators.extend_from_slice(&ghosts[..ghosts.len() / D * D]);
+        self.ghost_count = ghosts.len() / D;
+        self.bin_generators();
+    }
+
+    /// Removes all ghost generators.
+    pub fn clear_ghosts(&mut self) {
+        self.set_ghosts_unchecked(&[]);
+    }
+
+    /// Returns the number of owned generators, which are the generators that get a cell.
+    pub fn count_owned(&self) -> usize {
+        self.generators.len() / D - self.ghost_count
+    }
+
+    /// Returns the number of ghost generators.
+    pub fn ghost_count(&self) -> usize {
+        self.ghost_count
+    }
+
+    /// Returns the ghost generators as a flat array.
+    pub fn ghosts(&self) -> &[f64] {
+        &self.generators[self.count_owned() * D..]
+    }
+
+    /// Returns `true` if a face neighbor ID refers to a ghost generator.
+    pub fn is_ghost(&self, neighbor_id: i32) -> bool {
+        self.ghost_index(neighbor_id).is_some()
+    }
+
+    /// Maps a face neighbor ID to the index of the ghost generator it refers to,
+    /// or `None` for owned generators and walls.
+    pub fn ghost_index(&self, neighbor_id: i32) -> Option<usize> {
+        let index = usize::try_from(neighbor_id).ok()?.checked_sub(self.count_owned())?;
+        (index < self.ghost_count).then_some(index)
+    }
+}
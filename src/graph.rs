@@ -0,0 +1,174 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use std::cmp::Ordering;
+use std::collections::{BinaryHeap, HashSet};
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Labels the connected components of the neighbor graph, returning a component ID per cell.
+    ///
+    /// Two adjacent cells are connected if `filter(i, j, area)` returns true, with the area of their
+    /// shared face. Component IDs are numbered from zero in the order of their lowest cell index,
+    /// so cells rejected by every filter call form their own components. For percolation analysis,
+    /// connect cells of the same label with `|i, j, _| labels[i] == labels[j]`.
+    pub fn connected_components<F: Fn(usize, usize, f64) -> bool>(&self, filter: F) -> Vec<usize> {
+        let areas = self.face_area_matrix();
+        let mut components = vec![usize::MAX; areas.size];
+        let mut count = 0;
+        let mut stack = Vec::new();
+        for start in 0..areas.size {
+            if components[start] != usize::MAX {
+                continue;
+            }
+            components[start] = count;
+            stack.push(start);
+            while let Some(i) = stack.pop() {
+                for (j, area) in areas.row(i) {
+                    if components[j] == usize::MAX && filter(i, j, area) {
+                        components[j] = count;
+                        stack.push(j);
+                    }
+                }
+            }
+            count += 1;
+        }
+        components
+    }
+
+    /// Returns the cells within `depth` steps of cell `i` through shared faces, the k-ring of the
+    /// cell, for analyzing local environments or editing with a brush.
+    ///
+    /// The cells are ordered by their graph distance from `i`, starting with `i` itself, and by the
+    /// order of the faces within each ring. Returns an empty list if `i` is out of range.
+    pub fn neighbor_shell(&self, i: usize, depth: usize) -> Vec<usize> {
+        if i >= self.cells.len() {
+            return Vec::new();
+        }
+        let mut visited = HashSet::from([i]);
+        let mut shell = vec![i];
+        let mut ring_start = 0;
+        for _ in 0..depth {
+            let ring_end = shell.len();
+            for k in ring_start..ring_end {
+                for &n in self.cells[shell[k]].neighbors() {
+                    if n >= 0 && (n as usize) < self.cells.len() && visited.insert(n as usize) {
+                        shell.push(n as usize);
+                    }
+                }
+            }
+            if shell.len() == ring_end {
+                break;
+            }
+            ring_start = ring_end;
+        }
+        shell
+    }
+
+    /// Finds the cheapest path from cell `from` to cell `to` through adjacent cells, returning
+    /// its total cost and the visited cells including both ends.
+    ///
+    /// Stepping from cell `i` to its neighbor `j` costs `cost(i, j, area)`, with the area of their
+    /// shared face; `None` blocks the step. Costs must be non-negative. Returns `None` if `to`
+    /// cannot be reached or an index is out of range.
+    pub fn shortest_path<F: Fn(usize, usize, f64) -> Option<f64>>(&self, from: usize, to: usize, cost: F) -> Option<(f64, Vec<usize>)> {
+        let areas = self.face_area_matrix();
+        if from >= areas.size || to >= areas.size {
+            return None;
+        }
+        let mut distances = vec![f64::INFINITY; areas.size];
+        let mut previous = vec![usize::MAX; areas.size];
+        let mut queue = BinaryHeap::new();
+        distances[from] = 0.0;
+        queue.push(PathItem { cost: 0.0, cell: from });
+
+        while let Some(PathItem { cost: current, cell: i }) = queue.pop() {
+            if i == to {
+                let mut path = vec![to];
+                while let Some(&last) = path.last().filter(|&&c| c != from) {
+                    path.push(previous[last]);
+                }
+                path.reverse();
+                return Some((current, path));
+            }
+            if current > distances[i] {
+                continue;
+            }
+            for (j, area) in areas.row(i) {
+                let Some(step) = cost(i, j, area) else { continue };
+                let next = current + step;
+                if next < distances[j] {
+                    distances[j] = next;
+                    previous[j] = i;
+                    queue.push(PathItem { cost: next, cell: j });
+                }
+            }
+        }
+        None
+    }
+
+    /// Assigns each cell a color index below `max_colors` such that adjacent cells never share a
+    /// color, for example to fill the cells with distinguishable colors when rendering.
+    ///
+    /// The coloring is greedy: cells are visited in order of decreasing number of neighbors and
+    /// take the lowest color not used by a colored neighbor, which needs few colors for the
+    /// neighbor graphs of tessellations but is not minimal. Returns `None` if it needs more than
+    /// `max_colors` colors.
+    pub fn color_cells(&self, max_colors: usize) -> Option<Vec<usize>> {
+        let count = self.cells.len();
+        let mut adjacency = vec![Vec::new(); count];
+        for (i, cell) in self.cells.iter().enumerate() {
+            for &n in cell.neighbors() {
+                if n >= 0 && (n as usize) < count && n as usize != i {
+                    adjacency[i].push(n as usize);
+                    adjacency[n as usize].push(i);
+                }
+            }
+        }
+        for list in &mut adjacency {
+            list.sort_unstable();
+            list.dedup();
+        }
+
+        let mut order: Vec<usize> = (0..count).collect();
+        order.sort_by_key(|&i| std::cmp::Reverse(adjacency[i].len()));
+        let mut colors = vec![usize::MAX; count];
+        let mut used = Vec::new();
+        for i in order {
+            used.clear();
+            used.resize(max_colors, false);
+            for &j in &adjacency[i] {
+                if colors[j] < max_colors {
+                    used[colors[j]] = true;
+                }
+            }
+            colors[i] = used.iter().position(|u| !u)?;
+        }
+        Some(colors)
+    }
+}
+
+struct PathItem {
+    cost: f64,
+    cell: usize,
+}
+
+impl PartialEq for PathItem {
+    fn eq(&self, other: &Self) -> bool {
+        self.cost == other.cost
+    }
+}
+
+impl Eq for PathItem {}
+
+impl PartialOrd for PathItem {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PathItem {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reverse ordering for Min-Heap behavior
+        other.cost.total_cmp(&self.cost)
+    }
+}
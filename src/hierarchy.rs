@@ -0,0 +1,129 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::algorithm::algo_3d_grid::Algorithm3DGrid;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::tessellation::Tessellation;
+use crate::wall::{Wall, WALL_ID_MAX};
+use crate::wall::wall_3d::PlaneGeometry;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::collections::BTreeMap;
+
+/// A two-level tessellation, where selected cells of a parent tessellation are subdivided
+/// by tessellating them again with their own generators.
+///
+/// Each subdivided parent cell is the clipping domain of a child tessellation: every face of the
+/// parent cell becomes a plane wall with ID `WALL_ID_MAX - face_index`, so the boundary faces of the
+/// child cells can be traced back to the parent face with [`NestedTessellation::parent_neighbor`].
+/// Cells are addressed as `(parent, child)`, with the child index into the child tessellation.
+pub struct NestedTessellation<C: Cell<3>, A: SpatialAlgorithm<3>> {
+    pub parent: Tessellation<3, C, A>,
+    children: BTreeMap<usize, Tessellation<3, C, Algorithm3DGrid>>,
+}
+
+impl<C: Cell<3>, A: SpatialAlgorithm<3>> NestedTessellation<C, A> {
+    /// Creates a hierarchy on top of a parent tessellation without subdivided cells.
+    pub fn new(parent: Tessellation<3, C, A>) -> Self {
+        Self { parent, children: BTreeMap::new() }
+    }
+
+    /// Subdivides the parent cell at index `parent` with the given generators, replacing an existing
+    /// subdivision. Generators outside the parent cell are discarded.
+    ///
+    /// The parent cells must be calculated, the child tessellation is created from the current
+    /// geometry of the parent cell. Returns [`GeneratorError::MissingParent`] if the parent cell
+    /// does not exist or is empty.
+    pub fn subdivide(&mut self, parent: usize, generators: &[f64]) -> Result<&mut Tessellation<3, C, Algorithm3DGrid>, GeneratorError> {
+        let mut child = self.child_domain(parent, generators.len() / 3)?;
+        child.set_generators(generators)?;
+        child.calculate();
+        Ok(self.children.entry(parent).insert_entry(child).into_mut())
+    }
+
+    /// Subdivides the parent cell at index `parent` with `count` generators placed uniformly at
+    /// random inside it, reproducible from the `seed`.
+    pub fn subdivide_random(&mut self, parent: usize, count: usize, seed: u64) -> Result<&mut Tessellation<3, C, Algorithm3DGrid>, GeneratorError> {
+        let generators = self
+            .parent
+            .cells
+            .get(parent)
+            .map(|cell| cell.sample_points(count, &mut StdRng::seed_from_u64(seed)))
+            .unwrap_or_default();
+        self.subdivide(parent, &generators)
+    }
+
+    /// Removes the subdivision of the parent cell at index `parent`, returning its tessellation.
+    pub fn merge(&mut self, parent: usize) -> Option<Tessellation<3, C, Algorithm3DGrid>> {
+        self.children.remove(&parent)
+    }
+
+    /// Recalculates the cells of all child tessellations.
+    pub fn calculate(&mut self) {
+        self.children.values_mut().for_each(|child| child.calculate());
+    }
+
+    /// Returns the child tessellation of the parent cell at index `parent`, if it is subdivided.
+    pub fn child(&self, parent: usize) -> Option<&Tessellation<3, C, Algorithm3DGrid>> {
+        self.children.get(&parent)
+    }
+
+    /// Returns the indices of the subdivided parent cells, in increasing order.
+    pub fn subdivided(&self) -> Vec<usize> {
+        self.children.keys().copied().collect()
+    }
+
+    /// Returns the cell `child` of the subdivided parent cell `parent`.
+    pub fn get_cell(&self, parent: usize, child: usize) -> Option<C> {
+        self.children.get(&parent)?.get_cell(child)
+    }
+
+    /// Maps a face neighbor ID of a cell in the subdivision of `parent` to the level of the parent:
+    /// sibling IDs are returned unchanged, while the ID of a parent face is replaced by the neighbor
+    /// of that face in the parent tessellation.
+    pub fn parent_neighbor(&self, parent: usize, neighbor_id: i32) -> Option<i32> {
+        if neighbor_id >= 0 {
+            return Some(neighbor_id);
+        }
+        let face = usize::try_from(WALL_ID_MAX.checked_sub(neighbor_id)?).ok()?;
+        self.parent.cells.get(parent)?.neighbors().get(face).copied()
+    }
+
+    /// Creates an empty child tessellation bounded by the faces of the parent cell.
+    fn child_domain(&self, parent: usize, count: usize) -> Result<Tessellation<3, C, Algorithm3DGrid>, GeneratorError> {
+        let cell = match self.parent.cells.get(parent) {
+            Some(cell) if !cell.is_empty() => cell,
+            _ => return Err(GeneratorError::MissingParent(parent)),
+        };
+        let vertices: Vec<[f64; 3]> = cell.vertices().chunks_exact(3).map(|v| [v[0], v[1], v[2]]).collect();
+        let center = cell.centroid();
+
+        // The bounding box is padded, so the parent faces are cut by the walls rather than the box.
+        let min: [f64; 3] = std::array::from_fn(|k| vertices.iter().map(|v| v[k]).fold(f64::INFINITY, f64::min));
+        let max: [f64; 3] = std::array::from_fn(|k| vertices.iter().map(|v| v[k]).fold(f64::NEG_INFINITY, f64::max));
+        let pad = (0..3).map(|k| max[k] - min[k]).fold(0.0, f64::max) * 0.01;
+        let bounds = BoundingBox::new(min.map(|v| v - pad), max.map(|v| v + pad));
+
+        let mut child = Tessellation::new(bounds, Algorithm3DGrid::auto(count, &bounds));
+        for (face_index, face) in cell.faces().iter().enumerate() {
+            // Newell's method for the face normal, oriented towards the inside of the cell.
+            let mut normal = [0.0; 3];
+            let mut point = [0.0; 3];
+            for (i, &a) in face.iter().enumerate() {
+                let (p, q) = (vertices[a], vertices[face[(i + 1) % face.len()]]);
+                normal[0] += (p[1] - q[1]) * (p[2] + q[2]);
+                normal[1] += (p[2] - q[2]) * (p[0] + q[0]);
+                normal[2] += (p[0] - q[0]) * (p[1] + q[1]);
+                (0..3).for_each(|k| point[k] += p[k] / face.len() as f64);
+            }
+            if (0..3).map(|k| normal[k] * (center[k] - point[k])).sum::<f64>() < 0.0 {
+                normal = normal.map(|n| -n);
+            }
+            let id = WALL_ID_MAX - face_index as i32;
+            if let Ok(wall) = Wall::try_new(id, Box::new(PlaneGeometry::new(point, normal))) {
+                child.add_wall(wall);
+            }
+        }
+        Ok(child)
+    }
+}
@@ -0,0 +1,133 @@
+use crate::error::WallError;
+use crate::wall::WallMesh;
+use crate::wall::wall_3d::ConvexPolyhedronGeometry;
+use std::collections::{HashMap, HashSet};
+
+/// The convex hull of a point set, as a wall geometry for clipping and as a mesh for rendering.
+#[derive(Debug)]
+pub struct ConvexHull {
+    /// One plane per hull face, with coplanar triangles sharing a single plane.
+    pub geometry: ConvexPolyhedronGeometry,
+    /// The hull triangles, counter-clockwise seen from outside. Only the points on the hull are
+    /// kept as vertices.
+    pub mesh: WallMesh<3>,
+}
+
+/// Computes the convex hull of a set of points, given as a flat array [x1, y1, z1, ...].
+/// Clipping a tessellation to the hull of its data takes
+/// `tess.add_wall(Wall::try_new(id, Box::new(convex_hull(&points)?.geometry))?)`.
+///
+/// Returns an error if the array is not a multiple of 3, contains non-finite values,
+/// or if the points do not span a volume.
+pub fn convex_hull(points: &[f64]) -> Result<ConvexHull, WallError> {
+    if !points.len().is_multiple_of(3) || points.iter().any(|v| !v.is_finite()) {
+        return Err(WallError::InvalidGeometry("Vertices must be finite and a multiple of 3"));
+    }
+    let vertices: Vec<[f64; 3]> = points.chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect();
+    let Some((faces, tolerance)) = convex_hull_faces(&vertices) else {
+        return Err(WallError::InvalidGeometry("The vertices do not span a volume"));
+    };
+
+    let mut planes: Vec<([f64; 3], [f64; 3])> = Vec::with_capacity(faces.len());
+    let mut mesh = WallMesh::default();
+    let mut indices: HashMap<usize, usize> = HashMap::new();
+    for [a, b, c] in faces {
+        let n = cross(sub(&vertices[b], &vertices[a]), sub(&vertices[c], &vertices[a]));
+        let len = dot(n, n).sqrt();
+        let normal = [n[0] / len, n[1] / len, n[2] / len];
+        let offset = dot(normal, vertices[a]);
+        let coplanar = planes.iter().any(|(p, m)| dot(normal, *m) > 1.0 - 1e-9 && (dot(*m, *p) - offset).abs() <= tolerance);
+        if !coplanar {
+            planes.push((vertices[a], normal));
+        }
+        mesh.elements.push([a, b, c].map(|v| {
+            *indices.entry(v).or_insert_with(|| {
+                mesh.vertices.extend_from_slice(&vertices[v]);
+                mesh.vertices.len() / 3 - 1
+            })
+        }));
+    }
+    Ok(ConvexHull { geometry: ConvexPolyhedronGeometry { planes }, mesh })
+}
+
+fn sub(a: &[f64; 3], b: &[f64; 3]) -> [f64; 3] {
+    [a[0] - b[0], a[1] - b[1], a[2] - b[2]]
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+fn cross(a: [f64; 3], b: [f64; 3]) -> [f64; 3] {
+    [a[1] * b[2] - a[2] * b[1], a[2] * b[0] - a[0] * b[2], a[0] * b[1] - a[1] * b[0]]
+}
+
+/// Computes the triangles of the convex hull of `points` incrementally, oriented counter-clockwise
+/// seen from outside, together with the distance tolerance used for coplanarity. Returns `None`
+/// if the points do not span a volume.
+fn convex_hull_faces(points: &[[f64; 3]]) -> Option<(Vec<[usize; 3]>, f64)> {
+    let extent = (0..3)
+        .map(|k| {
+            let (lo, hi) = points.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), p| (lo.min(p[k]), hi.max(p[k])));
+            hi - lo
+        })
+        .fold(0.0, f64::max);
+    if points.len() < 4 || extent <= 0.0 {
+        return None;
+    }
+    let tolerance = 1e-10 * extent;
+    let argmax = |f: &dyn Fn(&[f64; 3]) -> f64| (0..points.len()).max_by(|&i, &j| f(&points[i]).total_cmp(&f(&points[j]))).unwrap_or(0);
+
+    // The initial tetrahedron spans the points as widely as possible.
+    let i0 = 0;
+    let i1 = argmax(&|p| {
+        let d = sub(p, &points[i0]);
+        dot(d, d)
+    });
+    let axis = sub(&points[i1], &points[i0]);
+    let i2 = argmax(&|p| {
+        let c = cross(sub(p, &points[i0]), axis);
+        dot(c, c)
+    });
+    let normal = cross(axis, sub(&points[i2], &points[i0]));
+    let length = dot(normal, normal).sqrt();
+    if length <= tolerance * extent {
+        return None;
+    }
+    let i3 = argmax(&|p| dot(sub(p, &points[i0]), normal).abs());
+    if dot(sub(&points[i3], &points[i0]), normal).abs() / length <= tolerance {
+        return None;
+    }
+
+    let interior: [f64; 3] = std::array::from_fn(|k| 0.25 * (points[i0][k] + points[i1][k] + points[i2][k] + points[i3][k]));
+    let distance = |[a, b, c]: [usize; 3], p: &[f64; 3]| {
+        let n = cross(sub(&points[b], &points[a]), sub(&points[c], &points[a]));
+        dot(n, sub(p, &points[a])) / dot(n, n).sqrt()
+    };
+    let mut faces: Vec<[usize; 3]> = [[i0, i1, i2], [i0, i1, i3], [i0, i2, i3], [i1, i2, i3]]
+        .into_iter()
+        .map(|[a, b, c]| if distance([a, b, c], &interior) > 0.0 { [a, c, b] } else { [a, b, c] })
+        .collect();
+
+    for (i, p) in points.iter().enumerate() {
+        if [i0, i1, i2, i3].contains(&i) {
+            continue;
+        }
+        let visible: Vec<bool> = faces.iter().map(|&f| distance(f, p) > tolerance).collect();
+        if !visible.contains(&true) {
+            continue;
+        }
+        // The horizon consists of the edges of visible faces whose opposite face is hidden.
+        let edges: HashSet<(usize, usize)> = faces
+            .iter()
+            .zip(&visible)
+            .filter(|(_, v)| **v)
+            .flat_map(|(&[a, b, c], _)| [(a, b), (b, c), (c, a)])
+            .collect();
+        let horizon: Vec<(usize, usize)> = edges.iter().copied().filter(|&(a, b)| !edges.contains(&(b, a))).collect();
+        let mut visible = visible.into_iter();
+        faces.retain(|_| !visible.next().unwrap_or(false));
+        faces.extend(horizon.into_iter().map(|(a, b)| [a, b, i]));
+    }
+    Some((faces, tolerance))
+}
@@ -0,0 +1,108 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::tessellation::Tessellation;
+use std::collections::HashSet;
+
+/// A face neighbor in terms of external generator IDs, as returned by
+/// [`Tessellation::face_neighbor_ids`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum NeighborId {
+    /// A generator, by its external ID, or its index if no IDs are registered.
+    Generator(u64),
+    /// A wall or bounding box side, by its wall ID.
+    Wall(i32),
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Registers an external ID for each generator, including ghosts, such as the 64-bit IDs of
+    /// particles in a simulation. Unlike the generator indices, the IDs stay attached to their
+    /// generators when [`Tessellation::add_wall`] prunes generators outside the walls.
+    ///
+    /// The IDs are cleared when the generators are replaced, and are ignored once the number of
+    /// generators changes otherwise, e.g. by [`Tessellation::set_ghosts`].
+    ///
+    /// Returns a [`GeneratorError::CountMismatch`] if there is not one ID per generator, or a
+    /// [`GeneratorError::DuplicateId`] if an ID is not unique, leaving the current IDs unchanged.
+    pub fn set_generator_ids(&mut self, ids: &[u64]) -> Result<(), GeneratorError> {
+        if ids.len() != self.count_generators() {
+            return Err(GeneratorError::CountMismatch(self.count_generators(), ids.len()));
+        }
+        check_unique(ids)?;
+        self.register_generator_ids(ids.to_vec());
+        Ok(())
+    }
+
+    /// Sets the generators like [`Tessellation::set_generators`] together with their external IDs,
+    /// keeping the IDs of the generators accepted by the walls and the [`crate::BoundaryPolicy`].
+    ///
+    /// Returns a [`GeneratorError::CountMismatch`] if there is not one ID per generator, or a
+    /// [`GeneratorError::DuplicateId`] if an ID is not unique, in addition to the errors of
+    /// [`Tessellation::set_generators`]. On error, the current generators are left unchanged.
+    pub fn set_generators_with_ids(&mut self, generators: &[f64], ids: &[u64]) -> Result<(), GeneratorError> {
+        if ids.len() != generators.len() / D {
+            return Err(GeneratorError::CountMismatch(generators.len() / D, ids.len()));
+        }
+        Self::check_finite(generators)?;
+        check_unique(ids)?;
+
+        let accepted = self.set_generators_filtered(generators);
+        self.register_generator_ids(accepted.iter().map(|&i| ids[i]).collect());
+        self.clear_sites();
+        Ok(())
+    }
+
+    /// Removes the external IDs, so generators are identified by their indices again.
+    pub fn clear_generator_ids(&mut self) {
+        self.generator_ids.clear();
+        self.id_lookup.clear();
+    }
+
+    /// Returns `true` if external IDs are registered for the current generators.
+    pub fn has_generator_ids(&self) -> bool {
+        !self.generator_ids.is_empty() && self.generator_ids.len() == self.count_generators()
+    }
+
+    /// Returns the external ID of a generator, or its index if no IDs are registered.
+    pub fn generator_id(&self, index: usize) -> u64 {
+        if self.has_generator_ids() && let Some(&id) = self.generator_ids.get(index) { id } else { index as u64 }
+    }
+
+    /// Returns the index of the generator with an external ID, or `None` if there is none.
+    /// Without registered IDs, the ID is the index itself.
+    pub fn generator_index(&self, id: u64) -> Option<usize> {
+        if self.has_generator_ids() {
+            self.id_lookup.get(&id).copied()
+        } else {
+            (id < self.count_generators() as u64).then_some(id as usize)
+        }
+    }
+
+    /// Returns the neighbors of the faces of a computed cell in terms of external IDs,
+    /// in the order of [`Cell::neighbors`], or `None` if the cell does not exist.
+    pub fn face_neighbor_ids(&self, cell: usize) -> Option<Vec<NeighborId>> {
+        let cell = self.cells.get(cell)?;
+        let neighbors = cell
+            .neighbors()
+            .iter()
+            .map(|&n| if n >= 0 { NeighborId::Generator(self.generator_id(n as usize)) } else { NeighborId::Wall(n) })
+            .collect();
+        Some(neighbors)
+    }
+
+    /// Stores IDs for the current generators, which have been checked to be unique.
+    pub(crate) fn register_generator_ids(&mut self, ids: Vec<u64>) {
+        self.id_lookup = ids.iter().enumerate().map(|(i, &id)| (id, i)).collect();
+        self.generator_ids = ids;
+    }
+}
+
+fn check_unique(ids: &[u64]) -> Result<(), GeneratorError> {
+    let mut seen = HashSet::with_capacity(ids.len());
+    for &id in ids {
+        if !seen.insert(id) {
+            return Err(GeneratorError::DuplicateId(id));
+        }
+    }
+    Ok(())
+}
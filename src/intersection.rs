@@ -0,0 +1,172 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::box_side;
+use crate::cell::Cell;
+use crate::tessellation::{Tessellation, point_at};
+use rayon::prelude::*;
+use std::collections::{HashSet, VecDeque};
+
+/// The volume, relative to the source cell, below which an overlap is considered degenerate.
+/// Cells sharing only a face clip each other to such slivers.
+const OVERLAP_TOLERANCE: f64 = 1e-12;
+
+/// The overlap of a cell of one tessellation with a cell of another,
+/// as returned by [`Tessellation::intersect`].
+#[derive(Clone, Debug)]
+pub struct CellOverlap<C> {
+    /// The index of the cell in the tessellation that was intersected.
+    pub source: usize,
+    /// The index of the cell in the other tessellation.
+    pub target: usize,
+    /// The volume (area in 2D) of the overlap.
+    pub volume: f64,
+    /// The overlap as a cell, clipped from the source cell. Faces taken from the target cell
+    /// are labeled with the neighbors of the target cell.
+    pub cell: C,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Intersects the cells with the cells of another tessellation over the same domain,
+    /// returning the overlap of every pair of cells with a non-zero volume.
+    ///
+    /// Each cell is clipped by the bounding box, the walls and the bisector planes that bound the
+    /// overlapping cells of `other`, which are found by walking its neighbor graph. The overlaps
+    /// of a cell add up to its volume wherever `other` covers it, so quantities can be remapped
+    /// conservatively between the tessellations by weighting with `volume`.
+    ///
+    /// Both tessellations must be calculated. Cells outside the walls of `other` have no overlaps,
+    /// and are found by scanning all cells of `other`.
+    pub fn intersect<C2: Cell<D>, B: SpatialAlgorithm<D>>(&self, other: &Tessellation<D, C2, B>) -> Vec<CellOverlap<C>> {
+        if other.cells.is_empty() {
+            return Vec::new();
+        }
+        let overlaps: Vec<Vec<CellOverlap<C>>> = self
+            .cells
+            .par_iter()
+            .enumerate()
+            .map_init(
+                || (C::Scratch::default(), 0),
+                |(scratch, hint), (i, cell)| {
+                    if cell.is_empty() {
+                        return Vec::new();
+                    }
+                    let center = cell.centroid();
+                    let mut start = nearest_generator(other, *hint, &center);
+                    let mut overlaps = collect_overlaps(i, cell, other, start, scratch);
+                    if overlaps.is_empty() {
+                        // The walk gets stuck if walls split the neighbor graph of `other`.
+                        start = (0..other.cells.len())
+                            .filter(|&j| !other.cells[j].is_empty())
+                            .min_by(|&a, &b| distance_sq(&point_at(&other.generators, a), &center).total_cmp(&distance_sq(&point_at(&other.generators, b), &center)))
+                            .unwrap_or(start);
+                        overlaps = collect_overlaps(i, cell, other, start, scratch);
+                    }
+                    *hint = start;
+                    overlaps
+                },
+            )
+            .collect();
+        overlaps.into_iter().flatten().collect()
+    }
+}
+
+/// Walks the neighbor graph of `other` from the cell `start` towards the generator closest to `point`.
+fn nearest_generator<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>>(other: &Tessellation<D, C, A>, start: usize, point: &[f64; D]) -> usize {
+    let mut current = start.min(other.cells.len() - 1);
+    let mut current_dist = distance_sq(&point_at(&other.generators, current), point);
+    loop {
+        let mut next = current;
+        for &k in other.cells[current].neighbors() {
+            if k < 0 || k as usize >= other.cells.len() {
+                continue;
+            }
+            let dist = distance_sq(&point_at(&other.generators, k as usize), point);
+            if dist < current_dist {
+                current_dist = dist;
+                next = k as usize;
+            }
+        }
+        if next == current {
+            return current;
+        }
+        current = next;
+    }
+}
+
+/// Collects the overlaps of `cell` with the cells of `other`, spreading from the cell `start`
+/// to the neighbors of every cell with a non-zero overlap.
+fn collect_overlaps<const D: usize, C: Cell<D>, C2: Cell<D>, B: SpatialAlgorithm<D>>(
+    source: usize,
+    cell: &C,
+    other: &Tessellation<D, C2, B>,
+    start: usize,
+    scratch: &mut C::Scratch,
+) -> Vec<CellOverlap<C>> {
+    let min_volume = cell.volume() * OVERLAP_TOLERANCE;
+    let mut overlaps = Vec::new();
+    let mut visited = HashSet::from([start]);
+    let mut queue = VecDeque::from([start]);
+    while let Some(j) = queue.pop_front() {
+        let Some(piece) = clip_to_cell(cell, other, j, scratch) else { continue };
+        let volume = piece.volume();
+        if volume <= min_volume {
+            continue;
+        }
+        overlaps.push(CellOverlap { source, target: j, volume, cell: piece });
+        for &k in other.cells[j].neighbors() {
+            if k >= 0 && (k as usize) < other.cells.len() && visited.insert(k as usize) {
+                queue.push_back(k as usize);
+            }
+        }
+    }
+    overlaps
+}
+
+/// Clips a copy of `cell` to the cell `j` of `other`, returning `None` if nothing remains.
+fn clip_to_cell<const D: usize, C: Cell<D>, C2: Cell<D>, B: SpatialAlgorithm<D>>(
+    cell: &C,
+    other: &Tessellation<D, C2, B>,
+    j: usize,
+    scratch: &mut C::Scratch,
+) -> Option<C> {
+    if other.cells[j].is_empty() {
+        return None;
+    }
+    let g_pos: [f64; D] = point_at(&other.generators, j);
+    let mut piece = cell.clone();
+
+    for axis in 0..D {
+        for (is_max, sign, value) in [(false, -1.0, other.bounds.min[axis]), (true, 1.0, other.bounds.max[axis])] {
+            let mut point = g_pos;
+            let mut normal = [0.0; D];
+            point[axis] = value;
+            normal[axis] = sign;
+            piece.clip(&point, &normal, box_side(axis, is_max), scratch, None);
+        }
+    }
+    for wall in &other.walls {
+        wall.cut(&g_pos, &mut |point, normal| {
+            piece.clip(&point, &normal, wall.id(), scratch, None);
+        });
+    }
+    if piece.is_empty() {
+        return None;
+    }
+
+    for &k in other.cells[j].neighbors() {
+        if k < 0 || k as usize >= other.cells.len() {
+            continue;
+        }
+        let n_pos: [f64; D] = point_at(&other.generators, k as usize);
+        let midpoint: [f64; D] = std::array::from_fn(|d| 0.5 * (g_pos[d] + n_pos[d]));
+        let normal: [f64; D] = std::array::from_fn(|d| n_pos[d] - g_pos[d]);
+        piece.clip(&midpoint, &normal, k, scratch, None);
+        if piece.is_empty() {
+            return None;
+        }
+    }
+    Some(piece)
+}
+
+fn distance_sq<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+    (0..D).map(|k| (a[k] - b[k]).powi(2)).sum()
+}
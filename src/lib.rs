@@ -19,32 +19,102 @@
 //!
 //! The primary entry point is the [`Tessellation`] struct, which manages the grid and generators.
 
+#![cfg_attr(
+    all(feature = "no-panic", not(test)),
+    deny(clippy::panic, clippy::unwrap_used, clippy::expect_used)
+)]
+
 mod algorithm;
 mod cell;
+mod conditioning;
+mod correction;
 mod bounds;
+mod error;
+mod explode;
+mod extrude;
+mod fingerprint;
+mod ghost;
+mod graph;
+mod hierarchy;
+mod hull;
+mod ids;
+mod intersection;
+mod merge;
+mod morph;
+mod open_faces;
+mod operators;
+mod partition;
+mod quality;
+mod quantize;
+mod sites;
+mod snapshot;
+mod solid;
+mod sparse;
+mod stats;
+mod surface;
 mod tessellation;
+mod trace;
+mod tracking;
+mod transform;
+mod validation;
+mod volume_mesh;
 mod wall;
 
-pub use algorithm::SpatialAlgorithm;
+pub use algorithm::{SpatialAlgorithm, auto_resolution};
 pub use algorithm::algo_2d_grid::Algorithm2DGrid;
 pub use algorithm::algo_3d_grid::Algorithm3DGrid;
+pub use algorithm::algo_3d_adaptive_grid::AlgorithmAdaptiveGrid;
+pub use algorithm::algo_3d_binned_bvh::AlgorithmBinnedBvh;
 pub use algorithm::algo_3d_octree::Algorithm3DOctree;
 
 pub use bounds::BoundingBox;
 pub use bounds::box_side;
 
 pub use cell::Cell;
+pub use cell::{CellNet, ClipRecord, NetEdge, Quadrature, TetMesh, Tetrahedralization};
 pub use cell::cell_2d::Cell2D;
 pub use cell::cell_3d_faces::Cell3DFaces;
 
 pub use wall::Wall;
 pub use wall::WallGeometry;
+pub use wall::WallMesh;
 pub use wall::WALL_ID_MAX;
+pub use wall::curve;
 pub use wall::wall_2d;
 pub use wall::wall_3d;
 
-pub use tessellation::Tessellation;
+pub use error::{GeneratorError, WallError};
+pub use conditioning::ConditioningReport;
+pub use hierarchy::NestedTessellation;
+pub use hull::{ConvexHull, convex_hull};
+pub use ids::NeighborId;
+pub use intersection::CellOverlap;
+pub use merge::MergedRegion;
+pub use morph::{CellMorph, MorphPlane};
+pub use partition::GeneratorPartition;
+pub use quality::{FaceQuality, MeshQualityReport};
+pub use quantize::QuantizedCell;
+pub use snapshot::{CellSnapshot, SnapshotHandle};
+pub use solid::SolidMesh;
+pub use sparse::SparseMatrix;
+pub use stats::{Binning, Histogram};
+pub use surface::{LabelVolumes, WallAreas, WallSurface};
+pub use tessellation::{BoundaryPolicy, Tessellation};
+#[cfg(feature = "tracing")]
+pub use trace::CalculationCounters;
+pub use transform::DomainTransform;
+pub use tracking::{AdjacencySnapshot, CellMatching, CellTrack, T1Event, TopologyEvents, TrackingReport, track_cells};
+pub use validation::{EmptyCell, SliverRepair, ValidationReport};
+
 
+// Canonical datasets with reference statistics, exposed for benchmarks and validation.
+#[cfg(feature = "datasets")]
+pub mod datasets;
+// Benchmark point distributions, exposed for testing custom configurations.
+#[cfg(feature = "test-util")]
+pub mod distributions;
+#[cfg(feature = "test-util")]
+pub mod testing;
 
 // The WebAssembly implementation is sourced out in these files.
 pub mod wasm;
\ No newline at end of file
@@ -0,0 +1,87 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use std::collections::{HashMap, HashSet};
+
+/// The distance, relative to the largest extent of the bounding box, within which
+/// vertices of different cells are welded into a single vertex of a merged region.
+const WELD_TOLERANCE: f64 = 1e-9;
+
+/// The union of a group of cells, as returned by [`Tessellation::merge_cells`].
+///
+/// Only the outer boundary is kept: faces shared by two cells of the group are removed,
+/// and the vertices of the remaining faces are welded across the cells.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MergedRegion<const D: usize> {
+    /// The indices of the merged cells, in the order given.
+    pub cells: Vec<usize>,
+    /// The vertex coordinates as a flat array `[x, y, (z), ...]`.
+    pub vertices: Vec<f64>,
+    /// The boundary faces (edges in 2D) as indices into the vertices,
+    /// ordered as in the original cells.
+    pub faces: Vec<Vec<usize>>,
+    /// The neighbor of each boundary face: a cell outside the group, or a negative wall ID.
+    pub face_neighbors: Vec<i32>,
+    /// The total volume (area in 2D) of the merged cells.
+    pub volume: f64,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Merges each group of cell indices into a single region with only its outer boundary,
+    /// for example to form grains or fracture chunks from several cells.
+    ///
+    /// A face is internal if its neighbor belongs to the same group. Indices of missing or
+    /// empty cells are skipped, and the groups are expected to be disjoint.
+    pub fn merge_cells(&self, groups: &[Vec<usize>]) -> Vec<MergedRegion<D>> {
+        groups
+            .iter()
+            .map(|group| {
+                let mut region = MergedRegion { cells: group.clone(), ..Default::default() };
+                let members: HashSet<usize> = group.iter().copied().collect();
+                let mut welder = VertexWelder::new(&self.bounds);
+
+                for &i in group {
+                    let Some(cell) = self.cells.get(i).filter(|c| !c.is_empty()) else { continue };
+                    region.volume += cell.volume();
+                    let vertices = cell.vertices();
+
+                    for (face, &neighbor) in cell.faces().iter().zip(cell.neighbors()) {
+                        if neighbor >= 0 && members.contains(&(neighbor as usize)) {
+                            continue;
+                        }
+                        region.faces.push(face.iter().map(|&v| welder.weld(&vertices[v * D..(v + 1) * D])).collect());
+                        region.face_neighbors.push(neighbor);
+                    }
+                }
+                region.vertices = welder.vertices;
+                region
+            })
+            .collect()
+    }
+}
+
+/// Collects vertices of different cells, merging those within [`WELD_TOLERANCE`] of each other.
+pub(crate) struct VertexWelder<const D: usize> {
+    step: f64,
+    indices: HashMap<[i64; D], usize>,
+    /// The welded vertex coordinates as a flat array.
+    pub vertices: Vec<f64>,
+}
+
+impl<const D: usize> VertexWelder<D> {
+    pub(crate) fn new(bounds: &BoundingBox<D>) -> Self {
+        let extent = (0..D).map(|k| bounds.max[k] - bounds.min[k]).fold(0.0, f64::max);
+        Self { step: extent * WELD_TOLERANCE, indices: HashMap::new(), vertices: Vec::new() }
+    }
+
+    /// Returns the index of the welded vertex at `point`, adding it if there is none.
+    pub(crate) fn weld(&mut self, point: &[f64]) -> usize {
+        let step = self.step;
+        let key: [i64; D] = std::array::from_fn(|k| if step > 0.0 { (point[k] / step).round() as i64 } else { 0 });
+        *self.indices.entry(key).or_insert_with(|| {
+            self.vertices.extend_from_slice(point);
+            self.vertices.len() / D - 1
+        })
+    }
+}
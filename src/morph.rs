@@ -0,0 +1,157 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::tessellation::{Tessellation, point_at};
+use crate::wall::Wall;
+
+/// A bounding plane of a cell in two states, keeping the half-space `normal . x <= offset`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct MorphPlane<const D: usize> {
+    /// The generator index or wall ID of the face created by the plane.
+    pub neighbor: i32,
+    /// The unit normal in the previous state.
+    pub from_normal: [f64; D],
+    /// The offset in the previous state.
+    pub from_offset: f64,
+    /// The unit normal in the current state.
+    pub to_normal: [f64; D],
+    /// The offset in the current state.
+    pub to_offset: f64,
+}
+
+impl<const D: usize> MorphPlane<D> {
+    /// Returns a point on the plane and its normal at `t`, from 0 for the previous
+    /// to 1 for the current state.
+    pub fn at(&self, t: f64) -> ([f64; D], [f64; D]) {
+        let mut normal: [f64; D] = std::array::from_fn(|k| self.from_normal[k] + t * (self.to_normal[k] - self.from_normal[k]));
+        let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if length > 1e-12 {
+            normal = normal.map(|v| v / length);
+        } else {
+            // Opposite normals have no interpolation, switch halfway.
+            normal = if t < 0.5 { self.from_normal } else { self.to_normal };
+        }
+        let offset = self.from_offset + t * (self.to_offset - self.from_offset);
+        (normal.map(|v| v * offset), normal)
+    }
+}
+
+/// The data to interpolate the geometry of a cell between two states of a tessellation,
+/// as returned by [`Tessellation::morph_to`].
+#[derive(Clone, Debug)]
+pub struct CellMorph<const D: usize> {
+    /// The index of the cell in both states.
+    pub index: usize,
+    /// The bounding box the cell is clipped from.
+    pub bounds: BoundingBox<D>,
+    /// The planes bounding the cell in either state.
+    pub planes: Vec<MorphPlane<D>>,
+}
+
+impl<const D: usize> CellMorph<D> {
+    /// Computes the cell at `t`, from 0 for the previous to 1 for the current state,
+    /// by clipping it with the interpolated planes.
+    pub fn at<C: Cell<D>>(&self, t: f64) -> C {
+        let mut cell = C::new(self.index, self.bounds);
+        let mut scratch = C::Scratch::default();
+        for plane in &self.planes {
+            let (point, normal) = plane.at(t);
+            cell.clip(&point, &normal, plane.neighbor, &mut scratch, None);
+            if cell.is_empty() {
+                break;
+            }
+        }
+        cell
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Returns the data to smoothly interpolate each cell towards the cell with the same index
+    /// in `other`, for animating an evolving point set instead of switching between frames.
+    ///
+    /// Each cell is described by its bounding planes in both states: the bisector planes to the
+    /// neighbors it has in either state, and the cuts of the walls. A bisector to a generator that
+    /// is not a neighbor in one of the states does not cut the cell there, so the interpolated cell
+    /// matches both states at the ends, and adjacent cells interpolate their shared plane alike.
+    /// A wall cut missing in one state is replaced by a plane touching the cell there.
+    /// In between, the cells approximate the tessellation for the small motions between frames,
+    /// but no longer fill the domain for large motions.
+    ///
+    /// Both tessellations must be calculated with the same bounds and walls. Cells that are
+    /// empty in either state are left out.
+    pub fn morph_to<C2: Cell<D>, A2: SpatialAlgorithm<D>>(&self, other: &Tessellation<D, C2, A2>) -> Vec<CellMorph<D>> {
+        let count = self.cells.len().min(other.cells.len());
+        (0..count)
+            .filter(|&i| !self.cells[i].is_empty() && !other.cells[i].is_empty())
+            .map(|i| {
+                let (from_pos, to_pos) = (point_at(&self.generators, i), point_at(&other.generators, i));
+                let mut planes = Vec::new();
+
+                let mut neighbors: Vec<usize> = self.cells[i]
+                    .neighbors()
+                    .iter()
+                    .chain(other.cells[i].neighbors())
+                    .filter(|&&n| n >= 0 && (n as usize) < self.count_generators().min(other.count_generators()))
+                    .map(|&n| n as usize)
+                    .collect();
+                neighbors.sort_unstable();
+                neighbors.dedup();
+                for j in neighbors {
+                    let from = bisector(&from_pos, &point_at(&self.generators, j));
+                    let to = bisector(&to_pos, &point_at(&other.generators, j));
+                    if let (Some(from), Some(to)) = (from, to) {
+                        planes.push(MorphPlane { neighbor: j as i32, from_normal: from.0, from_offset: from.1, to_normal: to.0, to_offset: to.1 });
+                    }
+                }
+
+                for (from_wall, to_wall) in self.walls.iter().zip(&other.walls) {
+                    let from_cuts = wall_planes(from_wall, &from_pos);
+                    let to_cuts = wall_planes(to_wall, &to_pos);
+                    for k in 0..from_cuts.len().max(to_cuts.len()) {
+                        let from = from_cuts.get(k).copied();
+                        let to = to_cuts.get(k).copied();
+                        let from = from.unwrap_or_else(|| touching(to.map_or([0.0; D], |p| p.0), self.cells[i].vertices()));
+                        let to = to.unwrap_or_else(|| touching(from.0, other.cells[i].vertices()));
+                        planes.push(MorphPlane { neighbor: from_wall.id(), from_normal: from.0, from_offset: from.1, to_normal: to.0, to_offset: to.1 });
+                    }
+                }
+
+                CellMorph { index: i, bounds: self.bounds, planes }
+            })
+            .collect()
+    }
+}
+
+/// The bisector plane between two generators as a unit normal pointing away from `g` and an offset.
+fn bisector<const D: usize>(g: &[f64; D], n: &[f64; D]) -> Option<([f64; D], f64)> {
+    let d: [f64; D] = std::array::from_fn(|k| n[k] - g[k]);
+    let length = d.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if length == 0.0 {
+        return None;
+    }
+    let normal = d.map(|v| v / length);
+    let offset = (0..D).map(|k| normal[k] * 0.5 * (g[k] + n[k])).sum();
+    Some((normal, offset))
+}
+
+/// The planes a wall cuts the cell of a generator with, as unit normals and offsets.
+fn wall_planes<const D: usize>(wall: &Wall<D>, g: &[f64; D]) -> Vec<([f64; D], f64)> {
+    let mut planes = Vec::new();
+    wall.cut(g, &mut |point, normal| {
+        let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+        if length > 0.0 {
+            let normal = normal.map(|v| v / length);
+            planes.push((normal, (0..D).map(|k| normal[k] * point[k]).sum()));
+        }
+    });
+    planes
+}
+
+/// The plane with the given normal that touches the cell with the given vertices from outside.
+fn touching<const D: usize>(normal: [f64; D], vertices: &[f64]) -> ([f64; D], f64) {
+    let offset = vertices
+        .chunks_exact(D)
+        .map(|v| (0..D).map(|k| normal[k] * v[k]).sum::<f64>())
+        .fold(f64::NEG_INFINITY, f64::max);
+    (normal, offset)
+}
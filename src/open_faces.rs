@@ -0,0 +1,58 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::{BoundingBox, box_side};
+use crate::cell::Cell;
+use crate::error::WallError;
+use crate::tessellation::Tessellation;
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Marks the side of the bounding box at `axis` and `is_max` as open, for boxes that are a
+    /// computational convenience rather than a physical boundary, or closes it again with `None`.
+    ///
+    /// Cells are clipped `margin` beyond an open side instead of at the side itself, and cells that
+    /// still reach that extended side are flagged as unbounded, see [`Tessellation::is_cell_unbounded`].
+    /// A margin of 0 only flags the cells. Generators are still kept within the bounding box.
+    ///
+    /// Returns a [`WallError::InvalidGeometry`] if `axis` is not below `D` or the margin is negative
+    /// or not finite, leaving the sides unchanged.
+    pub fn set_open_face(&mut self, axis: usize, is_max: bool, margin: Option<f64>) -> Result<(), WallError> {
+        if axis >= D {
+            return Err(WallError::InvalidGeometry("open face axis out of range"));
+        }
+        if margin.is_some_and(|m| !m.is_finite() || m < 0.0) {
+            return Err(WallError::InvalidGeometry("open face margin must be finite and non-negative"));
+        }
+        self.open_faces[axis][is_max as usize] = margin;
+        Ok(())
+    }
+
+    /// Returns the margin of an open side of the bounding box, or `None` if it is closed.
+    pub fn open_face(&self, axis: usize, is_max: bool) -> Option<f64> {
+        self.open_faces.get(axis).and_then(|sides| sides[is_max as usize])
+    }
+
+    /// Returns `true` if the cell at `index` touches an open side of the bounding box, so that it
+    /// would extend further without the box. Returns `false` for missing cells.
+    pub fn is_cell_unbounded(&self, index: usize) -> bool {
+        self.cells.get(index).is_some_and(|cell| {
+            cell.neighbors().iter().any(|&n| {
+                (0..D).any(|axis| (0..2).any(|side| self.open_faces[axis][side].is_some() && n == box_side(axis, side == 1)))
+            })
+        })
+    }
+
+    /// Returns the indices of the cells touching an open side of the bounding box.
+    pub fn unbounded_cells(&self) -> Vec<usize> {
+        (0..self.cells.len()).filter(|&i| self.is_cell_unbounded(i)).collect()
+    }
+
+    /// Returns the box the cells are clipped against: the bounding box extended by the margins of
+    /// its open sides.
+    pub(crate) fn clip_bounds(&self) -> BoundingBox<D> {
+        let mut bounds = self.bounds;
+        for axis in 0..D {
+            bounds.min[axis] -= self.open_faces[axis][0].unwrap_or(0.0);
+            bounds.max[axis] += self.open_faces[axis][1].unwrap_or(0.0);
+        }
+        bounds
+    }
+}
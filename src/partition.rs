@@ -0,0 +1,91 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::tessellation::{Tessellation, point_at};
+
+/// The default halo width of [`Tessellation::partition_generators`], in mean generator spacings.
+const HALO_SPACINGS: f64 = 3.0;
+
+/// A spatially compact chunk of generators, as returned by [`Tessellation::partition_generators`].
+#[derive(Clone, Debug)]
+pub struct GeneratorPartition<const D: usize> {
+    /// The indices of the generators owned by the chunk, in Morton order.
+    pub owned: Vec<usize>,
+    /// The indices of the generators of other chunks within the halo, in increasing order.
+    /// These are the candidates for [`Tessellation::set_ghosts`].
+    pub halo: Vec<usize>,
+    /// The bounding box of the owned generators.
+    pub bounds: BoundingBox<D>,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Splits the generators into `k` spatially compact chunks of nearly equal size for distributed
+    /// runs, with a halo of three mean generator spacings, see
+    /// [`Tessellation::partition_generators_with_halo`].
+    pub fn partition_generators(&self, k: usize) -> Vec<GeneratorPartition<D>> {
+        let count = self.count_owned().max(1) as f64;
+        let spacing = (self.bounds.volume() / count).powf(1.0 / D as f64);
+        self.partition_generators_with_halo(k, HALO_SPACINGS * spacing)
+    }
+
+    /// Splits the generators into `k` spatially compact chunks of nearly equal size, by cutting
+    /// their Morton (Z-order) curve over the bounding box into contiguous pieces.
+    ///
+    /// The halo of a chunk holds the generators of other chunks within `halo_width` of the
+    /// bounding box of its generators. Each chunk can then be computed separately by setting its
+    /// owned generators and adding the halo with [`Tessellation::set_ghosts`]. A cell is exact if
+    /// the halo covers twice its radius around its generator, so the width should be at least
+    /// the diameter of the largest cells.
+    pub fn partition_generators_with_halo(&self, k: usize, halo_width: f64) -> Vec<GeneratorPartition<D>> {
+        let count = self.count_owned();
+        let k = k.clamp(1, count.max(1));
+
+        let mut order: Vec<(u64, usize)> = (0..count).map(|i| (self.morton_code(&point_at(&self.generators, i)), i)).collect();
+        order.sort_unstable();
+
+        let mut partitions: Vec<GeneratorPartition<D>> = (0..k)
+            .map(|c| {
+                let owned: Vec<usize> = order[c * count / k..(c + 1) * count / k].iter().map(|&(_, i)| i).collect();
+                let points: Vec<[f64; D]> = owned.iter().map(|&i| point_at(&self.generators, i)).collect();
+                let min = std::array::from_fn(|d| points.iter().map(|p| p[d]).fold(f64::INFINITY, f64::min));
+                let max = std::array::from_fn(|d| points.iter().map(|p| p[d]).fold(f64::NEG_INFINITY, f64::max));
+                GeneratorPartition { owned, halo: Vec::new(), bounds: BoundingBox::new(min, max) }
+            })
+            .collect();
+
+        let mut chunk_of = vec![0; count];
+        for (c, partition) in partitions.iter().enumerate() {
+            partition.owned.iter().for_each(|&i| chunk_of[i] = c);
+        }
+        for (i, &chunk) in chunk_of.iter().enumerate() {
+            let p: [f64; D] = point_at(&self.generators, i);
+            for (c, partition) in partitions.iter_mut().enumerate() {
+                let dist_sq: f64 = (0..D)
+                    .map(|d| (partition.bounds.min[d] - p[d]).max(p[d] - partition.bounds.max[d]).max(0.0).powi(2))
+                    .sum();
+                if c != chunk && dist_sq <= halo_width * halo_width {
+                    partition.halo.push(i);
+                }
+            }
+        }
+        partitions
+    }
+
+    /// Interleaves the bits of the coordinates of a point, quantized over the bounding box.
+    fn morton_code(&self, point: &[f64; D]) -> u64 {
+        let bits = (64 / D as u32).min(32);
+        let scale = ((1u64 << bits) - 1) as f64;
+        let cells: [u64; D] = std::array::from_fn(|d| {
+            let extent = self.bounds.max[d] - self.bounds.min[d];
+            let t = if extent > 0.0 { (point[d] - self.bounds.min[d]) / extent } else { 0.0 };
+            (t.clamp(0.0, 1.0) * scale) as u64
+        });
+        let mut code = 0;
+        for bit in (0..bits).rev() {
+            for cell in cells {
+                code = (code << 1) | ((cell >> bit) & 1);
+            }
+        }
+        code
+    }
+}
@@ -0,0 +1,134 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::{Tessellation, point_at};
+
+/// The finite-volume quality metrics of a face between two generators.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FaceQuality {
+    /// The cell the face belongs to.
+    pub cell: usize,
+    /// The generator on the other side of the face, which is larger than `cell`.
+    pub neighbor: usize,
+    /// The distance from the face plane to the midpoint of the two generators,
+    /// which is zero for an unmodified Voronoi face.
+    pub offset: f64,
+    /// The angle between the face normal and the line between the generators, in radians,
+    /// which is zero for an unmodified Voronoi face.
+    pub non_orthogonality: f64,
+    /// The distance from the face centroid to the point where the line between the generators
+    /// crosses the face plane, relative to the distance of the generators.
+    pub skewness: f64,
+}
+
+/// The quality of a tessellation as a finite-volume mesh, as returned by [`Tessellation::mesh_quality`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct MeshQualityReport {
+    /// The metrics of every face between two generators, once per pair of cells.
+    pub faces: Vec<FaceQuality>,
+    /// The largest offset of a face from the midpoint of its generators.
+    pub max_offset: f64,
+    /// The largest non-orthogonality angle, in radians.
+    pub max_non_orthogonality: f64,
+    /// The mean non-orthogonality angle, in radians.
+    pub mean_non_orthogonality: f64,
+    /// The largest skewness.
+    pub max_skewness: f64,
+    /// The mean skewness.
+    pub mean_skewness: f64,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Measures the quality of the tessellation as a finite-volume mesh, with the generators as the
+    /// cell centers: the offset of the faces from the generator midpoints, their non-orthogonality
+    /// and their skewness. Voronoi faces are orthogonal bisectors by construction, so the first two
+    /// only deviate for modified faces, as after sealing or relaxation without recalculation.
+    ///
+    /// Faces to walls and degenerate faces are skipped. The maxima and means are zero without faces.
+    pub fn mesh_quality(&self) -> MeshQualityReport {
+        let mut report = MeshQualityReport::default();
+        for (i, cell) in self.cells.iter().enumerate() {
+            let gi: [f64; D] = point_at(&self.generators, i);
+            let vertices = cell.vertices();
+            for (face, &neighbor) in cell.faces().iter().zip(cell.neighbors()) {
+                if neighbor < 0 || (neighbor as usize) <= i || neighbor as usize >= self.count_generators() {
+                    continue;
+                }
+                let Some((centroid, mut normal)) = face_plane::<D>(vertices, face) else { continue };
+                let gj: [f64; D] = point_at(&self.generators, neighbor as usize);
+                let d: [f64; D] = std::array::from_fn(|k| gj[k] - gi[k]);
+                let length = d.iter().map(|v| v * v).sum::<f64>().sqrt();
+                let mut cos = (0..D).map(|k| normal[k] * d[k]).sum::<f64>() / length;
+                if cos < 0.0 {
+                    normal = normal.map(|v| -v);
+                    cos = -cos;
+                }
+                if length == 0.0 || cos == 0.0 {
+                    continue;
+                }
+
+                // The angle from the components of `d` across and along the normal, which unlike
+                // the arc cosine stays accurate for nearly orthogonal faces.
+                let perpendicular = (0..D).map(|k| (d[k] - cos * length * normal[k]).powi(2)).sum::<f64>().sqrt();
+                let offset = (0..D).map(|k| normal[k] * (gi[k] + 0.5 * d[k] - centroid[k])).sum::<f64>().abs();
+                let t = (0..D).map(|k| normal[k] * (centroid[k] - gi[k])).sum::<f64>() / (cos * length);
+                let skew = (0..D).map(|k| (gi[k] + t * d[k] - centroid[k]).powi(2)).sum::<f64>().sqrt();
+                report.faces.push(FaceQuality {
+                    cell: i,
+                    neighbor: neighbor as usize,
+                    offset,
+                    non_orthogonality: perpendicular.atan2(cos * length),
+                    skewness: skew / length,
+                });
+            }
+        }
+
+        for face in &report.faces {
+            report.max_offset = report.max_offset.max(face.offset);
+            report.max_non_orthogonality = report.max_non_orthogonality.max(face.non_orthogonality);
+            report.max_skewness = report.max_skewness.max(face.skewness);
+            report.mean_non_orthogonality += face.non_orthogonality;
+            report.mean_skewness += face.skewness;
+        }
+        if !report.faces.is_empty() {
+            report.mean_non_orthogonality /= report.faces.len() as f64;
+            report.mean_skewness /= report.faces.len() as f64;
+        }
+        report
+    }
+}
+
+/// The area centroid and a unit normal of a face: an edge in 2D, a planar polygon in 3D.
+fn face_plane<const D: usize>(vertices: &[f64], face: &[usize]) -> Option<([f64; D], [f64; D])> {
+    let point = |v: usize| -> [f64; D] { std::array::from_fn(|k| vertices[v * D + k]) };
+    let mut centroid = [0.0; D];
+    let mut normal = [0.0; D];
+    match D {
+        2 if face.len() == 2 => {
+            let (a, b) = (point(face[0]), point(face[1]));
+            centroid = std::array::from_fn(|k| 0.5 * (a[k] + b[k]));
+            (normal[0], normal[1]) = (a[1] - b[1], b[0] - a[0]);
+        }
+        3 if face.len() >= 3 => {
+            let a = point(face[0]);
+            let mut area = 0.0;
+            for w in face[1..].windows(2) {
+                let (b, c) = (point(w[0]), point(w[1]));
+                let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+                let n = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+                let weight = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+                for k in 0..3 {
+                    centroid[k] += weight * (a[k] + b[k] + c[k]) / 3.0;
+                    normal[k] += n[k];
+                }
+                area += weight;
+            }
+            if area == 0.0 {
+                return None;
+            }
+            centroid = centroid.map(|v| v / area);
+        }
+        _ => return None,
+    }
+    let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+    (length > 0.0).then(|| (centroid, normal.map(|v| v / length)))
+}
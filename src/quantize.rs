@@ -0,0 +1,129 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+
+/// The largest quantized coordinate.
+const QUANTIZATION_STEPS: f64 = u16::MAX as f64;
+
+/// A cell with its vertices quantized to 16 bits within its bounding box, as returned by
+/// [`Tessellation::calculate_quantized`]. This takes about a quarter of the memory of the vertices
+/// of a full cell, at a precision sufficient for visualization.
+#[derive(Clone, Debug, PartialEq)]
+pub struct QuantizedCell<const D: usize> {
+    /// The lower corner of the bounding box of the vertices.
+    pub min: [f64; D],
+    /// The upper corner of the bounding box of the vertices.
+    pub max: [f64; D],
+    /// The quantized vertex coordinates as a flat array, mapping `0..=65535` to `min..=max`.
+    pub vertices: Vec<u16>,
+    /// The number of vertices of each face, in the order of the neighbors.
+    pub face_counts: Vec<u16>,
+    /// The vertex indices of all faces, concatenated.
+    pub face_indices: Vec<u16>,
+    /// The neighbor of each face, a generator index or a negative wall ID.
+    pub neighbors: Vec<i32>,
+    /// The volume (area in 2D) of the cell, computed before quantization.
+    pub volume: f64,
+}
+
+impl<const D: usize> QuantizedCell<D> {
+    /// Quantizes a cell. Cells with more than 65536 vertices are not supported.
+    pub fn from_cell<C: Cell<D>>(cell: &C) -> Self {
+        let points = cell.vertices();
+        if points.is_empty() {
+            return Self {
+                min: [0.0; D],
+                max: [0.0; D],
+                vertices: Vec::new(),
+                face_counts: Vec::new(),
+                face_indices: Vec::new(),
+                neighbors: Vec::new(),
+                volume: 0.0,
+            };
+        }
+        let min: [f64; D] = std::array::from_fn(|k| points.chunks_exact(D).map(|p| p[k]).fold(f64::INFINITY, f64::min));
+        let max: [f64; D] = std::array::from_fn(|k| points.chunks_exact(D).map(|p| p[k]).fold(f64::NEG_INFINITY, f64::max));
+        let vertices = points
+            .chunks_exact(D)
+            .flat_map(|p| {
+                (0..D).map(move |k| {
+                    let extent = max[k] - min[k];
+                    if extent > 0.0 { ((p[k] - min[k]) / extent * QUANTIZATION_STEPS).round() as u16 } else { 0 }
+                })
+            })
+            .collect();
+        let faces = cell.faces();
+        Self {
+            min,
+            max,
+            vertices,
+            face_counts: faces.iter().map(|f| f.len() as u16).collect(),
+            face_indices: faces.iter().flatten().map(|&v| v as u16).collect(),
+            neighbors: cell.neighbors().to_vec(),
+            volume: cell.volume(),
+        }
+    }
+
+    /// Returns `true` if the cell has no vertices.
+    pub fn is_empty(&self) -> bool {
+        self.vertices.is_empty()
+    }
+
+    /// Dequantizes the vertex coordinates into a flat array `[x, y, (z), ...]`.
+    pub fn dequantized_vertices(&self) -> Vec<f64> {
+        self.vertices
+            .chunks_exact(D)
+            .flat_map(|q| (0..D).map(move |k| self.min[k] + q[k] as f64 / QUANTIZATION_STEPS * (self.max[k] - self.min[k])))
+            .collect()
+    }
+
+    /// Returns the vertex indices of each face, in the order of the neighbors.
+    pub fn faces(&self) -> Vec<Vec<usize>> {
+        let mut offset = 0;
+        self.face_counts
+            .iter()
+            .map(|&count| {
+                let face = self.face_indices[offset..offset + count as usize].iter().map(|&v| v as usize).collect();
+                offset += count as usize;
+                face
+            })
+            .collect()
+    }
+
+    /// Returns the largest deviation of a dequantized coordinate from the original along each axis.
+    pub fn max_error(&self) -> [f64; D] {
+        std::array::from_fn(|k| 0.5 * (self.max[k] - self.min[k]) / QUANTIZATION_STEPS)
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Calculates all cells like [`Tessellation::calculate`], but returns them quantized instead of
+    /// storing them, for visualizing millions of cells with bounded memory. The full cells only
+    /// exist while they are computed, see [`Tessellation::map`].
+    pub fn calculate_quantized(&self) -> Vec<QuantizedCell<D>> {
+        self.map(|cell| QuantizedCell::from_cell(&cell))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{BoundingBox, Cell3DFaces};
+
+    #[test]
+    fn test_quantized_cell() {
+        let bounds = BoundingBox::new([1.0, 2.0, 3.0], [2.0, 4.0, 3.5]);
+        let mut cell = Cell3DFaces::new(0, bounds);
+        cell.clip(&[1.3, 3.0, 3.2], &[1.0, 0.7, 0.2], 5);
+
+        let quantized = QuantizedCell::from_cell(&cell);
+        assert_eq!(quantized.faces(), Cell::faces(&cell));
+        assert_eq!(quantized.neighbors, Cell::neighbors(&cell));
+        assert_eq!(quantized.volume, Cell::volume(&cell));
+        let error = quantized.max_error();
+        for (a, b) in quantized.dequantized_vertices().chunks_exact(3).zip(Cell::vertices(&cell).chunks_exact(3)) {
+            assert!((0..3).all(|k| (a[k] - b[k]).abs() <= error[k] * (1.0 + 1e-9)));
+        }
+        assert!(QuantizedCell::<3>::from_cell(&Cell3DFaces { vertices: Vec::new(), ..cell }).is_empty());
+    }
+}
@@ -0,0 +1,89 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::merge::MergedRegion;
+use crate::tessellation::Tessellation;
+use std::collections::BTreeMap;
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Groups the generators into logical sites, given the site ID of each generator including
+    /// ghosts, for example to model a non-spherical particle by several sub-points as in Set Voronoi
+    /// tessellations. The cell of a site is the union of the cells of its generators, see
+    /// [`Tessellation::site_cells`]. Site IDs need not be contiguous.
+    ///
+    /// Like external IDs, the sites stay attached to their generators when walls prune generators
+    /// or [`Tessellation::relax`] moves them, are cleared when the generators are replaced, and are
+    /// ignored once the number of generators changes otherwise.
+    ///
+    /// Returns a [`GeneratorError::CountMismatch`] if there is not one site per generator, or a
+    /// [`GeneratorError::InvalidSite`] if a site ID does not fit the `i32` face neighbors of
+    /// [`Tessellation::site_cells`], leaving the current sites unchanged.
+    pub fn set_sites(&mut self, sites: &[usize]) -> Result<(), GeneratorError> {
+        if sites.len() != self.count_generators() {
+            return Err(GeneratorError::CountMismatch(self.count_generators(), sites.len()));
+        }
+        if let Some(&site) = sites.iter().find(|&&site| i32::try_from(site).is_err()) {
+            return Err(GeneratorError::InvalidSite(site));
+        }
+        self.sites = sites.to_vec();
+        Ok(())
+    }
+
+    /// Removes the sites, so each generator is its own site again.
+    pub fn clear_sites(&mut self) {
+        self.sites.clear();
+    }
+
+    /// Returns `true` if sites are set for the current generators.
+    pub fn has_sites(&self) -> bool {
+        !self.sites.is_empty() && self.sites.len() == self.count_generators()
+    }
+
+    /// Returns the site ID of a generator, or its index if no sites are set.
+    pub fn site_of(&self, index: usize) -> usize {
+        if self.has_sites() && let Some(&site) = self.sites.get(index) { site } else { index }
+    }
+
+    /// Returns the indices of the generators of a site, in increasing order.
+    pub fn site_members(&self, site: usize) -> Vec<usize> {
+        (0..self.count_generators()).filter(|&i| self.site_of(i) == site).collect()
+    }
+
+    /// Returns the cell of each site with computed cells, by site ID: the union of the cells of its
+    /// generators as merged by [`Tessellation::merge_cells`], without the faces between them.
+    ///
+    /// The face neighbors of the merged regions are site IDs instead of cell indices, while walls
+    /// keep their negative IDs, so the adjacency of the sites can be read off directly.
+    pub fn site_cells(&self) -> BTreeMap<usize, MergedRegion<D>> {
+        let mut groups: BTreeMap<usize, Vec<usize>> = BTreeMap::new();
+        for i in 0..self.cells.len() {
+            groups.entry(self.site_of(i)).or_default().push(i);
+        }
+        let members: Vec<Vec<usize>> = groups.values().cloned().collect();
+        groups
+            .into_keys()
+            .zip(self.merge_cells(&members))
+            .map(|(site, mut region)| {
+                for neighbor in &mut region.face_neighbors {
+                    if *neighbor >= 0
+                        && (*neighbor as usize) < self.count_generators()
+                        && let Ok(site) = i32::try_from(self.site_of(*neighbor as usize))
+                    {
+                        *neighbor = site;
+                    }
+                }
+                (site, region)
+            })
+            .collect()
+    }
+
+    /// Keeps the sites of the previous generators at `accepted`, followed by those of the ghosts
+    /// from index `owned` on if given, after the generators were filtered.
+    pub(crate) fn retain_sites(&mut self, accepted: &[usize], owned: Option<usize>) {
+        let mut sites: Vec<usize> = accepted.iter().filter_map(|&i| self.sites.get(i).copied()).collect();
+        if let Some(owned) = owned {
+            sites.extend(self.sites.get(owned..).unwrap_or_default());
+        }
+        self.sites = sites;
+    }
+}
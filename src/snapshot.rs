@@ -0,0 +1,84 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use std::sync::{Arc, Mutex};
+
+/// The cells of a tessellation as published by [`Tessellation::swap`].
+#[derive(Clone, Debug, Default)]
+pub struct CellSnapshot<C> {
+    /// The cells at the time of the swap, indexed like the generators.
+    pub cells: Vec<C>,
+    /// The generators of the cells as a flat array.
+    pub generators: Vec<f64>,
+    /// The number of swaps before this snapshot was published, starting at 1. The empty snapshot
+    /// before the first swap has generation 0.
+    pub generation: u64,
+}
+
+/// A handle to the latest published cells of a tessellation, which can be cloned and sent to
+/// other threads, such as a render thread that keeps drawing the previous results while the next
+/// calculation runs, see [`Tessellation::snapshot_handle`].
+pub struct SnapshotHandle<C> {
+    latest: Arc<Mutex<Arc<CellSnapshot<C>>>>,
+}
+
+impl<C> SnapshotHandle<C> {
+    /// Returns the latest published snapshot. The lock is only held to clone the pointer, so the
+    /// snapshot stays valid and unchanged for as long as it is held, even across later swaps.
+    pub fn load(&self) -> Arc<CellSnapshot<C>> {
+        self.latest.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).clone()
+    }
+
+    /// Returns the generation of the latest published snapshot, to detect new results cheaply.
+    pub fn generation(&self) -> u64 {
+        self.load().generation
+    }
+
+    fn store(&self, snapshot: CellSnapshot<C>) {
+        *self.latest.lock().unwrap_or_else(|poisoned| poisoned.into_inner()) = Arc::new(snapshot);
+    }
+}
+
+impl<C> Clone for SnapshotHandle<C> {
+    fn clone(&self) -> Self {
+        Self { latest: Arc::clone(&self.latest) }
+    }
+}
+
+impl<C> Default for SnapshotHandle<C> {
+    fn default() -> Self {
+        Self {
+            latest: Arc::new(Mutex::new(Arc::new(CellSnapshot { cells: Vec::new(), generators: Vec::new(), generation: 0 }))),
+        }
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Publishes the current cells and generators to all [`SnapshotHandle`]s of this tessellation
+    /// and returns the generation of the new snapshot.
+    ///
+    /// The cells of the tessellation act as a back buffer: [`Tessellation::calculate`] and all
+    /// other edits write into them without affecting the published snapshot, which readers keep
+    /// using until the next swap replaces it atomically.
+    ///
+    /// Publishing copies the cells and generators, which takes time linear in their size. They are
+    /// not moved, as the tessellation keeps its cells for queries and for the incremental updates
+    /// of [`Tessellation::calculate_warm`] and [`Tessellation::recalculate_region`]. Readers only
+    /// clone a pointer.
+    pub fn swap(&mut self) -> u64 {
+        let generation = self.snapshot.generation() + 1;
+        self.snapshot.store(CellSnapshot { cells: self.cells.clone(), generators: self.generators.clone(), generation });
+        generation
+    }
+
+    /// Returns a handle to the cells published by [`Tessellation::swap`], which stays connected to
+    /// this tessellation and observes all later swaps.
+    pub fn snapshot_handle(&self) -> SnapshotHandle<C> {
+        self.snapshot.clone()
+    }
+
+    /// Returns the latest snapshot published by [`Tessellation::swap`].
+    pub fn published(&self) -> Arc<CellSnapshot<C>> {
+        self.snapshot.load()
+    }
+}
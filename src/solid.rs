@@ -0,0 +1,335 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::merge::VertexWelder;
+use crate::tessellation::Tessellation;
+use std::collections::BTreeSet;
+use std::f64::consts::{PI, TAU};
+
+/// A closed triangle mesh of a solid, as returned by [`Tessellation::strut_mesh`]
+/// and [`Tessellation::shell_mesh`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SolidMesh {
+    /// The vertex coordinates as a flat array `[x, y, z, ...]`.
+    pub vertices: Vec<f64>,
+    /// The triangles as indices into the vertices, counter-clockwise seen from outside.
+    pub triangles: Vec<[usize; 3]>,
+}
+
+impl SolidMesh {
+    /// Adds a vertex and returns its index.
+    fn push(&mut self, p: [f64; 3]) -> usize {
+        self.vertices.extend_from_slice(&p);
+        self.vertices.len() / 3 - 1
+    }
+
+    /// Calculates the enclosed volume with the divergence theorem. Overlapping closed
+    /// shells are counted once each.
+    pub fn volume(&self) -> f64 {
+        let p = |v: usize| [self.vertices[v * 3], self.vertices[v * 3 + 1], self.vertices[v * 3 + 2]];
+        self.triangles
+            .iter()
+            .map(|&[a, b, c]| {
+                let (a, b, c) = (p(a), p(b), p(c));
+                a[0] * (b[1] * c[2] - b[2] * c[1]) + a[1] * (b[2] * c[0] - b[0] * c[2]) + a[2] * (b[0] * c[1] - b[1] * c[0])
+            })
+            .sum::<f64>()
+            / 6.0
+    }
+}
+
+impl<C: Cell<3>, A: SpatialAlgorithm<3>> Tessellation<3, C, A> {
+    /// Converts the edge network of the cells into a solid lattice of struts with the given
+    /// `radius`, for printing Voronoi lattice structures.
+    ///
+    /// Each edge becomes a capped prism with `segments` sides running between the centers of its
+    /// vertices, and each vertex becomes a sphere of the same radius with `segments` sides around,
+    /// which joins the struts meeting there. The struts and nodes are separate closed shells that
+    /// overlap at the nodes, to be merged by the slicer or a boolean union.
+    /// Edges on the bounding box and walls are included.
+    pub fn strut_mesh(&self, radius: f64, segments: usize) -> SolidMesh {
+        let segments = segments.max(3);
+        let (nodes, edges) = self.edge_network();
+        let node = |v: usize| [nodes[v * 3], nodes[v * 3 + 1], nodes[v * 3 + 2]];
+        let mut mesh = SolidMesh::default();
+
+        for &(a, b) in &edges {
+            let (p, q) = (node(a), node(b));
+            let d = [q[0] - p[0], q[1] - p[1], q[2] - p[2]];
+            let length = (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt();
+            if length == 0.0 {
+                continue;
+            }
+            let d = d.map(|x| x / length);
+            let (u, v) = perpendicular_basis(d);
+            let ring = |center: [f64; 3], k: usize| {
+                let (s, c) = (TAU * k as f64 / segments as f64).sin_cos();
+                std::array::from_fn(|i| center[i] + radius * (c * u[i] + s * v[i]))
+            };
+            let (cap_p, cap_q) = (mesh.push(p), mesh.push(q));
+            let start = mesh.vertices.len() / 3;
+            for k in 0..segments {
+                mesh.push(ring(p, k));
+                mesh.push(ring(q, k));
+            }
+            for k in 0..segments {
+                let (pk, qk) = (start + 2 * k, start + 2 * k + 1);
+                let (pn, qn) = (start + 2 * ((k + 1) % segments), start + 2 * ((k + 1) % segments) + 1);
+                mesh.triangles.extend([[pk, pn, qn], [pk, qn, qk], [cap_p, pn, pk], [cap_q, qk, qn]]);
+            }
+        }
+
+        let rings = (segments / 2).max(2);
+        for v in 0..nodes.len() / 3 {
+            let c = node(v);
+            let top = mesh.push([c[0], c[1], c[2] + radius]);
+            let start = mesh.vertices.len() / 3;
+            for i in 1..rings {
+                let (sp, cp) = (PI * i as f64 / rings as f64).sin_cos();
+                for k in 0..segments {
+                    let (st, ct) = (TAU * k as f64 / segments as f64).sin_cos();
+                    mesh.push([c[0] + radius * sp * ct, c[1] + radius * sp * st, c[2] + radius * cp]);
+                }
+            }
+            let bottom = mesh.push([c[0], c[1], c[2] - radius]);
+            let at = |i: usize, k: usize| start + (i - 1) * segments + k % segments;
+            for k in 0..segments {
+                mesh.triangles.push([top, at(1, k), at(1, k + 1)]);
+                for i in 1..rings - 1 {
+                    mesh.triangles.extend([[at(i, k), at(i + 1, k), at(i + 1, k + 1)], [at(i, k), at(i + 1, k + 1), at(i, k + 1)]]);
+                }
+                mesh.triangles.push([bottom, at(rings - 1, k + 1), at(rings - 1, k)]);
+            }
+        }
+        mesh
+    }
+
+    /// Generates the solid material between the cell boundaries and the cells inset by the wall
+    /// `thickness`, for printing foam or honeycomb structures.
+    ///
+    /// Each cell is inset by half the thickness on the faces shared with other cells and by the
+    /// full thickness on the bounding box and walls, so all walls have the given thickness.
+    /// The mesh consists of the welded outer boundary of the domain and the inset cells as
+    /// inward facing cavities. Cells too small for the thickness are left solid.
+    pub fn shell_mesh(&self, thickness: f64) -> SolidMesh {
+        let mut mesh = SolidMesh::default();
+        let mut welder = VertexWelder::new(&self.bounds);
+        let mut outer = Vec::new();
+        let mut scratch = C::Scratch::default();
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.is_empty() {
+                continue;
+            }
+            let vertices = cell.vertices();
+            let centroid = cell.centroid();
+            let mut inset = C::new(i, self.bounds);
+            for (face, &neighbor) in cell.faces().iter().zip(cell.neighbors()) {
+                let Some((center, normal)) = outward_plane(vertices, face, &centroid) else { continue };
+                if neighbor < 0 {
+                    let mut indices: Vec<usize> = face.iter().map(|&v| welder.weld(&vertices[v * 3..v * 3 + 3])).collect();
+                    if !is_outward(vertices, face, &normal) {
+                        indices.reverse();
+                    }
+                    outer.push(indices);
+                }
+                if !inset.is_empty() {
+                    let depth = if neighbor < 0 { thickness } else { 0.5 * thickness };
+                    let point = std::array::from_fn(|k| center[k] - depth * normal[k]);
+                    inset.clip(&point, &normal, neighbor, &mut scratch, None);
+                }
+            }
+            if inset.is_empty() {
+                continue;
+            }
+
+            // The inset cell is a cavity, so its faces point into it.
+            let (inset_vertices, inset_centroid) = (inset.vertices(), inset.centroid());
+            let start = mesh.vertices.len() / 3;
+            mesh.vertices.extend_from_slice(inset_vertices);
+            for face in inset.faces() {
+                let Some((_, normal)) = outward_plane(inset_vertices, &face, &inset_centroid) else { continue };
+                let mut indices: Vec<usize> = face.iter().map(|&v| start + v).collect();
+                if is_outward(inset_vertices, &face, &normal) {
+                    indices.reverse();
+                }
+                fan(&mut mesh.triangles, &indices);
+            }
+        }
+
+        let start = mesh.vertices.len() / 3;
+        mesh.vertices.extend(welder.vertices);
+        for face in outer {
+            let indices: Vec<usize> = face.iter().map(|&v| start + v).collect();
+            fan(&mut mesh.triangles, &indices);
+        }
+        mesh
+    }
+
+    /// Generates a mesh of each cell with the faces subdivided and displaced by seeded noise,
+    /// so fractured pieces look rough rather than planar.
+    ///
+    /// Each face is split into triangles around its center, and each of them into
+    /// `(subdivisions + 1)^2` triangles. The points inside a face are displaced along its normal
+    /// by `amplitude` times a smooth value noise of the position with the given spatial `frequency`.
+    /// The edges of the faces are kept in place, and a face shared by two cells is displaced in
+    /// the same direction in both, so neighboring meshes still fit together. The meshes are
+    /// returned in the order of the cells, and are empty for empty cells.
+    pub fn displaced_cell_meshes(&self, subdivisions: usize, amplitude: f64, frequency: f64, seed: u64) -> Vec<SolidMesh> {
+        let n = subdivisions + 1;
+        self.cells
+            .iter()
+            .enumerate()
+            .map(|(i, cell)| {
+                let mut welder = VertexWelder::new(&self.bounds);
+                let mut triangles = Vec::new();
+                if cell.is_empty() {
+                    return SolidMesh::default();
+                }
+                let vertices = cell.vertices();
+                let centroid = cell.centroid();
+                for (face, &neighbor) in cell.faces().iter().zip(cell.neighbors()) {
+                    let Some((center, normal)) = outward_plane(vertices, face, &centroid) else { continue };
+                    let mut face = face.clone();
+                    if !is_outward(vertices, &face, &normal) {
+                        face.reverse();
+                    }
+                    // The direction of the displacement is shared with the neighboring cell.
+                    let direction = if neighbor >= 0 && (neighbor as usize) < i { normal.map(|x| -x) } else { normal };
+                    let point = |v: usize| [vertices[v * 3], vertices[v * 3 + 1], vertices[v * 3 + 2]];
+
+                    for k in 0..face.len() {
+                        let (a, b) = (point(face[k]), point(face[(k + 1) % face.len()]));
+                        let mut grid = vec![0; (n + 1) * (n + 2) / 2];
+                        let index = |s: usize, t: usize| s * (2 * n + 3 - s) / 2 + t;
+                        for s in 0..=n {
+                            for t in 0..=n - s {
+                                let p: [f64; 3] = if s + t == n {
+                                    std::array::from_fn(|d| a[d] + (b[d] - a[d]) * t as f64 / n as f64)
+                                } else {
+                                    let p: [f64; 3] = std::array::from_fn(|d| {
+                                        center[d] + (a[d] - center[d]) * s as f64 / n as f64 + (b[d] - center[d]) * t as f64 / n as f64
+                                    });
+                                    let offset = amplitude * value_noise(p.map(|x| x * frequency), seed);
+                                    std::array::from_fn(|d| p[d] + offset * direction[d])
+                                };
+                                grid[index(s, t)] = welder.weld(&p);
+                            }
+                        }
+                        for s in 0..n {
+                            for t in 0..n - s {
+                                triangles.push([grid[index(s, t)], grid[index(s + 1, t)], grid[index(s, t + 1)]]);
+                                if s + t + 1 < n {
+                                    triangles.push([grid[index(s + 1, t)], grid[index(s + 1, t + 1)], grid[index(s, t + 1)]]);
+                                }
+                            }
+                        }
+                    }
+                }
+                SolidMesh { vertices: welder.vertices, triangles }
+            })
+            .collect()
+    }
+
+    /// Collects the vertices of all cells, welded across cells, and the edges between them,
+    /// each once as a pair of vertex indices in increasing order.
+    fn edge_network(&self) -> (Vec<f64>, BTreeSet<(usize, usize)>) {
+        let mut welder = VertexWelder::new(&self.bounds);
+        let mut edges = BTreeSet::new();
+        for cell in &self.cells {
+            let vertices = cell.vertices();
+            for face in cell.faces() {
+                let indices: Vec<usize> = face.iter().map(|&v| welder.weld(&vertices[v * 3..v * 3 + 3])).collect();
+                for (k, &a) in indices.iter().enumerate() {
+                    let b = indices[(k + 1) % indices.len()];
+                    if a != b {
+                        edges.insert((a.min(b), a.max(b)));
+                    }
+                }
+            }
+        }
+        (welder.vertices, edges)
+    }
+}
+
+/// Two unit vectors that form a right-handed orthonormal basis with the unit vector `d`.
+fn perpendicular_basis(d: [f64; 3]) -> ([f64; 3], [f64; 3]) {
+    let other = if d[0].abs() < 0.9 { [1.0, 0.0, 0.0] } else { [0.0, 1.0, 0.0] };
+    let u = [d[1] * other[2] - d[2] * other[1], d[2] * other[0] - d[0] * other[2], d[0] * other[1] - d[1] * other[0]];
+    let length = (u[0] * u[0] + u[1] * u[1] + u[2] * u[2]).sqrt();
+    let u = u.map(|x| x / length);
+    let v = [d[1] * u[2] - d[2] * u[1], d[2] * u[0] - d[0] * u[2], d[0] * u[1] - d[1] * u[0]];
+    (u, v)
+}
+
+/// The Newell normal of a face, following its winding order.
+fn newell_normal(vertices: &[f64], face: &[usize]) -> [f64; 3] {
+    let mut n = [0.0; 3];
+    for (k, &a) in face.iter().enumerate() {
+        let b = face[(k + 1) % face.len()];
+        let (pa, pb) = (&vertices[a * 3..a * 3 + 3], &vertices[b * 3..b * 3 + 3]);
+        n[0] += (pa[1] - pb[1]) * (pa[2] + pb[2]);
+        n[1] += (pa[2] - pb[2]) * (pa[0] + pb[0]);
+        n[2] += (pa[0] - pb[0]) * (pa[1] + pb[1]);
+    }
+    n
+}
+
+/// The center and the outward unit normal of a face of the convex cell with the given centroid,
+/// or `None` for a degenerate face.
+fn outward_plane(vertices: &[f64], face: &[usize], centroid: &[f64; 3]) -> Option<([f64; 3], [f64; 3])> {
+    let n = newell_normal(vertices, face);
+    let length = (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt();
+    if face.len() < 3 || length == 0.0 {
+        return None;
+    }
+    let center: [f64; 3] = std::array::from_fn(|k| face.iter().map(|&v| vertices[v * 3 + k]).sum::<f64>() / face.len() as f64);
+    let outward: f64 = (0..3).map(|k| n[k] * (center[k] - centroid[k])).sum();
+    let sign = if outward < 0.0 { -1.0 } else { 1.0 };
+    Some((center, n.map(|x| sign * x / length)))
+}
+
+/// Whether the winding order of a face is counter-clockwise seen along the outward `normal`.
+fn is_outward(vertices: &[f64], face: &[usize], normal: &[f64; 3]) -> bool {
+    let n = newell_normal(vertices, face);
+    (0..3).map(|k| n[k] * normal[k]).sum::<f64>() > 0.0
+}
+
+/// Triangulates a convex polygon as a fan around its first vertex.
+fn fan(triangles: &mut Vec<[usize; 3]>, polygon: &[usize]) {
+    for k in 1..polygon.len().saturating_sub(1) {
+        triangles.push([polygon[0], polygon[k], polygon[k + 1]]);
+    }
+}
+
+/// Smooth value noise in `[-1, 1]`, interpolating seeded random values at the integer lattice points.
+fn value_noise(p: [f64; 3], seed: u64) -> f64 {
+    let cell = p.map(f64::floor);
+    let f: [f64; 3] = std::array::from_fn(|k| {
+        let t = p[k] - cell[k];
+        t * t * (3.0 - 2.0 * t)
+    });
+    let lattice = |dx: i64, dy: i64, dz: i64| {
+        let mut h = seed;
+        for (c, d) in cell.iter().zip([dx, dy, dz]) {
+            h = splitmix64(h ^ (*c as i64 + d) as u64);
+        }
+        (h >> 11) as f64 / (1u64 << 53) as f64 * 2.0 - 1.0
+    };
+    let lerp = |a: f64, b: f64, t: f64| a + (b - a) * t;
+    let plane = |dz: i64| {
+        lerp(
+            lerp(lattice(0, 0, dz), lattice(1, 0, dz), f[0]),
+            lerp(lattice(0, 1, dz), lattice(1, 1, dz), f[0]),
+            f[1],
+        )
+    };
+    lerp(plane(0), plane(1), f[2])
+}
+
+/// The SplitMix64 mixing function, used to hash the noise lattice points.
+fn splitmix64(x: u64) -> u64 {
+    let mut z = x.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
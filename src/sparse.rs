@@ -0,0 +1,120 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use std::collections::BTreeMap;
+
+/// A square sparse matrix over the cells in compressed sparse row (CSR) format.
+///
+/// The entries of row `i` are stored at `row_offsets[i]..row_offsets[i + 1]` in `columns`
+/// and `values`, with the columns in increasing order.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct SparseMatrix {
+    /// The number of rows and columns.
+    pub size: usize,
+    /// The offset of the first entry of each row, followed by the number of entries.
+    pub row_offsets: Vec<usize>,
+    /// The column of each entry.
+    pub columns: Vec<usize>,
+    /// The value of each entry.
+    pub values: Vec<f64>,
+}
+
+impl SparseMatrix {
+    /// Creates a matrix from `(row, column, value)` entries, summing duplicate entries.
+    /// Entries outside the matrix are ignored.
+    pub fn from_triplets(size: usize, triplets: impl IntoIterator<Item = (usize, usize, f64)>) -> Self {
+        let mut entries: BTreeMap<(usize, usize), f64> = BTreeMap::new();
+        for (i, j, v) in triplets {
+            if i < size && j < size {
+                *entries.entry((i, j)).or_insert(0.0) += v;
+            }
+        }
+
+        let mut matrix = SparseMatrix { size, row_offsets: vec![0; size + 1], ..Default::default() };
+        for (&(i, j), &v) in &entries {
+            matrix.row_offsets[i + 1] += 1;
+            matrix.columns.push(j);
+            matrix.values.push(v);
+        }
+        for i in 0..size {
+            matrix.row_offsets[i + 1] += matrix.row_offsets[i];
+        }
+        matrix
+    }
+
+    /// Returns the number of stored entries.
+    pub fn nnz(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns the `(column, value)` entries of row `i`.
+    pub fn row(&self, i: usize) -> impl Iterator<Item = (usize, f64)> + '_ {
+        let range = self.row_offsets[i]..self.row_offsets[i + 1];
+        self.columns[range.clone()].iter().copied().zip(self.values[range].iter().copied())
+    }
+
+    /// Returns the entry at row `i` and column `j`, or zero if it is not stored.
+    pub fn get(&self, i: usize, j: usize) -> f64 {
+        if i >= self.size {
+            return 0.0;
+        }
+        let start = self.row_offsets[i];
+        let columns = &self.columns[start..self.row_offsets[i + 1]];
+        columns.binary_search(&j).map_or(0.0, |k| self.values[start + k])
+    }
+
+    /// Multiplies the matrix with the vector `x` of length `size`.
+    pub fn multiply(&self, x: &[f64]) -> Vec<f64> {
+        (0..self.size).map(|i| self.row(i).map(|(j, v)| v * x[j]).sum()).collect()
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Returns the area of the face shared by each pair of adjacent cells (the edge length in 2D),
+    /// as a symmetric sparse matrix with an entry for each pair.
+    ///
+    /// The area of a shared face is computed by both cells, which differ by rounding,
+    /// so the matrix holds their average. A face that only one of the cells reports is taken as is.
+    /// Degenerate faces without area, such as those of cells touching only at an edge, are left out.
+    pub fn face_area_matrix(&self) -> SparseMatrix {
+        let n = self.cells.len();
+        let mut pairs: BTreeMap<(usize, usize), (f64, [bool; 2])> = BTreeMap::new();
+        for (i, cell) in self.cells.iter().enumerate() {
+            for (f, &neighbor) in cell.neighbors().iter().enumerate() {
+                let j = neighbor as usize;
+                if neighbor < 0 || j >= n || j == i {
+                    continue;
+                }
+                let (side, key) = if i < j { (0, (i, j)) } else { (1, (j, i)) };
+                let entry = pairs.entry(key).or_insert((0.0, [false; 2]));
+                entry.0 += cell.face_area(f);
+                entry.1[side] = true;
+            }
+        }
+
+        SparseMatrix::from_triplets(
+            n,
+            pairs
+                .into_iter()
+                .map(|((i, j), (area, sides))| (i, j, if sides[0] && sides[1] { 0.5 * area } else { area }))
+                .filter(|&(_, _, area)| area > 0.0)
+                .flat_map(|(i, j, area)| [(i, j, area), (j, i, area)]),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sparse_matrix() {
+        let m = SparseMatrix::from_triplets(3, [(0, 1, 2.0), (2, 0, 1.0), (0, 1, 1.0), (1, 1, 4.0), (3, 0, 9.0)]);
+        assert_eq!(m.row_offsets, vec![0, 1, 2, 3]);
+        assert_eq!(m.nnz(), 3);
+        assert_eq!(m.get(0, 1), 3.0);
+        assert_eq!(m.get(1, 0), 0.0);
+        assert_eq!(m.row(2).collect::<Vec<_>>(), vec![(0, 1.0)]);
+        assert_eq!(m.multiply(&[1.0, 2.0, 3.0]), vec![6.0, 8.0, 1.0]);
+    }
+}
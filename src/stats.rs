@@ -0,0 +1,151 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+
+/// The spacing of the bins of a [`Histogram`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Binning {
+    /// Bins of equal width.
+    #[default]
+    Linear,
+    /// Bins of equal width on a logarithmic scale, for quantities spanning orders of magnitude.
+    /// Values that are not positive are left out.
+    Log,
+}
+
+/// The counts of values in consecutive bins.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Histogram {
+    /// The `bins + 1` edges of the bins in increasing order.
+    pub edges: Vec<f64>,
+    /// The number of values in each bin. A bin includes its lower edge,
+    /// and the last bin also its upper edge.
+    pub counts: Vec<usize>,
+}
+
+impl Histogram {
+    /// Counts the `values` in `bins` bins spanning their range.
+    /// Non-finite values are left out.
+    pub fn new(values: &[f64], bins: usize, binning: Binning) -> Self {
+        let (min, max) = values
+            .iter()
+            .filter(|&&v| is_valid(v, binning))
+            .fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), &v| (lo.min(v), hi.max(v)));
+        if min > max {
+            return Self { edges: Vec::new(), counts: Vec::new() };
+        }
+        Self::with_range(values, bins, binning, min, max)
+    }
+
+    /// Counts the `values` in `bins` bins spanning `min` to `max`, so that histograms of different
+    /// data sets can be compared. Values outside the range and non-finite values are left out.
+    pub fn with_range(values: &[f64], bins: usize, binning: Binning, min: f64, max: f64) -> Self {
+        let bins = bins.max(1);
+        let (lo, hi) = match binning {
+            Binning::Linear => (min, max),
+            Binning::Log => (min.ln(), max.ln()),
+        };
+        let edges: Vec<f64> = (0..=bins)
+            .map(|k| {
+                // The outer edges are kept exact, without rounding in the exponential.
+                if k == 0 {
+                    return min;
+                } else if k == bins {
+                    return max;
+                }
+                let t = lo + (hi - lo) * k as f64 / bins as f64;
+                match binning {
+                    Binning::Linear => t,
+                    Binning::Log => t.exp(),
+                }
+            })
+            .collect();
+        let mut histogram = Self { edges, counts: vec![0; bins] };
+        for &v in values {
+            if let Some(bin) = histogram.bin(v) {
+                histogram.counts[bin] += 1;
+            }
+        }
+        histogram
+    }
+
+    /// Returns the bin of a value, or `None` if it is outside all bins.
+    pub fn bin(&self, value: f64) -> Option<usize> {
+        let (&min, &max) = (self.edges.first()?, self.edges.last()?);
+        if !(value >= min && value <= max) {
+            return None;
+        }
+        // The edges are increasing, so the bin is the last edge not above the value.
+        let bin = self.edges.partition_point(|&e| e <= value).saturating_sub(1);
+        Some(bin.min(self.counts.len() - 1))
+    }
+
+    /// Returns the center of each bin, the geometric mean of the edges for logarithmic bins.
+    pub fn centers(&self, binning: Binning) -> Vec<f64> {
+        self.edges
+            .windows(2)
+            .map(|e| match binning {
+                Binning::Linear => 0.5 * (e[0] + e[1]),
+                Binning::Log => (e[0] * e[1]).sqrt(),
+            })
+            .collect()
+    }
+
+    /// Returns the total number of counted values.
+    pub fn total(&self) -> usize {
+        self.counts.iter().sum()
+    }
+
+    /// Returns the mean of `values` over the entries whose `keys` fall in each bin, for example
+    /// the mean volume of the cells by distance to a wall. Bins without entries have a NaN mean.
+    pub fn binned_means(&self, keys: &[f64], values: &[f64]) -> Vec<f64> {
+        let mut sums = vec![0.0; self.counts.len()];
+        let mut counts = vec![0usize; self.counts.len()];
+        for (&key, &value) in keys.iter().zip(values) {
+            if let Some(bin) = self.bin(key) {
+                sums[bin] += value;
+                counts[bin] += 1;
+            }
+        }
+        sums.iter().zip(counts).map(|(&s, n)| if n > 0 { s / n as f64 } else { f64::NAN }).collect()
+    }
+}
+
+fn is_valid(value: f64, binning: Binning) -> bool {
+    value.is_finite() && (binning == Binning::Linear || value > 0.0)
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Builds a histogram of a per-cell quantity, such as the volume or the number of faces,
+    /// over the non-empty cells.
+    pub fn cell_histogram<F: Fn(&C) -> f64>(&self, quantity: F, bins: usize, binning: Binning) -> Histogram {
+        let values: Vec<f64> = self.cells.iter().filter(|c| !c.is_empty()).map(quantity).collect();
+        Histogram::new(&values, bins, binning)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_histogram() {
+        let values = [0.0, 0.5, 1.0, 1.5, 2.0, 4.0, f64::NAN];
+        let histogram = Histogram::new(&values, 4, Binning::Linear);
+        assert_eq!(histogram.edges, vec![0.0, 1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(histogram.counts, vec![2, 2, 1, 1]);
+        assert_eq!(histogram.centers(Binning::Linear), vec![0.5, 1.5, 2.5, 3.5]);
+        assert_eq!(histogram.bin(-1.0), None);
+        let means = histogram.binned_means(&values, &[1.0, 3.0, 2.0, 2.0, 5.0, 7.0, 9.0]);
+        assert_eq!(&means[..2], &[2.0, 2.0]);
+
+        // Logarithmic bins leave out values that are not positive.
+        let histogram = Histogram::new(&[0.0, 1.0, 5.0, 20.0, 50.0, 100.0], 2, Binning::Log);
+        assert_eq!(histogram.counts, vec![2, 3]);
+        assert!((histogram.edges[1] - 10.0).abs() < 1e-12);
+        assert!((histogram.centers(Binning::Log)[0] - 10f64.sqrt()).abs() < 1e-12);
+
+        assert_eq!(Histogram::new(&[], 3, Binning::Linear).total(), 0);
+        assert_eq!(Histogram::with_range(&[1.0, 5.0], 2, Binning::Linear, 0.0, 2.0).counts, vec![0, 1]);
+    }
+}
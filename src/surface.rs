@@ -0,0 +1,174 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::box_side;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::merge::VertexWelder;
+use crate::tessellation::{Tessellation, point_at};
+use std::collections::BTreeMap;
+
+/// The faces of the cells on a wall or bounding box side, as returned by
+/// [`Tessellation::faces_on_wall`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WallSurface<const D: usize> {
+    /// The ID of the wall or bounding box side.
+    pub wall_id: i32,
+    /// The vertex coordinates as a flat array `[x, y, (z), ...]`.
+    pub vertices: Vec<f64>,
+    /// The faces (edges in 2D) as indices into the vertices, ordered as in the original cells.
+    pub faces: Vec<Vec<usize>>,
+    /// The cell of each face, in increasing order.
+    pub cells: Vec<usize>,
+    /// The total area (length in 2D) of the faces.
+    pub area: f64,
+}
+
+/// The contact area between the cells and each wall or bounding box side,
+/// as returned by [`Tessellation::wall_areas`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WallAreas {
+    /// The total area (length in 2D) of the faces on each wall or bounding box side, by ID.
+    pub totals: BTreeMap<i32, f64>,
+    /// The area of the faces of each cell on each wall or bounding box side, by ID,
+    /// as `(cell, area)` in increasing order of the cells.
+    pub contributions: BTreeMap<i32, Vec<(usize, f64)>>,
+}
+
+/// The volume of each label and the interface area between each pair of labels, as returned by
+/// [`Tessellation::volume_by_label`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LabelVolumes {
+    /// The total volume (area in 2D) of the cells of each label.
+    pub volumes: BTreeMap<u32, f64>,
+    /// The total area (length in 2D) of the faces between the cells of two labels, by the labels
+    /// in increasing order. A pair of equal labels holds the internal boundaries of that label.
+    pub interfaces: BTreeMap<(u32, u32), f64>,
+}
+
+impl LabelVolumes {
+    /// Returns the fraction of the total volume taken by each label, or an empty map without volume.
+    pub fn volume_fractions(&self) -> BTreeMap<u32, f64> {
+        let total: f64 = self.volumes.values().sum();
+        if total <= 0.0 {
+            return BTreeMap::new();
+        }
+        self.volumes.iter().map(|(&label, &volume)| (label, volume / total)).collect()
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Sums the volume of the cells of each label and the area of the faces between the cells of
+    /// each pair of labels, for phase-fraction analysis of multiphase microstructures. `labels`
+    /// holds the label of each generator, including ghosts, whose faces count towards the
+    /// interfaces but which have no volume.
+    ///
+    /// Each face between two cells is counted once, faces on walls and bounding box sides are left
+    /// out, and the sums are taken in the order of the cells. Returns a
+    /// [`GeneratorError::CountMismatch`] if there is not one label per generator.
+    pub fn volume_by_label(&self, labels: &[u32]) -> Result<LabelVolumes, GeneratorError> {
+        if labels.len() != self.count_generators() {
+            return Err(GeneratorError::CountMismatch(self.count_generators(), labels.len()));
+        }
+        let mut result = LabelVolumes::default();
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.is_empty() {
+                continue;
+            }
+            *result.volumes.entry(labels[i]).or_insert(0.0) += cell.volume();
+            for (f, &neighbor) in cell.neighbors().iter().enumerate() {
+                // A face between two cells is counted by the lower one, a face to a ghost by the cell.
+                let j = neighbor as usize;
+                if neighbor < 0 || j >= labels.len() || (j < self.cells.len() && j <= i) {
+                    continue;
+                }
+                let pair = (labels[i].min(labels[j]), labels[i].max(labels[j]));
+                *result.interfaces.entry(pair).or_insert(0.0) += cell.face_area(f);
+            }
+        }
+        Ok(result)
+    }
+
+    /// Sums the area of the cell faces on each wall and bounding box side, along with the
+    /// contribution of every cell, for surface estimates such as heat transfer through a wall.
+    /// Sides without any faces are left out, and the sums are taken in the order of the cells.
+    pub fn wall_areas(&self) -> WallAreas {
+        let mut areas = WallAreas::default();
+        for (i, cell) in self.cells.iter().enumerate() {
+            let mut cell_areas: BTreeMap<i32, f64> = BTreeMap::new();
+            for (f, &neighbor) in cell.neighbors().iter().enumerate() {
+                if neighbor < 0 {
+                    *cell_areas.entry(neighbor).or_insert(0.0) += cell.face_area(f);
+                }
+            }
+            for (id, area) in cell_areas {
+                *areas.totals.entry(id).or_insert(0.0) += area;
+                areas.contributions.entry(id).or_default().push((i, area));
+            }
+        }
+        areas
+    }
+
+    /// Returns the nearest wall or bounding box side of each owned generator, as its ID and the
+    /// signed distance to it (positive inside), for analyzing properties by the distance from
+    /// the container surface. Walls whose [`Wall::signed_distance`](crate::Wall::signed_distance)
+    /// is unknown for a generator are skipped.
+    pub fn wall_distances(&self) -> Vec<(i32, f64)> {
+        (0..self.count_owned())
+            .map(|i| {
+                let p: [f64; D] = point_at(&self.generators, i);
+                let mut nearest = (0, f64::INFINITY);
+                for (k, &x) in p.iter().enumerate() {
+                    for (is_max, d) in [(false, x - self.bounds.min[k]), (true, self.bounds.max[k] - x)] {
+                        if d < nearest.1 {
+                            nearest = (box_side(k, is_max), d);
+                        }
+                    }
+                }
+                for wall in &self.walls {
+                    if let Some(d) = wall.signed_distance(&p).filter(|d| *d < nearest.1) {
+                        nearest = (wall.id(), d);
+                    }
+                }
+                nearest
+            })
+            .collect()
+    }
+
+    /// Collects the faces of all cells whose neighbor is the wall or bounding box side `wall_id`,
+    /// for example to texture or analyze the contact surface with a wall.
+    ///
+    /// With `weld`, the vertices shared by faces of different cells are merged into a single
+    /// surface mesh. Otherwise each face keeps its own vertices, so per-cell attributes
+    /// can be assigned to them. Cells that do not provide [`Cell::faces`] are skipped.
+    pub fn faces_on_wall(&self, wall_id: i32, weld: bool) -> WallSurface<D> {
+        let mut surface = WallSurface { wall_id, ..Default::default() };
+        let mut welder = VertexWelder::new(&self.bounds);
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            let vertices = cell.vertices();
+            for (f, (face, &neighbor)) in cell.faces().iter().zip(cell.neighbors()).enumerate() {
+                if neighbor != wall_id {
+                    continue;
+                }
+                let indices = face
+                    .iter()
+                    .map(|&v| {
+                        let point = &vertices[v * D..(v + 1) * D];
+                        if weld {
+                            welder.weld(point)
+                        } else {
+                            surface.vertices.extend_from_slice(point);
+                            surface.vertices.len() / D - 1
+                        }
+                    })
+                    .collect();
+                surface.faces.push(indices);
+                surface.cells.push(i);
+                surface.area += cell.face_area(f);
+            }
+        }
+        if weld {
+            surface.vertices = welder.vertices;
+        }
+        surface
+    }
+}
@@ -1,15 +1,81 @@
-use crate::bounds::BoundingBox;
+use crate::bounds::{BoundingBox, box_side};
 use crate::algorithm::SpatialAlgorithm;
 use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::snapshot::SnapshotHandle;
+use crate::trace::{Counter, LocalCounter, Tally};
+#[cfg(feature = "tracing")]
+use crate::trace::CalculationCounters;
+use crate::transform::DomainTransform;
 use crate::wall::Wall;
 use rayon::prelude::*;
 use rand::prelude::*;
 use rand::rngs::StdRng;
 use std::fs::File;
 use std::io::{BufRead, BufReader};
+use std::collections::HashMap;
 use std::convert::TryInto;
 
+/// The distance within which a generator is considered to lie on a wall or bounding box face.
+/// This matches the tolerance used by the cell clipping.
+pub(crate) const BOUNDARY_TOLERANCE: f64 = 1e-9;
+
+/// The default neighbor search safety factor. Only generators within twice the radius of a cell
+/// around its generator can cut it, so a factor of 4 on the squared radius is exact.
+const DEFAULT_SEARCH_FACTOR: f64 = 4.0;
+
+/// The number of times [`Tessellation::limit_displacement`] halves a step that leaves the domain.
+const MAX_STEP_HALVINGS: usize = 16;
+
+/// The distance from the generator, relative to the radius of the cell, below which
+/// [`Tessellation::refine_where`] does not insert at the centroid.
+const REFINE_MIN_OFFSET: f64 = 0.1;
+
+/// The number of times [`Tessellation::relax`] projects a centroid onto the violated wall planes
+/// before keeping the previous generator position.
+const MAX_PROJECTION_PASSES: usize = 8;
+
+/// The fraction of the generators above which [`Tessellation::set_generators_sparse`] rebuilds
+/// the spatial algorithm instead of updating each generator.
+const SPARSE_REBIN_FRACTION: f64 = 0.25;
+
+/// The number of points [`Tessellation::domain_volume`] samples for walls without a known volume.
+const DOMAIN_VOLUME_SAMPLES: usize = 1 << 20;
+
+/// The number of uniform samples [`Tessellation::random_generators_density_seeded`] draws
+/// to estimate the largest density before sampling.
+const DENSITY_PILOT_SAMPLES: usize = 1000;
+
+/// The settings of the neighbor search of a cell, see [`Tessellation::set_search_factor`] and
+/// [`Tessellation::set_candidate_buffer`].
+#[derive(Clone, Copy, Debug)]
+struct NeighborSearch {
+    factor: f64,
+    buffer: usize,
+}
+
+/// Determines how generators lying on a wall or bounding box face are handled
+/// by [`Tessellation::set_generators`] and [`Tessellation::add_wall`].
+///
+/// The wall containment checks are inclusive, while the clipping uses a small tolerance,
+/// so generators exactly on a boundary can produce degenerate cells.
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum BoundaryPolicy {
+    /// Discards generators that lie on a boundary or outside the bounding box.
+    Reject,
+    /// Moves generators that lie on or within the given distance of a boundary
+    /// to that distance inside the valid region.
+    SnapInside(f64),
+    /// Keeps generators on boundaries, only discarding those outside the walls.
+    #[default]
+    Keep,
+}
+
 /// A geometry-based Voronoi tessellation that unifies the [`Cell`], [`SpatialAlgorithm`], and [`Wall`] traits.
+///
+/// This is the single engine behind the 2D and 3D tessellations, including the WebAssembly
+/// wrappers: binning, neighbor search and clipping are generic over the cell type `C` and the
+/// spatial algorithm `A`, so user provided implementations of either trait plug in directly.
 pub struct Tessellation<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> {
     pub bounds: BoundingBox<D>,
     pub generators: Vec<f64>,
@@ -19,6 +85,33 @@ pub struct Tessellation<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> {
     pub seal_log: Vec<i32>,
     pub prune_log: Vec<i32>,
     pub prune_pos_log: Vec<f64>,
+    /// The handling of generators on boundaries, see [`Tessellation::set_boundary_policy`].
+    pub(crate) boundary_policy: BoundaryPolicy,
+    /// Whether statistics over the cells are reduced in a stable order, see [`Tessellation::set_deterministic`].
+    pub(crate) deterministic: bool,
+    /// The safety factor of the neighbor search, see [`Tessellation::set_search_factor`].
+    pub(crate) search_factor: f64,
+    /// The number of ghost generators stored after the owned ones, see [`Tessellation::set_ghosts`].
+    pub(crate) ghost_count: usize,
+    /// The margin of each open side of the bounding box, see [`Tessellation::set_open_face`].
+    pub(crate) open_faces: [[Option<f64>; 2]; D],
+    /// The candidate buffer overriding that of the algorithm, see [`Tessellation::set_candidate_buffer`].
+    pub(crate) candidate_buffer: Option<usize>,
+    /// The external IDs of the generators, empty if none are registered, see [`Tessellation::set_generator_ids`].
+    pub(crate) generator_ids: Vec<u64>,
+    /// The generator index of each external ID.
+    pub(crate) id_lookup: HashMap<u64, usize>,
+    /// The site of each generator, empty if each generator is its own site, see [`Tessellation::set_sites`].
+    pub(crate) sites: Vec<usize>,
+    /// The map from physical to computed coordinates, see [`Tessellation::set_domain_transform`].
+    pub(crate) domain_transform: Option<DomainTransform<D>>,
+    /// Whether each cell is approximate, empty if all cells are exact, see [`Tessellation::preview_calculate`].
+    pub(crate) approximate: Vec<bool>,
+    /// The cells published by [`Tessellation::swap`].
+    pub(crate) snapshot: SnapshotHandle<C>,
+    /// The counters of the last calculation, see [`Tessellation::counters`].
+    #[cfg(feature = "tracing")]
+    pub(crate) counters: CalculationCounters,
 }
 
 impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
@@ -32,45 +125,266 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
             seal_log: Vec::new(),
             prune_log: Vec::new(),
             prune_pos_log: Vec::new(),
+            boundary_policy: BoundaryPolicy::default(),
+            deterministic: true,
+            search_factor: DEFAULT_SEARCH_FACTOR,
+            ghost_count: 0,
+            open_faces: [[None; 2]; D],
+            candidate_buffer: None,
+            generator_ids: Vec::new(),
+            id_lookup: HashMap::new(),
+            sites: Vec::new(),
+            domain_transform: None,
+            approximate: Vec::new(),
+            snapshot: SnapshotHandle::default(),
+            #[cfg(feature = "tracing")]
+            counters: CalculationCounters::default(),
+        }
+    }
+
+    /// Sets the policy for generators lying on a wall or bounding box face.
+    /// It is applied to subsequently set generators and added walls.
+    pub fn set_boundary_policy(&mut self, policy: BoundaryPolicy) {
+        self.boundary_policy = policy;
+    }
+
+    /// Returns the policy for generators lying on a wall or bounding box face, see
+    /// [`Tessellation::set_boundary_policy`].
+    pub fn boundary_policy(&self) -> BoundaryPolicy {
+        self.boundary_policy
+    }
+
+    /// Sets whether statistics over the cells are reduced in a stable order (the default).
+    ///
+    /// The computed cells themselves are always bit-identical regardless of the number of
+    /// threads. With `deterministic` disabled, reductions such as [`Tessellation::total_volume`]
+    /// run in parallel, so their floating-point summation order depends on the scheduling.
+    pub fn set_deterministic(&mut self, deterministic: bool) {
+        self.deterministic = deterministic;
+    }
+
+    /// Returns `true` if statistics over the cells are reduced in a stable order, see
+    /// [`Tessellation::set_deterministic`].
+    pub fn is_deterministic(&self) -> bool {
+        self.deterministic
+    }
+
+    /// Sets the safety factor of the neighbor search: candidates are searched up to a squared
+    /// distance of `factor` times the squared radius of the cell around its generator.
+    ///
+    /// The default of 4 is exact. Smaller factors stop the search earlier, which is faster for
+    /// well-separated generators but may miss neighbors and leave cells too large, while larger
+    /// factors visit more candidates as a margin against rounding.
+    pub fn set_search_factor(&mut self, factor: f64) {
+        self.search_factor = factor;
+    }
+
+    /// Returns the safety factor of the neighbor search, see [`Tessellation::set_search_factor`].
+    pub fn search_factor(&self) -> f64 {
+        self.search_factor
+    }
+
+    /// Overrides the candidate buffer of the spatial algorithm, see
+    /// [`SpatialAlgorithm::candidate_buffer`], or restores it with `None`.
+    ///
+    /// With a buffer of `n`, up to `n` candidates of the neighbor search are held back and the one
+    /// with the lowest [`SpatialAlgorithm::candidate_priority`] is clipped first, so closer
+    /// generators shrink the cell earlier and fewer candidates need clipping. The cells are the
+    /// same for any buffer up to rounding, while 0 keeps the native order of the algorithm.
+    pub fn set_candidate_buffer(&mut self, buffer: Option<usize>) {
+        self.candidate_buffer = buffer;
+    }
+
+    /// Returns the generators the cell at `index` is clipped against by the neighbor search, in
+    /// the order of clipping, to inspect the effect of [`Tessellation::set_candidate_buffer`].
+    /// Walls are not included, and an empty list is returned for invalid indices.
+    pub fn candidate_order(&self, index: usize) -> Vec<usize> {
+        let mut order = Vec::new();
+        if index < self.count_owned() {
+            let mut scratch = C::Scratch::default();
+            Self::compute_cell_with(
+                index, &self.generators, &self.clip_bounds(), &self.walls, &self.algorithm, self.neighbor_search(), &[],
+                usize::MAX, &mut scratch, Some(&mut order),
+            );
+        }
+        order
+    }
+
+    fn neighbor_search(&self) -> NeighborSearch {
+        NeighborSearch {
+            factor: self.search_factor,
+            buffer: self.candidate_buffer.unwrap_or_else(|| self.algorithm.candidate_buffer()),
         }
     }
 
+    /// Replaces the spatial algorithm and indexes the current generators with it, for example to
+    /// switch to an algorithm suited to the measured distribution of the generators. The cells
+    /// do not depend on the algorithm, so computed cells remain valid.
+    pub fn set_algorithm(&mut self, algorithm: A) {
+        self.algorithm = algorithm;
+        self.bin_generators();
+    }
+
+    /// Rebuilds the spatial algorithm from the current generators.
+    pub(crate) fn bin_generators(&mut self) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("bin_generators", generators = self.generators.len() / D).entered();
+        self.algorithm.set_generators(&self.generators, &self.bounds);
+    }
+
+    /// Applies the boundary policy to a point, returning the (possibly moved) point
+    /// if it should be accepted as a generator.
+    fn apply_boundary_policy(&self, point: &[f64; D]) -> Option<[f64; D]> {
+        let contained = |p: &[f64; D]| self.walls.iter().all(|w| w.contains(p));
+        match self.boundary_policy {
+            BoundaryPolicy::Keep => contained(point).then_some(*point),
+            BoundaryPolicy::Reject => {
+                let inside_box = (0..D).all(|k| {
+                    point[k] - self.bounds.min[k] > BOUNDARY_TOLERANCE
+                        && self.bounds.max[k] - point[k] > BOUNDARY_TOLERANCE
+                });
+                let off_walls = self.walls.iter().all(|w| {
+                    let mut off = true;
+                    w.cut(point, &mut |q, n| {
+                        if signed_distance(point, &q, &n) > -BOUNDARY_TOLERANCE {
+                            off = false;
+                        }
+                    });
+                    off
+                });
+                (inside_box && off_walls && contained(point)).then_some(*point)
+            }
+            BoundaryPolicy::SnapInside(epsilon) => {
+                let mut p = *point;
+                for (k, v) in p.iter_mut().enumerate() {
+                    let (min, max) = (self.bounds.min[k], self.bounds.max[k]);
+                    if *v < min - BOUNDARY_TOLERANCE || *v > max + BOUNDARY_TOLERANCE {
+                        return None;
+                    }
+                    *v = v.clamp(min + epsilon, max - epsilon);
+                }
+                for wall in &self.walls {
+                    let mut shift = None;
+                    wall.cut(&p, &mut |q, n| {
+                        let d = signed_distance(&p, &q, &n);
+                        if d > -epsilon && d <= BOUNDARY_TOLERANCE {
+                            shift = Some((d + epsilon, n));
+                        }
+                    });
+                    if let Some((d, n)) = shift {
+                        let len = n.iter().map(|v| v * v).sum::<f64>().sqrt();
+                        for k in 0..D {
+                            p[k] -= d * n[k] / len;
+                        }
+                    }
+                }
+                contained(&p).then_some(p)
+            }
+        }
+    }
+
+    /// Moves a point outside the walls or the bounding box onto the planes of the violated wall
+    /// cuts and into the box, repeated while moving off one wall violates another. Returns `None`
+    /// if the point is still outside after [`MAX_PROJECTION_PASSES`] passes.
+    fn project_inside(&self, point: &[f64; D]) -> Option<[f64; D]> {
+        let mut p = *point;
+        for _ in 0..MAX_PROJECTION_PASSES {
+            if self.walls.iter().all(|w| w.contains(&p)) {
+                return Some(p);
+            }
+            for wall in &self.walls {
+                if wall.contains(&p) {
+                    continue;
+                }
+                let mut shift = [0.0; D];
+                wall.cut(&p, &mut |q, n| {
+                    let length = n.iter().map(|v| v * v).sum::<f64>().sqrt();
+                    let d = signed_distance(&p, &q, &n);
+                    // Cut planes the point lies beyond, with a margin to end up strictly inside.
+                    if length > 0.0 && d > -BOUNDARY_TOLERANCE {
+                        for k in 0..D {
+                            shift[k] -= (d + 2.0 * BOUNDARY_TOLERANCE) * n[k] / length;
+                        }
+                    }
+                });
+                for k in 0..D {
+                    p[k] = (p[k] + shift[k]).clamp(self.bounds.min[k], self.bounds.max[k]);
+                }
+            }
+        }
+        self.walls.iter().all(|w| w.contains(&p)).then_some(p)
+    }
+
+    /// Returns a [`GeneratorError::NonFinite`] with the indices of the points in the flat array
+    /// `coords` that have a NaN or infinite coordinate, if there are any.
+    pub(crate) fn check_finite(coords: &[f64]) -> Result<(), GeneratorError> {
+        let invalid: Vec<usize> = coords
+            .chunks_exact(D)
+            .enumerate()
+            .filter(|(_, p)| p.iter().any(|v| !v.is_finite()))
+            .map(|(i, _)| i)
+            .collect();
+        if invalid.is_empty() { Ok(()) } else { Err(GeneratorError::NonFinite(invalid)) }
+    }
+
     /// Update all generators at once. Only accepts generators that are inside the
-    /// bounding box and contained by the walls.
-    /// 
+    /// bounding box and contained by the walls, subject to the [`BoundaryPolicy`].
+    ///
+    /// Returns a [`GeneratorError::NonFinite`] with the offending indices if any coordinate
+    /// is NaN or infinite, in which case the current generators are left unchanged.
+    /// Use [`Tessellation::set_generators_unchecked`] to skip this check for trusted input.
+    ///
     /// # Arguments
     /// * `generators` - A flat array of coordinates `[x, y, z, ..., x, y, z, ...]`.
-    pub fn set_generators(&mut self, generators: &[f64]) {
+    pub fn set_generators(&mut self, generators: &[f64]) -> Result<(), GeneratorError> {
+        Self::check_finite(generators)?;
+
+        self.set_generators_unchecked(generators);
+        Ok(())
+    }
+
+    /// Update all generators at once without checking for non-finite coordinates.
+    /// Only accepts generators that are contained by the walls, subject to the [`BoundaryPolicy`].
+    /// Any ghost generators are removed.
+    ///
+    /// Non-finite coordinates corrupt the spatial binning and produce invalid cells,
+    /// so this should only be used for trusted input in performance-critical code.
+    pub fn set_generators_unchecked(&mut self, generators: &[f64]) {
+        self.set_generators_filtered(generators);
+        self.clear_generator_ids();
+        self.clear_sites();
+    }
+
+    /// Sets the generators that pass the walls and the [`BoundaryPolicy`], removing any ghosts,
+    /// and returns the indices of the accepted generators in the input.
+    pub(crate) fn set_generators_filtered(&mut self, generators: &[f64]) -> Vec<usize> {
         let mut valid_generators = Vec::with_capacity(generators.len());
+        let mut accepted = Vec::with_capacity(generators.len() / D);
         let count = generators.len() / D;
 
         for i in 0..count {
             let offset = i * D;
             let point_slice = &generators[offset..offset + D];
-            if let Ok(point) = point_slice.try_into() {
-                let mut inside = true;
-                for wall in &self.walls {
-                    if !wall.contains(point) {
-                        inside = false;
-                        break;
-                    }
-                }
-                if inside {
-                    valid_generators.extend_from_slice(point_slice);
-                }
+            if let Ok(point) = point_slice.try_into()
+                && let Some(p) = self.apply_boundary_policy(point)
+            {
+                valid_generators.extend_from_slice(&p);
+                accepted.push(i);
             }
         }
 
         valid_generators.shrink_to_fit();
         self.generators = valid_generators;
-        self.algorithm.set_generators(&self.generators, &self.bounds);
+        self.ghost_count = 0;
+        self.bin_generators();
+        accepted
     }
 
     /// Update the position of a single generator by index. Only sets the generator
-    /// if it is inside the bounding box and contained by the walls.
+    /// if it has finite coordinates, is inside the bounding box and contained by the walls.
     pub fn set_generator(&mut self, index: usize, generator: &[f64; D]) {
         let offset = index * D;
-        if offset + D > self.generators.len() {
+        if offset + D > self.generators.len() || generator.iter().any(|v| !v.is_finite()) {
             return;
         }
 
@@ -80,8 +394,7 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
             }
         }
 
-        let old_slice = &self.generators[offset..offset + D];
-        let old_pos: [f64; D] = old_slice.try_into().unwrap();
+        let old_pos: [f64; D] = point_at(&self.generators, index);
 
         self.algorithm
             .update_generator(index, &old_pos, generator, &self.bounds);
@@ -91,10 +404,155 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         }
     }
 
+    /// Updates the positions of the generators at `indices` in one call, for moving a few thousand
+    /// generators per frame without the overhead of a call to [`Tessellation::set_generator`] each.
+    /// Returns the number of generators that were moved.
+    ///
+    /// Like [`Tessellation::set_generator`], updates with an index out of range or a position
+    /// outside the walls are skipped, and a later update of the same index wins. The walls are
+    /// checked in parallel, and the spatial algorithm is rebuilt once if many generators move.
+    ///
+    /// Returns a [`GeneratorError::CountMismatch`] if `positions` does not hold a position for each
+    /// index, or a [`GeneratorError::NonFinite`] with the offending entries, in which case no
+    /// generator is moved.
+    pub fn set_generators_sparse(&mut self, indices: &[usize], positions: &[f64]) -> Result<usize, GeneratorError> {
+        if positions.len() != indices.len() * D {
+            return Err(GeneratorError::CountMismatch(indices.len(), positions.len() / D));
+        }
+        Self::check_finite(positions)?;
+
+        let count = self.count_generators();
+        let updates: Vec<(usize, [f64; D])> = indices
+            .par_iter()
+            .enumerate()
+            .filter(|&(_, &index)| index < count)
+            .map(|(k, &index)| (index, point_at(positions, k)))
+            .filter(|(_, p)| self.walls.iter().all(|w| w.contains(p)))
+            .collect();
+
+        if updates.len() as f64 > SPARSE_REBIN_FRACTION * count as f64 {
+            for (index, p) in &updates {
+                self.generators[index * D..(index + 1) * D].copy_from_slice(p);
+            }
+            self.bin_generators();
+        } else {
+            for (index, p) in &updates {
+                let old_pos: [f64; D] = point_at(&self.generators, *index);
+                self.algorithm.update_generator(*index, &old_pos, p, &self.bounds);
+                self.generators[index * D..(index + 1) * D].copy_from_slice(p);
+            }
+        }
+        Ok(updates.len())
+    }
+
+    /// Moves each generator towards its target in `new_positions` by at most `max_step`,
+    /// for stable animation loops where targets may jump far between frames.
+    ///
+    /// A step that would leave the bounding box or the walls is halved until it stays inside,
+    /// or skipped. Ghost generators are not moved. Returns the number of generators that were
+    /// held back from their target, so a loop has converged once it returns zero.
+    ///
+    /// Returns a [`GeneratorError::CountMismatch`] if `new_positions` does not hold a position for
+    /// each owned generator, or a [`GeneratorError::NonFinite`] with the offending indices,
+    /// in which case no generator is moved.
+    pub fn limit_displacement(&mut self, new_positions: &[f64], max_step: f64) -> Result<usize, GeneratorError> {
+        let count = self.count_owned();
+        if new_positions.len() != count * D {
+            return Err(GeneratorError::CountMismatch(count, new_positions.len() / D));
+        }
+        Self::check_finite(new_positions)?;
+
+        let mut limited = 0;
+        for i in 0..count {
+            let current: [f64; D] = point_at(&self.generators, i);
+            let target: [f64; D] = point_at(new_positions, i);
+            let distance = (0..D).map(|k| (target[k] - current[k]).powi(2)).sum::<f64>().sqrt();
+            if distance == 0.0 {
+                continue;
+            }
+            let mut fraction = (max_step.max(0.0) / distance).min(1.0);
+            if fraction < 1.0 {
+                limited += 1;
+            }
+
+            // Halve the step until it stays inside the bounding box and walls.
+            for _ in 0..MAX_STEP_HALVINGS {
+                let point: [f64; D] = std::array::from_fn(|k| current[k] + fraction * (target[k] - current[k]));
+                let inside = (0..D).all(|k| point[k] >= self.bounds.min[k] && point[k] <= self.bounds.max[k])
+                    && self.walls.iter().all(|w| w.contains(&point));
+                if inside {
+                    self.set_generator(i, &point);
+                    break;
+                }
+                if fraction == 1.0 {
+                    limited += 1;
+                }
+                fraction *= 0.5;
+            }
+        }
+        Ok(limited)
+    }
+
+    /// Moves the generators at `indices` to the closest point on the wall or bounding box side
+    /// `wall_id`, at the distance `offset` inside, for building layers of cells that conform to
+    /// the boundary. Returns the number of generators moved.
+    ///
+    /// The closest point is found on the plane of the wall cut nearest to the generator, which is
+    /// exact for planar walls and for curved walls whose cut is the tangent plane at the closest
+    /// point, such as spheres and cylinders. Indices of ghosts or missing generators, and
+    /// projections outside the bounding box or any wall, are skipped. A positive offset keeps the
+    /// generators clear of the surface.
+    pub fn project_generators_to_wall(&mut self, indices: &[usize], wall_id: i32, offset: f64) -> usize {
+        let mut moved = 0;
+        for &i in indices {
+            if i >= self.count_owned() {
+                continue;
+            }
+            let g: [f64; D] = point_at(&self.generators, i);
+            // The nearest plane as the distance to it and its outward unit normal.
+            let mut nearest: Option<(f64, [f64; D])> = None;
+            let mut consider = |point: [f64; D], normal: [f64; D]| {
+                let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+                if length > 0.0 {
+                    let normal = normal.map(|v| v / length);
+                    let d = (0..D).map(|k| normal[k] * (point[k] - g[k])).sum::<f64>();
+                    if nearest.is_none_or(|(m, _)| d.abs() < m.abs()) {
+                        nearest = Some((d, normal));
+                    }
+                }
+            };
+            if let Some(wall) = self.walls.iter().find(|w| w.id() == wall_id) {
+                wall.cut(&g, &mut consider);
+            } else if let Some(k) = (0..D).find(|&k| box_side(k, false) == wall_id || box_side(k, true) == wall_id) {
+                let is_max = box_side(k, true) == wall_id;
+                let mut point = g;
+                let mut normal = [0.0; D];
+                point[k] = if is_max { self.bounds.max[k] } else { self.bounds.min[k] };
+                normal[k] = if is_max { 1.0 } else { -1.0 };
+                consider(point, normal);
+            }
+            let Some((d, normal)) = nearest else { continue };
+
+            let target: [f64; D] = std::array::from_fn(|k| g[k] + normal[k] * (d - offset));
+            let inside = (0..D).all(|k| target[k] >= self.bounds.min[k] && target[k] <= self.bounds.max[k]);
+            if inside && self.walls.iter().all(|w| w.contains(&target)) {
+                self.set_generator(i, &target);
+                moved += 1;
+            }
+        }
+        moved
+    }
+
     /// Generates random points within the boundaries of the bounding box
     /// and walls and sets them as generators.
     pub fn random_generators(&mut self, count: usize) {
-        let mut rng = StdRng::seed_from_u64(get_seed());
+        self.random_generators_seeded(count, get_seed());
+    }
+
+    /// Generates random points like [`Tessellation::random_generators`], but from a fixed `seed`,
+    /// so the generators are reproducible across runs and platforms.
+    pub fn random_generators_seeded(&mut self, count: usize, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
         let mut points = Vec::with_capacity(count * D);
         
         let mut found = 0;
@@ -104,10 +562,10 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         while found < count && attempts < max_attempts {
             attempts += 1;
             let mut point = [0.0; D];
-            for i in 0..D {
+            for (i, value) in point.iter_mut().enumerate() {
                 let min = self.bounds.min[i];
                 let max = self.bounds.max[i];
-                point[i] = min + rng.r#gen::<f64>() * (max - min);
+                *value = min + rng.r#gen::<f64>() * (max - min);
             }
 
             if self.walls.iter().all(|w| w.contains(&point)) {
@@ -117,7 +575,100 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         }
         
         self.generators = points;
-        self.algorithm.set_generators(&self.generators, &self.bounds);
+        self.ghost_count = 0;
+        self.clear_generator_ids();
+        self.clear_sites();
+        self.bin_generators();
+    }
+
+    /// Generates random points with a probability proportional to the scalar field `density`
+    /// within the bounding box and walls, and sets them as generators, so the distribution
+    /// already approximates the target density of a centroidal tessellation.
+    pub fn random_generators_density<F: Fn(&[f64; D]) -> f64>(&mut self, count: usize, density: F) {
+        self.random_generators_density_seeded(count, get_seed(), density);
+    }
+
+    /// Generates random points like [`Tessellation::random_generators_density`], but from a fixed
+    /// `seed`, so the generators are reproducible across runs and platforms.
+    ///
+    /// Points are drawn by rejection sampling against the largest density found in a pilot sample,
+    /// which is raised whenever a larger density is drawn. Narrow peaks missed by the pilot sample
+    /// are therefore slightly undersampled. Negative and non-finite densities count as zero.
+    pub fn random_generators_density_seeded<F: Fn(&[f64; D]) -> f64>(&mut self, count: usize, seed: u64, density: F) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let sample = |rng: &mut StdRng| -> [f64; D] {
+            std::array::from_fn(|i| self.bounds.min[i] + rng.r#gen::<f64>() * (self.bounds.max[i] - self.bounds.min[i]))
+        };
+        let value = |point: &[f64; D]| {
+            let v = density(point);
+            if v.is_finite() { v.max(0.0) } else { 0.0 }
+        };
+
+        let mut max_density = (0..DENSITY_PILOT_SAMPLES)
+            .map(|_| value(&sample(&mut rng)))
+            .fold(0.0, f64::max);
+        let mut points = Vec::with_capacity(count * D);
+        let mut found = 0;
+        let max_attempts = count * 1000;
+        let mut attempts = 0;
+
+        while found < count && attempts < max_attempts {
+            attempts += 1;
+            let point = sample(&mut rng);
+            if !self.walls.iter().all(|w| w.contains(&point)) {
+                continue;
+            }
+            let v = value(&point);
+            max_density = max_density.max(v);
+            if v > 0.0 && rng.r#gen::<f64>() * max_density < v {
+                points.extend_from_slice(&point);
+                found += 1;
+            }
+        }
+
+        self.generators = points;
+        self.ghost_count = 0;
+        self.clear_generator_ids();
+        self.clear_sites();
+        self.bin_generators();
+    }
+
+    /// Places one generator in each sub-cell of a regular grid with `divisions` cells per axis over
+    /// the bounding box, moved from the sub-cell center by up to `jitter` times half its size.
+    ///
+    /// This low-discrepancy initialization needs far fewer relaxation steps than uniformly
+    /// random points. A `jitter` of 0 gives a regular grid and 1 a random point per sub-cell.
+    /// Points outside the walls are skipped.
+    pub fn stratified_generators(&mut self, divisions: [usize; D], jitter: f64) {
+        self.stratified_generators_seeded(divisions, jitter, get_seed());
+    }
+
+    /// Places generators like [`Tessellation::stratified_generators`], but from a fixed `seed`,
+    /// so the generators are reproducible across runs and platforms.
+    pub fn stratified_generators_seeded(&mut self, divisions: [usize; D], jitter: f64, seed: u64) {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let jitter = jitter.clamp(0.0, 1.0);
+        let size: [f64; D] = std::array::from_fn(|k| (self.bounds.max[k] - self.bounds.min[k]) / divisions[k].max(1) as f64);
+        let total: usize = divisions.iter().product();
+        let mut points = Vec::with_capacity(total * D);
+
+        for mut index in 0..total {
+            let point: [f64; D] = std::array::from_fn(|k| {
+                let cell = index % divisions[k];
+                index /= divisions[k];
+                let offset = 0.5 + jitter * (rng.r#gen::<f64>() - 0.5);
+                self.bounds.min[k] + (cell as f64 + offset) * size[k]
+            });
+            if self.walls.iter().all(|w| w.contains(&point)) {
+                points.extend_from_slice(&point);
+            }
+        }
+
+        self.generators = points;
+        self.ghost_count = 0;
+        self.clear_generator_ids();
+        self.clear_sites();
+        self.bin_generators();
     }
 
     /// Imports generators from a text file.
@@ -143,14 +694,14 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
             }
         }
 
-        self.set_generators(&raw_points);
-        Ok(())
+        self.set_generators(&raw_points)
+            .map_err(|e| std::io::Error::new(std::io::ErrorKind::InvalidData, e))
     }
 
     /// Reads generators from a string.
     /// Each line should contain an id followed by D coordinate entries.
     /// The id is ignored.
-    pub fn read_generators(&mut self, input: &str) {
+    pub fn read_generators(&mut self, input: &str) -> Result<(), GeneratorError> {
         let mut raw_points = Vec::new();
 
         for line in input.lines() {
@@ -167,29 +718,63 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
             }
         }
 
-        self.set_generators(&raw_points);
+        self.set_generators(&raw_points)
+    }
+
+    /// Reads generators from a binary buffer of little-endian `f64` coordinates,
+    /// as written by [`Tessellation::generators_bytes`].
+    pub fn read_generators_bytes(&mut self, bytes: &[u8]) -> Result<(), GeneratorError> {
+        if !bytes.len().is_multiple_of(8 * D) {
+            return Err(GeneratorError::InvalidLength(bytes.len()));
+        }
+        let raw_points: Vec<f64> = bytes
+            .chunks_exact(8)
+            .map(|b| f64::from_le_bytes(std::array::from_fn(|k| b[k])))
+            .collect();
+        self.set_generators(&raw_points)
+    }
+
+    /// Returns the generators as a binary buffer of little-endian `f64` coordinates.
+    pub fn generators_bytes(&self) -> Vec<u8> {
+        self.generators.iter().flat_map(|v| v.to_le_bytes()).collect()
     }
 
-    /// Removes generators that are not inside the defined walls.
+    /// Removes generators that are not inside the defined walls, and applies the [`BoundaryPolicy`].
     /// Note: This changes the indices of the remaining generators.
     fn prune_outside_generators(&mut self) {
         let mut new_generators = Vec::with_capacity(self.generators.len());
         let count = self.generators.len() / D;
+        let owned = count - self.ghost_count;
+        let mut ghost_count = 0;
+        let mut accepted = Vec::with_capacity(count);
         
         for i in 0..count {
             let offset = i * D;
             let point_slice = &self.generators[offset..offset + D];
-            if let Ok(point) = point_slice.try_into() {
-                if self.walls.iter().all(|w| w.contains(point)) {
-                    new_generators.extend_from_slice(point_slice);
+            if let Ok(point) = point_slice.try_into()
+                && let Some(p) = self.apply_boundary_policy(point)
+            {
+                new_generators.extend_from_slice(&p);
+                accepted.push(i);
+                if i >= owned {
+                    ghost_count += 1;
                 }
             }
         }
         
-        if new_generators.len() != self.generators.len() {
+        if new_generators != self.generators {
+            // The external IDs follow the remaining generators.
+            if self.has_generator_ids() {
+                let ids: Vec<u64> = accepted.iter().map(|&i| self.generator_ids[i]).collect();
+                self.register_generator_ids(ids);
+            }
+            if self.has_sites() {
+                self.retain_sites(&accepted, None);
+            }
             new_generators.shrink_to_fit();
             self.generators = new_generators;
-            self.algorithm.set_generators(&self.generators, &self.bounds);
+            self.ghost_count = ghost_count;
+            self.bin_generators();
         }
     }
 
@@ -210,23 +795,213 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
     /// and clips the cells against the generators, the bounding box and any added walls.
     /// For the clipping it applies the algoritm as defined in the Cell implementation.
     /// It runs in parallel if the `rayon` feature is enabled (which is default).
+    /// Only the owned generators get a cell, ghost generators merely clip them.
+    ///
+    /// Each cell only depends on the generators, bounds and walls, and is stored at the index
+    /// of its generator, so the output is bit-identical regardless of thread count and scheduling.
     pub fn calculate(&mut self) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("calculate", generators = self.generators.len() / D).entered();
+        let tally = Tally::default();
+
         self.seal_log.clear();
         self.prune_log.clear();
         self.prune_pos_log.clear();
-        let count = self.generators.len() / D;
+        self.approximate.clear();
+        let count = self.count_owned();
         let generators = &self.generators;
-        let bounds = &self.bounds;
+        let bounds = &self.clip_bounds();
         let walls = &self.walls;
         let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
 
         self.cells = (0..count)
             .into_par_iter()
             .map_init(
-                || C::Scratch::default(),
-                |scratch, i| Self::compute_cell(i, generators, bounds, walls, algorithm, scratch),
+                C::Scratch::default,
+                |scratch, i| {
+                    tally.record(|| Self::compute_cell(i, generators, bounds, walls, algorithm, search, &[], scratch))
+                },
             )
             .collect();
+
+        #[cfg(feature = "tracing")]
+        {
+            self.counters = tally.finish();
+        }
+    }
+
+    /// Returns the counters of the last calculation of the cells by [`Tessellation::calculate`],
+    /// [`Tessellation::calculate_warm`], [`Tessellation::preview_calculate`] or
+    /// [`Tessellation::calculate_streaming`], or zero before the first one.
+    /// Only available with the `tracing` feature.
+    #[cfg(feature = "tracing")]
+    pub fn counters(&self) -> CalculationCounters {
+        self.counters
+    }
+
+    /// Calculates all cells like [`Tessellation::calculate`], warm-started from the previous cells
+    /// for generators that moved only slightly since the last calculation, as between the frames
+    /// of a simulation. Each cell is first clipped by the generators that were its neighbors
+    /// before, which shrinks it to nearly its final size, so the neighbor search that validates
+    /// the cell against the security radius only visits the few candidates close to it.
+    ///
+    /// The cells are the same as those of [`Tessellation::calculate`], up to rounding from the
+    /// order of the clips. If the number of owned generators changed since the last calculation,
+    /// the cells are calculated from scratch.
+    pub fn calculate_warm(&mut self) {
+        let count = self.count_owned();
+        if self.cells.len() != count {
+            self.calculate();
+            return;
+        }
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("calculate_warm", generators = self.generators.len() / D).entered();
+        let tally = Tally::default();
+
+        self.seal_log.clear();
+        self.prune_log.clear();
+        self.prune_pos_log.clear();
+        self.approximate.clear();
+        let previous = std::mem::take(&mut self.cells);
+        let generators = &self.generators;
+        let bounds = &self.clip_bounds();
+        let walls = &self.walls;
+        let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
+
+        self.cells = previous
+            .par_iter()
+            .enumerate()
+            .map_init(
+                C::Scratch::default,
+                |scratch, (i, cell)| {
+                    tally.record(|| {
+                        Self::compute_cell(i, generators, bounds, walls, algorithm, search, cell.neighbors(), scratch)
+                    })
+                },
+            )
+            .collect();
+
+        #[cfg(feature = "tracing")]
+        {
+            self.counters = tally.finish();
+        }
+    }
+
+    /// Calculates approximate cells quickly, for previews while scrubbing through interactive edits.
+    /// Each cell is clipped by at most `max_neighbors_per_cell` candidates of the neighbor search,
+    /// which the spatial algorithms visit roughly nearest first, so the nearest neighbors shape
+    /// the cell while distant ones that might still cut off a corner are skipped.
+    ///
+    /// A cell is exact if its search ends within the budget, which takes all candidates within
+    /// the security radius and so typically two to three times the number of faces of the cell.
+    /// [`Tessellation::is_cell_exact`] reports which cells are exact. Approximate cells may
+    /// overlap their neighbors and are larger than the exact cells, so their total volume exceeds
+    /// that of the domain. Any later exact calculation replaces the flags.
+    pub fn preview_calculate(&mut self, max_neighbors_per_cell: usize) {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("preview_calculate", generators = self.generators.len() / D).entered();
+        let tally = Tally::default();
+
+        self.seal_log.clear();
+        self.prune_log.clear();
+        self.prune_pos_log.clear();
+        let count = self.count_owned();
+        let generators = &self.generators;
+        let bounds = &self.clip_bounds();
+        let walls = &self.walls;
+        let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
+
+        let (cells, approximate): (Vec<C>, Vec<bool>) = (0..count)
+            .into_par_iter()
+            .map_init(
+                C::Scratch::default,
+                |scratch, i| {
+                    let (cell, exact) = tally.record(|| {
+                        Self::compute_cell_with(
+                            i, generators, bounds, walls, algorithm, search, &[], max_neighbors_per_cell, scratch, None,
+                        )
+                    });
+                    (cell, !exact)
+                },
+            )
+            .unzip();
+        self.cells = cells;
+        self.approximate = if approximate.contains(&true) { approximate } else { Vec::new() };
+
+        #[cfg(feature = "tracing")]
+        {
+            self.counters = tally.finish();
+        }
+    }
+
+    /// Returns whether the cell at `index` is exact, which is always the case unless it was
+    /// calculated by [`Tessellation::preview_calculate`] and its neighbor search ran out of budget.
+    pub fn is_cell_exact(&self, index: usize) -> bool {
+        !self.approximate.get(index).copied().unwrap_or(false)
+    }
+
+    /// Returns the indices of the approximate cells of [`Tessellation::preview_calculate`].
+    pub fn approximate_cells(&self) -> Vec<usize> {
+        self.approximate.iter().enumerate().filter(|(_, a)| **a).map(|(i, _)| i).collect()
+    }
+
+    /// Recalculates only the cells whose generators lie in `region`, or whose previous cells
+    /// intersect it, for interactive tools that edit the generators in a small part of a large
+    /// tessellation. Returns the indices of the recalculated cells in increasing order.
+    ///
+    /// The cells outside the region are kept as they are, so the region must enclose every
+    /// generator that moved along with the cells they touch before and after the edit.
+    /// If the number of owned generators changed since the last calculation, all cells are
+    /// recalculated. The logs of [`Tessellation::calculate_sealed`] and
+    /// [`Tessellation::prune_boundaries`] are left untouched.
+    pub fn recalculate_region(&mut self, region: &BoundingBox<D>) -> Vec<usize> {
+        let count = self.count_owned();
+        if self.cells.len() != count {
+            self.calculate();
+            return (0..count).collect();
+        }
+
+        let inside = |p: &[f64]| (0..D).all(|k| p[k] >= region.min[k] && p[k] <= region.max[k]);
+        let indices: Vec<usize> = (0..count)
+            .into_par_iter()
+            .filter(|&i| {
+                if inside(&self.generators[i * D..(i + 1) * D]) {
+                    return true;
+                }
+                let vertices = self.cells[i].vertices();
+                if vertices.is_empty() {
+                    return false;
+                }
+                (0..D).all(|k| {
+                    let coords = vertices.chunks_exact(D).map(|v| v[k]);
+                    let (lo, hi) = coords.fold((f64::INFINITY, f64::NEG_INFINITY), |(lo, hi), v| (lo.min(v), hi.max(v)));
+                    lo <= region.max[k] && hi >= region.min[k]
+                })
+            })
+            .collect();
+
+        let generators = &self.generators;
+        let bounds = &self.clip_bounds();
+        let walls = &self.walls;
+        let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
+        let cells: Vec<C> = indices
+            .par_iter()
+            .map_init(
+                C::Scratch::default,
+                |scratch, &i| Self::compute_cell(i, generators, bounds, walls, algorithm, search, &[], scratch),
+            )
+            .collect();
+        for (&i, cell) in indices.iter().zip(cells) {
+            self.cells[i] = cell;
+            if let Some(approximate) = self.approximate.get_mut(i) {
+                *approximate = false;
+            }
+        }
+        indices
     }
 
     /// Calculates cells, and then runs a post-processing pass to share
@@ -234,31 +1009,14 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
     pub fn calculate_sealed(&mut self) {
         self.calculate();
 
-        let count = self.generators.len() / D;
-        let generators = &self.generators;
-        let walls = &self.walls;
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("seal").entered();
 
         // 2. Extract neighbor topologies
         // We need to know who neighbors who before we start mutating cells.
-        let mut topologies: Vec<Vec<usize>> = Vec::with_capacity(count);
-        let mut cell_walls: Vec<Vec<i32>> = Vec::with_capacity(count);
-        for cell in &self.cells {
-            let mut neighbors = Vec::new();
-            let mut non_planar_walls = Vec::new();
-            for &face_neighbor in cell.neighbors() {
-                if face_neighbor >= 0 && (face_neighbor as usize) < count {
-                    neighbors.push(face_neighbor as usize);
-                } else {
-                    if let Some(wall) = walls.iter().find(|w| w.id() == face_neighbor) {
-                        if !wall.is_planar() && !non_planar_walls.contains(&face_neighbor) {
-                            non_planar_walls.push(face_neighbor);
-                        }
-                    }
-                }
-            }
-            topologies.push(neighbors);
-            cell_walls.push(non_planar_walls);
-        }
+        let (topologies, cell_walls) = self.curved_wall_topology();
+        let generators = &self.generators;
+        let walls = &self.walls;
 
         // 3. Post-Op Planar Consensus (Pass 2)
         // Note: Using `par_iter_mut` so we can clip cells in parallel!
@@ -269,17 +1027,14 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
             }
 
             let mut scratch = C::Scratch::default();
-            let offset_i = i * D;
-            let g_pos: [f64; D] = generators[offset_i..offset_i + D].try_into().unwrap();
+            let g_pos: [f64; D] = point_at(generators, i);
             let mut local_log = Vec::new();
             
             // For every neighbor this cell has...
             for &neighbor_idx in &topologies[i] {
                 let neighbor_walls = &cell_walls[neighbor_idx];
 
-                let offset_n = neighbor_idx * D;
-                let n_slice = &generators[offset_n..offset_n + D];
-                let n_pos: [f64; D] = n_slice.try_into().unwrap();
+                let n_pos: [f64; D] = point_at(generators, neighbor_idx);
 
                 // Check if the neighbor generated any tangent planes from curved walls
                 for wall in walls {
@@ -321,31 +1076,9 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
     /// It queries additional tangent planes from curved walls by interpolating
     /// between the cell's generator and its neighbors' generators, creating a beveled, smoother surface.
     pub fn prune_boundaries(&mut self) {
-        let count = self.generators.len() / D;
+        let (topologies, cell_walls) = self.curved_wall_topology();
         let walls = &self.walls;
 
-        let mut topologies: Vec<Vec<usize>> = Vec::with_capacity(count);
-        let mut cell_walls: Vec<Vec<i32>> = Vec::with_capacity(count);
-        for cell in &self.cells {
-            let mut neighbors = Vec::new();
-            let mut non_planar_walls = Vec::new();
-            for &face_neighbor in cell.neighbors() {
-                if face_neighbor >= 0 && (face_neighbor as usize) < count {
-                    if !neighbors.contains(&(face_neighbor as usize)) {
-                        neighbors.push(face_neighbor as usize);
-                    }
-                } else {
-                    if let Some(wall) = walls.iter().find(|w| w.id() == face_neighbor) {
-                        if !wall.is_planar() && !non_planar_walls.contains(&face_neighbor) {
-                            non_planar_walls.push(face_neighbor);
-                        }
-                    }
-                }
-            }
-            topologies.push(neighbors);
-            cell_walls.push(non_planar_walls);
-        }
-
         let logs: Vec<(Vec<i32>, Vec<f64>)> = self.cells.par_iter().enumerate().filter_map(|(i, cell)| {
             let my_walls = &cell_walls[i];
             if my_walls.is_empty() {
@@ -384,6 +1117,81 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         self.prune_pos_log = prune_pos_logs.into_iter().flatten().collect();
     }
 
+    /// Collects for each cell its distinct neighboring cells and the curved walls it touches,
+    /// which drive the sealing and pruning passes along curved walls.
+    fn curved_wall_topology(&self) -> (Vec<Vec<usize>>, Vec<Vec<i32>>) {
+        let count = self.cells.len();
+        let mut topologies: Vec<Vec<usize>> = Vec::with_capacity(count);
+        let mut cell_walls: Vec<Vec<i32>> = Vec::with_capacity(count);
+        for cell in &self.cells {
+            let mut neighbors = Vec::new();
+            let mut non_planar_walls = Vec::new();
+            for &face_neighbor in cell.neighbors() {
+                if face_neighbor >= 0 && (face_neighbor as usize) < count {
+                    if !neighbors.contains(&(face_neighbor as usize)) {
+                        neighbors.push(face_neighbor as usize);
+                    }
+                } else if let Some(wall) = self.walls.iter().find(|w| w.id() == face_neighbor)
+                    && !wall.is_planar()
+                    && !non_planar_walls.contains(&face_neighbor)
+                {
+                    non_planar_walls.push(face_neighbor);
+                }
+            }
+            topologies.push(neighbors);
+            cell_walls.push(non_planar_walls);
+        }
+        (topologies, cell_walls)
+    }
+
+    /// Calculates all cells like [`Tessellation::calculate`], and invokes `callback` with the index
+    /// and the cell as soon as each cell is finished, so exports or uploads of huge tessellations
+    /// can run alongside the calculation. With `store` disabled the cells are dropped after the
+    /// callback and the tessellation is left without cells, which bounds the memory use.
+    ///
+    /// The callback is invoked exactly once for every owned generator, from the worker threads
+    /// and possibly concurrently, in no particular order. Each cell is final when it is passed,
+    /// and all invocations return before this method does.
+    pub fn calculate_streaming<F>(&mut self, store: bool, callback: F)
+    where
+        F: Fn(usize, &C) + Sync + Send,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("calculate_streaming", generators = self.generators.len() / D).entered();
+        let tally = Tally::default();
+
+        self.seal_log.clear();
+        self.prune_log.clear();
+        self.prune_pos_log.clear();
+        self.approximate.clear();
+        self.cells.clear();
+        let count = self.count_owned();
+        let generators = &self.generators;
+        let bounds = &self.clip_bounds();
+        let walls = &self.walls;
+        let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
+        let compute = |scratch: &mut C::Scratch, i: usize| {
+            let cell =
+                tally.record(|| Self::compute_cell(i, generators, bounds, walls, algorithm, search, &[], scratch));
+            callback(i, &cell);
+            cell
+        };
+
+        if store {
+            self.cells = (0..count).into_par_iter().map_init(C::Scratch::default, compute).collect();
+        } else {
+            (0..count).into_par_iter().for_each_init(C::Scratch::default, |scratch, i| {
+                compute(scratch, i);
+            });
+        }
+
+        #[cfg(feature = "tracing")]
+        {
+            self.counters = tally.finish();
+        }
+    }
+
     /// Computes cells and applies a mapping function `f` to each cell, returning the collected results.
     ///
     /// This method is memory-efficient as it does not store the intermediate `Cell` objects.
@@ -393,85 +1201,178 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         F: Fn(C) -> T + Sync + Send,
         T: Send,
     {
-        let count = self.generators.len() / D;
+        let count = self.count_owned();
         let generators = &self.generators;
-        let bounds = &self.bounds;
+        let bounds = &self.clip_bounds();
         let walls = &self.walls;
         let algorithm = &self.algorithm;
+        let search = self.neighbor_search();
 
         (0..count)
             .into_par_iter()
             .map_init(
-                || C::Scratch::default(),
+                C::Scratch::default,
                 |scratch, i| {
-                    let cell = Self::compute_cell(i, generators, bounds, walls, algorithm, scratch);
+                    let cell = Self::compute_cell(i, generators, bounds, walls, algorithm, search, &[], scratch);
                     f(cell)
                 },
             )
             .collect()
     }
 
+    #[allow(clippy::too_many_arguments)]
     fn compute_cell(
         i: usize,
         generators: &[f64],
         bounds: &BoundingBox<D>,
         walls: &[Wall<D>],
         algorithm: &A,
+        search: NeighborSearch,
+        hints: &[i32],
         scratch: &mut C::Scratch,
     ) -> C {
-        let offset = i * D;
-        let g_slice = &generators[offset..offset + D];
-        let g_pos: [f64; D] = g_slice.try_into().unwrap();
+        Self::compute_cell_with(i, generators, bounds, walls, algorithm, search, hints, usize::MAX, scratch, None).0
+    }
+
+    /// Computes a cell like [`Tessellation::compute_cell`], but stops the neighbor search once
+    /// another candidate that might cut the cell arrives after `budget` of them were clipped, and
+    /// records the clipped neighbors in `order` if given.
+    /// Returns the cell and whether it is exact, so whether the search ended within the budget.
+    #[allow(clippy::too_many_arguments)]
+    fn compute_cell_with(
+        i: usize,
+        generators: &[f64],
+        bounds: &BoundingBox<D>,
+        walls: &[Wall<D>],
+        algorithm: &A,
+        search: NeighborSearch,
+        hints: &[i32],
+        budget: usize,
+        scratch: &mut C::Scratch,
+        mut order: Option<&mut Vec<usize>>,
+    ) -> (C, bool) {
+        let g_pos: [f64; D] = point_at(generators, i);
 
         let mut cell = C::new(i, *bounds);
+        let mut clip_calls = LocalCounter::new(Counter::ClipCalls);
+        let mut candidates = LocalCounter::new(Counter::Candidates);
 
         // 1. Clip against walls
-        for wall in walls {
-            wall.cut(&g_pos, &mut |point, normal| {
-                cell.clip(&point, &normal, wall.id(), scratch, None);
-            });
-            if cell.is_empty() {
-                return cell;
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("wall_cut", cell = i).entered();
+            for wall in walls {
+                wall.cut(&g_pos, &mut |point, normal| {
+                    clip_calls.inc();
+                    cell.clip(&point, &normal, wall.id(), scratch, None);
+                });
+                if cell.is_empty() {
+                    return (cell, true);
+                }
             }
         }
 
-        let mut current_max_dist_sq = cell.max_radius_sq(&g_pos);
-
-        // 2. Clip against neighbors found by the SpatialAlgorithm
-        algorithm.visit_neighbors(
-            generators,
-            i,
-            g_pos,
-            &mut current_max_dist_sq,
-            |j, n_pos, cur_dist| {
-                let mut dist_sq = 0.0;
-                let mut midpoint = [0.0; D];
-                let mut normal = [0.0; D];
+        // The spatial algorithms search up to 4 times the radius they are given,
+        // so the radius is scaled to apply the search factor instead.
+        let search_scale = search.factor / DEFAULT_SEARCH_FACTOR;
+        let mut current_max_dist_sq = cell.max_radius_sq(&g_pos) * search_scale;
+        let bisector = |n_pos: &[f64; D]| -> (f64, [f64; D], [f64; D]) {
+            let mut dist_sq = 0.0;
+            let mut midpoint = [0.0; D];
+            let mut normal = [0.0; D];
+            for k in 0..D {
+                let d = n_pos[k] - g_pos[k];
+                dist_sq += d * d;
+                midpoint[k] = g_pos[k] + d * 0.5;
+                normal[k] = d;
+            }
+            (dist_sq, midpoint, normal)
+        };
 
-                for k in 0..D {
-                    let d = n_pos[k] - g_pos[k];
-                    dist_sq += d * d;
-                    midpoint[k] = g_pos[k] + d * 0.5;
-                    normal[k] = d;
+        // 2. Clip against the hinted neighbors, which the neighbor search then skips
+        let count = generators.len() / D;
+        let hinted = |j: usize| hints.contains(&(j as i32));
+        for &j in hints {
+            if j < 0 || j as usize >= count || j as usize == i {
+                continue;
+            }
+            candidates.inc();
+            let (dist_sq, midpoint, normal) = bisector(&point_at(generators, j as usize));
+            if dist_sq > 4.0 * current_max_dist_sq {
+                continue;
+            }
+            clip_calls.inc();
+            if let (true, new_radius) = cell.clip(&midpoint, &normal, j, scratch, Some(&g_pos)) {
+                if cell.is_empty() {
+                    return (cell, true);
                 }
+                current_max_dist_sq = new_radius * search_scale;
+            }
+        }
+
+        // 3. Clip against neighbors found by the SpatialAlgorithm, within the budget
+        let mut clipped = 0;
+        let mut exact = true;
+        let mut clip = |j: usize, n_pos: &[f64; D], cur_dist: f64| -> f64 {
+            let (dist_sq, midpoint, normal) = bisector(n_pos);
+            if dist_sq > 4.0 * cur_dist {
+                return cur_dist;
+            }
+            if clipped == budget {
+                exact = false;
+                return 0.0;
+            }
+            clipped += 1;
+            if let Some(order) = order.as_deref_mut() {
+                order.push(j);
+            }
 
-                if dist_sq > 4.0 * cur_dist {
-                    return cur_dist;
+            clip_calls.inc();
+            if let (true, new_radius) = cell.clip(&midpoint, &normal, j as i32, scratch, Some(&g_pos)) {
+                if cell.is_empty() {
+                    return 0.0;
                 }
+                return new_radius * search_scale;
+            }
+            cur_dist
+        };
 
-                if let (true, new_radius) =
-                    cell.clip(&midpoint, &normal, j as i32, scratch, Some(&g_pos))
-                {
-                    if cell.is_empty() {
-                        return 0.0;
+        // Candidates held back to be clipped in order of priority, see `SpatialAlgorithm::candidate_buffer`
+        let mut held: Vec<(f64, usize, [f64; D])> = Vec::with_capacity(search.buffer + 1);
+        let take_first = |held: &mut Vec<(f64, usize, [f64; D])>| {
+            let first = (0..held.len()).min_by(|&a, &b| held[a].0.total_cmp(&held[b].0))?;
+            Some(held.swap_remove(first))
+        };
+        {
+            #[cfg(feature = "tracing")]
+            let _span = tracing::trace_span!("neighbor_search", cell = i).entered();
+            algorithm.visit_neighbors(
+                generators,
+                i,
+                g_pos,
+                &mut current_max_dist_sq,
+                |j, n_pos, cur_dist| {
+                    if hinted(j) {
+                        return cur_dist;
                     }
-                    return new_radius;
-                }
-                cur_dist
-            },
-        );
+                    candidates.inc();
+                    if search.buffer == 0 {
+                        return clip(j, &n_pos, cur_dist);
+                    }
+                    held.push((algorithm.candidate_priority(&g_pos, &n_pos), j, n_pos));
+                    if held.len() <= search.buffer {
+                        return cur_dist;
+                    }
+                    take_first(&mut held).map_or(cur_dist, |(_, j, n_pos)| clip(j, &n_pos, cur_dist))
+                },
+            );
+        }
+        while current_max_dist_sq > 0.0 {
+            let Some((_, j, n_pos)) = take_first(&mut held) else { break };
+            current_max_dist_sq = clip(j, &n_pos, current_max_dist_sq);
+        }
 
-        cell
+        (cell, exact)
     }   
     
     /// Performs one step of Lloyd's relaxation.
@@ -479,6 +1380,11 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
     /// This moves each generator to the centroid of its calculated Voronoi cell,
     /// which tends to make the cells more uniform in size and shape. A calculation
     /// step must be invoked separately to get the new Voronoi cells.
+    /// Ghost generators are kept in place.
+    ///
+    /// Centroids outside a wall, as for cells along a concave wall, are projected onto the cut
+    /// planes of the wall, so no generators are lost in constrained domains. Registered
+    /// generator IDs are kept.
     pub fn relax(&mut self) {
         let new_generators: Vec<f64> = self.cells.par_iter()
             .zip(self.generators.par_chunks(D))
@@ -486,15 +1392,88 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
                 if cell.is_empty() {
                     original_pos.to_vec()
                 } else {
-                    cell.centroid().to_vec()
+                    // The centroids of cells along concave walls can lie outside, so they are
+                    // projected back instead of being pruned, or stay put if that fails.
+                    self.project_inside(&cell.centroid())
+                        .map_or_else(|| original_pos.to_vec(), |p| p.to_vec())
+                }
+            })
+            .collect();
+
+        let ids = self.has_generator_ids().then(|| self.generator_ids.clone());
+        let had_sites = self.has_sites();
+        let owned = self.count_owned();
+        let ghosts = self.ghosts().to_vec();
+        let accepted = self.set_generators_filtered(&new_generators);
+        if !ghosts.is_empty() {
+            self.set_ghosts_unchecked(&ghosts);
+        }
+        match ids {
+            Some(ids) => self.register_generator_ids(accepted.iter().map(|&i| ids[i]).chain(ids[owned..].iter().copied()).collect()),
+            None => self.clear_generator_ids(),
+        }
+        if had_sites {
+            self.retain_sites(&accepted, Some(owned));
+        } else {
+            self.clear_sites();
+        }
+    }
+
+    /// Inserts a generator into each cell for which `predicate` returns true, for adaptive
+    /// refinement of oversized or badly shaped cells, and returns the indices of the new generators.
+    ///
+    /// A generator is inserted at the centroid of the cell. If the centroid is too close to the
+    /// generator of the cell, as after relaxation, it is inserted halfway to the furthest vertex
+    /// instead, which splits the cell along its longest extent. Points outside the walls are skipped.
+    /// The new generators are appended after the owned generators, and a calculation step must
+    /// be invoked separately to get the refined cells. The new generators have no external IDs,
+    /// so registered generator IDs are cleared if any generator is inserted, while each new
+    /// generator joins the site of the cell it splits.
+    pub fn refine_where<F: Fn(&C) -> bool + Sync>(&mut self, predicate: F) -> Vec<usize> {
+        let points: Vec<(usize, [f64; D])> = self
+            .cells
+            .par_iter()
+            .enumerate()
+            .filter(|(_, cell)| !cell.is_empty() && predicate(cell))
+            .map(|(i, cell)| {
+                let g_pos: [f64; D] = point_at(&self.generators, i);
+                let centroid = cell.centroid();
+                let dist_sq: f64 = (0..D).map(|k| (centroid[k] - g_pos[k]).powi(2)).sum();
+                if dist_sq > REFINE_MIN_OFFSET * REFINE_MIN_OFFSET * cell.max_radius_sq(&g_pos) {
+                    return (i, centroid);
                 }
+                let furthest = cell
+                    .vertices()
+                    .chunks_exact(D)
+                    .max_by(|a, b| {
+                        let da: f64 = (0..D).map(|k| (a[k] - g_pos[k]).powi(2)).sum();
+                        let db: f64 = (0..D).map(|k| (b[k] - g_pos[k]).powi(2)).sum();
+                        da.total_cmp(&db)
+                    })
+                    .map_or(centroid, |v| std::array::from_fn(|k| v[k]));
+                (i, std::array::from_fn(|k| 0.5 * (g_pos[k] + furthest[k])))
             })
+            .filter(|(_, p)| self.walls.iter().all(|w| w.contains(p)))
             .collect();
 
-        self.set_generators(&new_generators);
+        let ghosts = self.ghosts().to_vec();
+        let first = self.count_owned();
+        if self.has_sites() {
+            let sites: Vec<usize> = points.iter().map(|&(i, _)| self.site_of(i)).collect();
+            let ghost_sites = self.sites.split_off(first);
+            self.sites.extend(sites.into_iter().chain(ghost_sites));
+        }
+        self.generators.truncate(first * D);
+        self.generators.extend(points.iter().flat_map(|(_, p)| p));
+        self.generators.extend_from_slice(&ghosts);
+        if !points.is_empty() {
+            self.clear_generator_ids();
+        }
+        self.bin_generators();
+        (first..first + points.len()).collect()
     }
 
-    /// Returns the number of generators in the tessellation.
+    /// Returns the number of generators in the tessellation, including ghost generators.
     pub fn count_generators(&self) -> usize {
         self.generators.len() / D
     }
@@ -504,10 +1483,114 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
         self.cells.len()
     }
 
+    /// Returns the sum of the volumes of all computed cells.
+    pub fn total_volume(&self) -> f64 {
+        self.sum_cells(|cell| cell.volume())
+    }
+
+    /// Returns the volume of the domain, the bounding box extended by the margins of its open
+    /// sides and intersected with all walls, as a reference for [`Tessellation::total_volume`],
+    /// which matches it when every generator contributes a cell and curved walls are resolved finely.
+    ///
+    /// The volume is exact without walls, with only planar walls, which clip a box like a cell,
+    /// and with a single wall whose [`WallGeometry::volume_in`](crate::WallGeometry::volume_in) is
+    /// known. Otherwise it is estimated from the fraction of a low-discrepancy point set in the
+    /// box that all walls contain, which is deterministic and typically accurate to about `1e-5`
+    /// of the box volume for smooth walls.
+    pub fn domain_volume(&self) -> f64 {
+        if let Some(volume) = self.planar_domain_volume() {
+            return volume;
+        }
+        let bounds = self.clip_bounds();
+        if let [wall] = self.walls.as_slice() && let Some(volume) = wall.volume_in(&bounds) {
+            return volume;
+        }
+
+        // The additive recurrence of the generalized golden ratio fills the box more evenly
+        // than random samples, so the estimate converges almost linearly in their number.
+        let mut phi = 2.0f64;
+        for _ in 0..32 {
+            phi = (1.0 + phi).powf(1.0 / (D as f64 + 1.0));
+        }
+        let alpha: [f64; D] = std::array::from_fn(|k| phi.powi(-(k as i32 + 1)));
+        let inside = (0..DOMAIN_VOLUME_SAMPLES)
+            .into_par_iter()
+            .filter(|&n| {
+                let point: [f64; D] = std::array::from_fn(|k| {
+                    let t = (0.5 + alpha[k] * n as f64).fract();
+                    bounds.min[k] + t * (bounds.max[k] - bounds.min[k])
+                });
+                self.walls.iter().all(|wall| wall.contains(&point))
+            })
+            .count();
+        bounds.volume() * inside as f64 / DOMAIN_VOLUME_SAMPLES as f64
+    }
+
+    /// Returns the exact volume of the domain if all walls are planar, which the cells then fill
+    /// exactly, by clipping a box like a cell, see [`Tessellation::domain_volume`].
+    pub(crate) fn planar_domain_volume(&self) -> Option<f64> {
+        if !self.walls.iter().all(|wall| wall.is_planar()) {
+            return None;
+        }
+        let bounds = self.clip_bounds();
+        let center: [f64; D] = std::array::from_fn(|k| 0.5 * (bounds.min[k] + bounds.max[k]));
+        let mut cell = C::new(0, bounds);
+        let mut scratch = C::Scratch::default();
+        for wall in &self.walls {
+            wall.cut(&center, &mut |point, normal| {
+                cell.clip(&point, &normal, wall.id(), &mut scratch, None);
+            });
+        }
+        Some(cell.volume())
+    }
+
+    /// Returns the largest [`Cell::diameter`] of the computed cells, or zero without cells,
+    /// for example to size interpolation stencils.
+    pub fn max_cell_diameter(&self) -> f64 {
+        self.cells.par_iter().map(|cell| cell.diameter()).reduce(|| 0.0, f64::max)
+    }
+
+    /// Sums a quantity over all cells, in index order if the tessellation is deterministic.
+    pub(crate) fn sum_cells<F: Fn(&C) -> f64 + Sync + Send>(&self, f: F) -> f64 {
+        if self.deterministic {
+            self.cells.iter().map(f).sum()
+        } else {
+            self.cells.par_iter().map(f).sum()
+        }
+    }
+
     /// Retrieves the position as `[f64; 3]` of a generator by its index.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of range, see [`Tessellation::try_get_generator`] for a fallible version.
+    /// Not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
     pub fn get_generator(&self, index: usize) -> [f64; D] {
-        let offset = index * D;
-        self.generators[offset..offset + D].try_into().unwrap()
+        point_at(&self.generators, index)
+    }
+
+    /// Retrieves the position of a generator by its index, or `None` if it is out of range.
+    pub fn try_get_generator(&self, index: usize) -> Option<[f64; D]> {
+        (index < self.count_generators()).then(|| point_at(&self.generators, index))
+    }
+
+    /// Finds the owned generator closest to `point` within `radius` using the spatial index,
+    /// for picking the generator under a cursor. Returns `None` if there is none in the radius.
+    pub fn find_generator_near(&self, point: &[f64; D], radius: f64) -> Option<usize> {
+        let owned = self.count_owned();
+        let radius_sq = radius * radius;
+        let mut best: Option<(usize, f64)> = None;
+        // The search covers four times the squared radius, see `SpatialAlgorithm::visit_neighbors`.
+        let mut max_dist_sq = 0.25 * radius_sq;
+        self.algorithm.visit_near_point(&self.generators, *point, &mut max_dist_sq, |j, p, current| {
+            let d2: f64 = (0..D).map(|k| (p[k] - point[k]).powi(2)).sum();
+            if j < owned && d2 <= radius_sq && best.is_none_or(|(i, b)| d2 < b || (d2 == b && j < i)) {
+                best = Some((j, d2));
+                return 0.25 * d2;
+            }
+            current
+        });
+        best.map(|(j, _)| j)
     }
 
     /// Retrieves a cell by its index.
@@ -526,10 +1609,38 @@ impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
     }
 }
 
+/// Reads the point at `index` from a flat array of coordinates.
+/// With the `no-panic` feature, coordinates past the end of the array read as NaN instead of panicking.
+pub(crate) fn point_at<const D: usize>(coords: &[f64], index: usize) -> [f64; D] {
+    #[cfg(not(feature = "no-panic"))]
+    {
+        std::array::from_fn(|k| coords[index * D + k])
+    }
+    #[cfg(feature = "no-panic")]
+    {
+        std::array::from_fn(|k| index.checked_mul(D).and_then(|i| i.checked_add(k)).and_then(|i| coords.get(i)).copied().unwrap_or(f64::NAN))
+    }
+}
+
+/// The distance of `point` from the plane through `origin` with the given outward `normal`,
+/// positive outside the valid region.
+fn signed_distance<const D: usize>(point: &[f64; D], origin: &[f64; D], normal: &[f64; D]) -> f64 {
+    let len = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+    if len == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    (0..D).map(|k| (point[k] - origin[k]) * normal[k]).sum::<f64>() / len
+}
+
 fn get_seed() -> u64 {
+    // Prefer the platform entropy source, which is available both in browsers and Node.js.
     #[cfg(target_arch = "wasm32")]
     {
-        (js_sys::Math::random() * 4294967296.0) as u64
+        let mut buf = [0u8; 8];
+        match getrandom::getrandom(&mut buf) {
+            Ok(()) => u64::from_le_bytes(buf),
+            Err(_) => (js_sys::Math::random() * 4294967296.0) as u64,
+        }
     }
     #[cfg(not(target_arch = "wasm32"))]
     {
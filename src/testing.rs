@@ -0,0 +1,179 @@
+//! Reusable property checks for tessellations, enabled by the `test-util` feature.
+//!
+//! These are the invariants the crate's own tests rely on, so that downstream crates can run
+//! them against their own combinations of cells, spatial algorithms and walls.
+//! Each check returns `Err` with a description of the first violation found.
+
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+
+/// Checks that the total volume of all cells matches `expected_volume` within the relative `tolerance`.
+///
+/// With walls the expected volume is the volume of the bounding box intersected with the walls.
+pub fn check_volume_conservation<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>>(
+    tess: &Tessellation<D, C, A>,
+    expected_volume: f64,
+    tolerance: f64,
+) -> Result<(), String> {
+    let total = tess.total_volume();
+    let error = (total - expected_volume).abs() / expected_volume.abs();
+    if error > tolerance {
+        return Err(format!(
+            "total volume {} deviates from {} by a relative error of {:e}",
+            total, expected_volume, error
+        ));
+    }
+    Ok(())
+}
+
+/// Checks that every neighbor relation between two cells is reciprocal.
+pub fn check_reciprocity<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>>(
+    tess: &Tessellation<D, C, A>,
+) -> Result<(), String> {
+    match tess.validate().asymmetric_neighbors.first() {
+        Some((i, j)) => Err(format!("cell {} lists {} as a neighbor, but not vice versa", i, j)),
+        None => Ok(()),
+    }
+}
+
+/// Checks that every cell is convex, i.e. all of its vertices lie on the inner side of each of
+/// its faces, up to the absolute `tolerance`. Only supported for 2D and 3D cells which provide
+/// their faces through [`Cell::faces`], other cells pass trivially.
+pub fn check_convexity<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>>(
+    tess: &Tessellation<D, C, A>,
+    tolerance: f64,
+) -> Result<(), String> {
+    for (i, cell) in tess.cells.iter().enumerate() {
+        let vertices: Vec<&[f64]> = cell.vertices().chunks_exact(D).collect();
+        if vertices.is_empty() {
+            continue;
+        }
+        let mut center = [0.0; D];
+        for v in &vertices {
+            for k in 0..D {
+                center[k] += v[k] / vertices.len() as f64;
+            }
+        }
+
+        for (f, face) in cell.faces().iter().enumerate() {
+            let normal = cell.face_normal(f);
+            if normal.iter().all(|&x| x == 0.0) {
+                continue;
+            }
+            let origin = vertices[face[0]];
+            let side = |p: &[f64]| (0..D).map(|k| (p[k] - origin[k]) * normal[k]).sum::<f64>();
+            // Orient the normal away from the vertex mean, which lies inside a convex cell.
+            let sign = if side(&center) > 0.0 { -1.0 } else { 1.0 };
+            if let Some(v) = vertices.iter().position(|v| sign * side(v) > tolerance) {
+                return Err(format!("vertex {} of cell {} lies outside of face {}", v, i, f));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Checks that the cells do not depend on the order of the generators.
+///
+/// The generators are shuffled with the given `seed` and the tessellation is recalculated.
+/// Each cell must have the same volume, within the relative `tolerance`, and the same neighbors
+/// as before. The original generators and cells are restored afterwards.
+pub fn check_permutation_invariance<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>>(
+    tess: &mut Tessellation<D, C, A>,
+    seed: u64,
+    tolerance: f64,
+) -> Result<(), String> {
+    let original = tess.generators.clone();
+    let cells = tess.cells.clone();
+    let approximate = tess.approximate.clone();
+    let count = original.len() / D;
+
+    let mut order: Vec<usize> = (0..count).collect();
+    order.shuffle(&mut StdRng::seed_from_u64(seed));
+    let mut position = vec![0; count];
+    let mut shuffled = Vec::with_capacity(original.len());
+    for (new, &old) in order.iter().enumerate() {
+        position[old] = new;
+        shuffled.extend_from_slice(&original[old * D..old * D + D]);
+    }
+
+    tess.set_generators_unchecked(&shuffled);
+    tess.calculate();
+
+    let mut result = Ok(());
+    if tess.cells.len() != cells.len() {
+        result = Err(format!("{} cells after shuffling, expected {}", tess.cells.len(), cells.len()));
+    }
+    for (old, cell) in cells.iter().enumerate() {
+        if result.is_err() {
+            break;
+        }
+        let permuted = &tess.cells[position[old]];
+        let (a, b) = (cell.volume(), permuted.volume());
+        if (a - b).abs() > tolerance * a.abs().max(b.abs()) {
+            result = Err(format!("cell {} has volume {} after shuffling, expected {}", old, b, a));
+            break;
+        }
+
+        let mut expected: Vec<i32> = cell
+            .neighbors()
+            .iter()
+            .map(|&n| if n >= 0 { position[n as usize] as i32 } else { n })
+            .collect();
+        let mut actual = permuted.neighbors().to_vec();
+        expected.sort_unstable();
+        actual.sort_unstable();
+        if expected != actual {
+            result = Err(format!("cell {} has neighbors {:?} after shuffling, expected {:?}", old, actual, expected));
+        }
+    }
+
+    tess.set_generators_unchecked(&original);
+    tess.cells = cells;
+    tess.approximate = approximate;
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Algorithm2DGrid, Algorithm3DGrid, BoundingBox, Cell2D, Cell3DFaces, Wall, WALL_ID_MAX};
+    use crate::wall_3d::SphereGeometry;
+
+    #[test]
+    fn test_properties_3d() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+        tess.random_generators(200);
+        tess.calculate();
+
+        check_volume_conservation(&tess, 1000.0, 1e-9).unwrap();
+        check_reciprocity(&tess).unwrap();
+        check_convexity(&tess, 1e-9).unwrap();
+        check_permutation_invariance(&mut tess, 42, 1e-9).unwrap();
+        assert!(check_volume_conservation(&tess, 900.0, 1e-3).is_err());
+
+        // A sphere wall is approximated by planes, so the volume only converges slowly.
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+        tess.random_generators(500);
+        tess.calculate();
+        check_volume_conservation(&tess, 4.0 / 3.0 * std::f64::consts::PI * 64.0, 0.05).unwrap();
+        check_convexity(&tess, 1e-9).unwrap();
+    }
+
+    #[test]
+    fn test_properties_2d() {
+        let bounds = BoundingBox::new([0.0, 0.0], [10.0, 10.0]);
+        let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(4, 4, &bounds));
+        tess.random_generators(100);
+        tess.calculate();
+
+        check_volume_conservation(&tess, 100.0, 1e-9).unwrap();
+        check_reciprocity(&tess).unwrap();
+        check_convexity(&tess, 1e-9).unwrap();
+        check_permutation_invariance(&mut tess, 7, 1e-9).unwrap();
+    }
+}
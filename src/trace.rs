@@ -0,0 +1,141 @@
+//! Optional instrumentation with the `tracing` crate, enabled by the `tracing` feature.
+//!
+//! Besides the spans around the main stages of a calculation, a few counters are accumulated
+//! during [`Tessellation::calculate`](crate::Tessellation::calculate). They are reported in a
+//! `debug` event once it finishes and kept for
+//! [`Tessellation::counters`](crate::Tessellation::counters). Each calculation tallies only the
+//! counts of its own cells, so concurrent calculations in the same process do not mix.
+//! Without the feature the counters compile to nothing.
+
+#[cfg(feature = "tracing")]
+use std::cell::Cell;
+#[cfg(feature = "tracing")]
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// The counters of a calculation, see [`Tessellation::counters`](crate::Tessellation::counters).
+#[cfg(feature = "tracing")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct CalculationCounters {
+    /// The number of clips of the cells by walls and by neighboring generators.
+    pub clip_calls: u64,
+    /// The number of generators passed to the cells by the neighbor searches.
+    pub candidates: u64,
+    /// The number of bins visited by the neighbor searches of the spatial algorithm.
+    pub bins_visited: u64,
+}
+
+#[cfg(feature = "tracing")]
+thread_local! {
+    /// The counts of the cells computed on this thread, in the order of [`Counter`], which only
+    /// ever increase.
+    static THREAD_COUNTS: Cell<[u64; 3]> = const { Cell::new([0; 3]) };
+}
+
+/// Identifies one of the counters.
+#[derive(Clone, Copy)]
+pub(crate) enum Counter {
+    ClipCalls,
+    Candidates,
+    BinsVisited,
+}
+
+/// A counter local to one loop which is added to the counts of the thread when dropped,
+/// to keep the hot loops free of thread-local accesses.
+pub(crate) struct LocalCounter {
+    #[cfg(feature = "tracing")]
+    counter: Counter,
+    #[cfg(feature = "tracing")]
+    count: u64,
+}
+
+impl LocalCounter {
+    #[inline(always)]
+    #[allow(unused_variables)]
+    pub(crate) fn new(counter: Counter) -> Self {
+        Self {
+            #[cfg(feature = "tracing")]
+            counter,
+            #[cfg(feature = "tracing")]
+            count: 0,
+        }
+    }
+
+    #[inline(always)]
+    pub(crate) fn inc(&mut self) {
+        #[cfg(feature = "tracing")]
+        {
+            self.count += 1;
+        }
+    }
+}
+
+#[cfg(feature = "tracing")]
+impl Drop for LocalCounter {
+    fn drop(&mut self) {
+        THREAD_COUNTS.with(|counts| {
+            let mut values = counts.get();
+            values[self.counter as usize] += self.count;
+            counts.set(values);
+        });
+    }
+}
+
+/// Collects the counters of the cells of one calculation, which may run on several threads.
+#[derive(Default)]
+pub(crate) struct Tally {
+    #[cfg(feature = "tracing")]
+    counts: [AtomicU64; 3],
+}
+
+impl Tally {
+    /// Runs `compute`, which computes a single cell on the current thread, and adds the counts
+    /// it accumulated to the tally.
+    #[inline(always)]
+    pub(crate) fn record<T>(&self, compute: impl FnOnce() -> T) -> T {
+        #[cfg(feature = "tracing")]
+        let start = THREAD_COUNTS.with(Cell::get);
+        let result = compute();
+        #[cfg(feature = "tracing")]
+        {
+            let end = THREAD_COUNTS.with(Cell::get);
+            for ((count, end), start) in self.counts.iter().zip(end).zip(start) {
+                count.fetch_add(end - start, Ordering::Relaxed);
+            }
+        }
+        result
+    }
+
+    /// Emits a `debug` event with the counters of the calculation and returns them.
+    #[cfg(feature = "tracing")]
+    pub(crate) fn finish(self) -> CalculationCounters {
+        let [clip_calls, candidates, bins_visited] = self.counts.map(AtomicU64::into_inner);
+        tracing::debug!(clip_calls, candidates, bins_visited, "calculation counters");
+        CalculationCounters { clip_calls, candidates, bins_visited }
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use super::*;
+    use crate::{Algorithm3DGrid, BoundingBox, Cell3DFaces, Tessellation};
+
+    #[test]
+    fn test_counters() {
+        let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(1, 1, 1, &bounds));
+        tess.set_generators(&[2.5, 5.0, 5.0, 7.5, 5.0, 5.0]).unwrap();
+        assert_eq!(tess.counters(), CalculationCounters::default());
+        tess.calculate();
+
+        // Each cell visits the single bin and clips against the other generator, regardless of
+        // the calculations that other tests run concurrently.
+        let expected = CalculationCounters { clip_calls: 2, candidates: 2, bins_visited: 2 };
+        assert_eq!(tess.counters(), expected);
+        tess.calculate_warm();
+        assert_eq!(tess.counters(), expected);
+
+        // Without a budget each search stops at its first candidate, which is not clipped.
+        tess.preview_calculate(0);
+        assert_eq!(tess.counters(), CalculationCounters { clip_calls: 0, candidates: 2, bins_visited: 2 });
+    }
+}
@@ -0,0 +1,243 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::tessellation::Tessellation;
+use std::collections::{BTreeSet, HashMap};
+
+/// How [`track_cells`] matches the cells of two tessellation states.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CellMatching {
+    /// Cells with the same generator index are the same cell, for point sets that only move.
+    #[default]
+    Index,
+    /// Cells are matched to the cell they overlap most, if that is mutual, for point sets
+    /// whose generators are added, removed or reordered between the states.
+    Overlap,
+}
+
+/// A cell present in both states, as reported by [`track_cells`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct CellTrack {
+    /// The index of the cell in the previous state.
+    pub prev: usize,
+    /// The index of the cell in the current state.
+    pub curr: usize,
+    /// The current volume minus the previous volume.
+    pub volume_change: f64,
+    /// The current indices of the tracked neighbors the cell gained contact with.
+    pub gained_neighbors: Vec<usize>,
+    /// The current indices of the tracked neighbors the cell lost contact with.
+    pub lost_neighbors: Vec<usize>,
+}
+
+/// A neighbor switch (T1 event): two cells lose their shared face while two cells that
+/// were neighbors of both gain one. All indices refer to the current state.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct T1Event {
+    /// The cells that lost their shared face.
+    pub lost: (usize, usize),
+    /// The cells that gained a shared face.
+    pub gained: (usize, usize),
+}
+
+/// The evolution of the cells between two states, as returned by [`track_cells`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TrackingReport {
+    /// The matched cells, in the order of their current index.
+    pub tracks: Vec<CellTrack>,
+    /// The current indices of the cells without a match in the previous state.
+    pub appeared: Vec<usize>,
+    /// The previous indices of the cells without a match in the current state.
+    pub vanished: Vec<usize>,
+    /// The neighbor switches among matched cells.
+    pub t1_events: Vec<T1Event>,
+}
+
+/// The neighbor relations of a calculated state, captured by [`Tessellation::adjacency_snapshot`]
+/// to detect topological events once the cells of the same generators are recalculated.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct AdjacencySnapshot {
+    /// The pairs `(i, j)` with `i < j` of cells sharing a face, listed by either cell.
+    pub pairs: BTreeSet<(usize, usize)>,
+    /// The non-empty cells.
+    pub cells: BTreeSet<usize>,
+}
+
+impl AdjacencySnapshot {
+    /// Returns the neighbors of each cell.
+    fn neighbor_sets(&self) -> HashMap<usize, BTreeSet<usize>> {
+        let mut sets: HashMap<usize, BTreeSet<usize>> = HashMap::new();
+        for &(i, j) in &self.pairs {
+            sets.entry(i).or_default().insert(j);
+            sets.entry(j).or_default().insert(i);
+        }
+        sets
+    }
+}
+
+/// The topological events between two states of the same generators, as returned by
+/// [`Tessellation::topological_events`].
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct TopologyEvents {
+    /// The pairs `(i, j)` with `i < j` of cells that gained a shared face.
+    pub created: Vec<(usize, usize)>,
+    /// The pairs `(i, j)` with `i < j` of cells that lost their shared face.
+    pub destroyed: Vec<(usize, usize)>,
+    /// The neighbor switches, where a destroyed face is replaced by a created face between
+    /// two former common neighbors.
+    pub t1_events: Vec<T1Event>,
+    /// The cells that vanished (T2 events), which were non-empty before and are empty or missing now.
+    pub t2_events: Vec<usize>,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Captures the neighbor relations of the computed cells, to be compared with
+    /// [`Tessellation::topological_events`] after a relaxation or update step.
+    pub fn adjacency_snapshot(&self) -> AdjacencySnapshot {
+        let count = self.cells.len();
+        let mut snapshot = AdjacencySnapshot::default();
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.is_empty() {
+                continue;
+            }
+            snapshot.cells.insert(i);
+            for &n in cell.neighbors() {
+                if n >= 0 && (n as usize) < count && n as usize != i {
+                    let j = n as usize;
+                    snapshot.pairs.insert((i.min(j), i.max(j)));
+                }
+            }
+        }
+        snapshot
+    }
+
+    /// Compares the neighbor relations of the computed cells with a snapshot taken before a
+    /// relaxation or update step, and reports the created and destroyed faces along with the
+    /// neighbor switches (T1) and vanished cells (T2), the observables of foam dynamics.
+    ///
+    /// Cells are matched by their generator index, see [`track_cells`] for point sets whose
+    /// generators are added, removed or reordered.
+    pub fn topological_events(&self, before: &AdjacencySnapshot) -> TopologyEvents {
+        let after = self.adjacency_snapshot();
+        let mut events = TopologyEvents {
+            created: after.pairs.difference(&before.pairs).copied().collect(),
+            destroyed: before.pairs.difference(&after.pairs).copied().collect(),
+            t2_events: before.cells.difference(&after.cells).copied().collect(),
+            ..Default::default()
+        };
+
+        let (prev_sets, curr_sets) = (before.neighbor_sets(), after.neighbor_sets());
+        let empty = BTreeSet::new();
+        for &(a, b) in &events.destroyed {
+            let (na, nb) = (prev_sets.get(&a).unwrap_or(&empty), prev_sets.get(&b).unwrap_or(&empty));
+            let common: Vec<usize> = na.intersection(nb).copied().collect();
+            for (k, &x) in common.iter().enumerate() {
+                for &y in &common[k + 1..] {
+                    if curr_sets.get(&x).is_some_and(|s| s.contains(&y)) && !prev_sets[&x].contains(&y) {
+                        events.t1_events.push(T1Event { lost: (a, b), gained: (x, y) });
+                    }
+                }
+            }
+        }
+        events
+    }
+}
+
+/// Matches the cells of two calculated states of an evolving point set, and reports the volume
+/// change and the neighbor changes of every matched cell along with the cells that appeared
+/// or vanished. Empty cells are never matched.
+///
+/// Neighbor changes only consider matched cells, so a contact with an appearing cell is not
+/// reported as gained. A lost contact between two cells is reported as a T1 event for every
+/// gained contact between two of their former common neighbors.
+pub fn track_cells<const D: usize, C1, A1, C2, A2>(
+    prev: &Tessellation<D, C1, A1>,
+    curr: &Tessellation<D, C2, A2>,
+    matching: CellMatching,
+) -> TrackingReport
+where
+    C1: Cell<D>,
+    A1: SpatialAlgorithm<D>,
+    C2: Cell<D>,
+    A2: SpatialAlgorithm<D>,
+{
+    let pairs: Vec<(usize, usize)> = match matching {
+        CellMatching::Index => (0..prev.cells.len().min(curr.cells.len()))
+            .filter(|&i| !prev.cells[i].is_empty() && !curr.cells[i].is_empty())
+            .map(|i| (i, i))
+            .collect(),
+        CellMatching::Overlap => {
+            // The largest overlap for every cell of either state.
+            let mut best_prev: HashMap<usize, (f64, usize)> = HashMap::new();
+            let mut best_curr: HashMap<usize, (f64, usize)> = HashMap::new();
+            for overlap in curr.intersect(prev) {
+                let (c, p, v) = (overlap.source, overlap.target, overlap.volume);
+                if best_prev.get(&c).is_none_or(|b| v > b.0) {
+                    best_prev.insert(c, (v, p));
+                }
+                if best_curr.get(&p).is_none_or(|b| v > b.0) {
+                    best_curr.insert(p, (v, c));
+                }
+            }
+            let mut pairs: Vec<(usize, usize)> = best_prev
+                .iter()
+                .filter(|&(c, &(_, p))| best_curr.get(&p).is_some_and(|b| b.1 == *c))
+                .map(|(&c, &(_, p))| (p, c))
+                .collect();
+            pairs.sort_unstable_by_key(|&(_, c)| c);
+            pairs
+        }
+    };
+
+    let mut prev_to_curr = vec![None; prev.cells.len()];
+    let mut curr_matched = vec![false; curr.cells.len()];
+    for &(p, c) in &pairs {
+        prev_to_curr[p] = Some(c);
+        curr_matched[c] = true;
+    }
+
+    // The contacts between matched cells in each state, in current indices.
+    let contacts = |neighbors: &[i32], count: usize, map: &dyn Fn(usize) -> Option<usize>| -> BTreeSet<usize> {
+        neighbors
+            .iter()
+            .filter(|&&n| n >= 0 && (n as usize) < count)
+            .filter_map(|&n| map(n as usize))
+            .collect()
+    };
+    let prev_contacts: HashMap<usize, BTreeSet<usize>> = pairs
+        .iter()
+        .map(|&(p, c)| (c, contacts(prev.cells[p].neighbors(), prev.cells.len(), &|n| prev_to_curr[n])))
+        .collect();
+    let curr_contacts: HashMap<usize, BTreeSet<usize>> = pairs
+        .iter()
+        .map(|&(_, c)| (c, contacts(curr.cells[c].neighbors(), curr.cells.len(), &|n| curr_matched[n].then_some(n))))
+        .collect();
+
+    let mut report = TrackingReport::default();
+    for &(p, c) in &pairs {
+        let (before, after) = (&prev_contacts[&c], &curr_contacts[&c]);
+        report.tracks.push(CellTrack {
+            prev: p,
+            curr: c,
+            volume_change: curr.cells[c].volume() - prev.cells[p].volume(),
+            gained_neighbors: after.difference(before).copied().collect(),
+            lost_neighbors: before.difference(after).copied().collect(),
+        });
+    }
+    report.appeared = (0..curr.cells.len()).filter(|&c| !curr_matched[c] && !curr.cells[c].is_empty()).collect();
+    report.vanished = (0..prev.cells.len()).filter(|&p| prev_to_curr[p].is_none() && !prev.cells[p].is_empty()).collect();
+
+    for track in &report.tracks {
+        let a = track.curr;
+        for &b in track.lost_neighbors.iter().filter(|&&b| b > a) {
+            let common: Vec<usize> = prev_contacts[&a].intersection(&prev_contacts[&b]).copied().collect();
+            for (k, &x) in common.iter().enumerate() {
+                for &y in &common[k + 1..] {
+                    if curr_contacts[&x].contains(&y) && !prev_contacts[&x].contains(&y) {
+                        report.t1_events.push(T1Event { lost: (a, b), gained: (x, y) });
+                    }
+                }
+            }
+        }
+    }
+    report
+}
@@ -0,0 +1,166 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::error::GeneratorError;
+use crate::tessellation::Tessellation;
+
+/// An axis-aligned affine map `x -> scale * x + offset` from physical coordinates to the coordinates
+/// a tessellation is computed in, see [`Tessellation::set_domain_transform`].
+///
+/// Mapping a highly stretched domain to the unit cube keeps the cells well shaped for the clipping
+/// tolerance. The cells are computed in the mapped coordinates, so for an anisotropic scale they
+/// are the Voronoi cells of the scaled metric rather than of the physical one.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DomainTransform<const D: usize> {
+    /// The factor along each axis, which must be positive.
+    pub scale: [f64; D],
+    /// The translation applied after scaling.
+    pub offset: [f64; D],
+}
+
+impl<const D: usize> DomainTransform<D> {
+    /// Creates a transform with the given positive scale factors and offset.
+    pub fn new(scale: [f64; D], offset: [f64; D]) -> Self {
+        Self { scale, offset }
+    }
+
+    /// Creates the transform mapping `bounds` to the unit cube. Axes without extent are only translated.
+    pub fn normalizing(bounds: &BoundingBox<D>) -> Self {
+        let scale: [f64; D] = std::array::from_fn(|k| {
+            let extent = bounds.max[k] - bounds.min[k];
+            if extent > 0.0 { 1.0 / extent } else { 1.0 }
+        });
+        Self { scale, offset: std::array::from_fn(|k| -bounds.min[k] * scale[k]) }
+    }
+
+    /// Maps a physical point to the computed coordinates.
+    pub fn forward(&self, point: &[f64; D]) -> [f64; D] {
+        std::array::from_fn(|k| point[k] * self.scale[k] + self.offset[k])
+    }
+
+    /// Maps a point in the computed coordinates back to physical coordinates.
+    pub fn inverse(&self, point: &[f64; D]) -> [f64; D] {
+        std::array::from_fn(|k| (point[k] - self.offset[k]) / self.scale[k])
+    }
+
+    /// Maps a flat array of physical points `[x, y, (z), ...]` to the computed coordinates.
+    pub fn forward_all(&self, points: &[f64]) -> Vec<f64> {
+        points.iter().enumerate().map(|(i, &x)| x * self.scale[i % D] + self.offset[i % D]).collect()
+    }
+
+    /// Maps a flat array of points `[x, y, (z), ...]` in the computed coordinates back to physical coordinates.
+    pub fn inverse_all(&self, points: &[f64]) -> Vec<f64> {
+        points.iter().enumerate().map(|(i, &x)| (x - self.offset[i % D]) / self.scale[i % D]).collect()
+    }
+
+    /// Maps a physical bounding box to the computed coordinates.
+    pub fn forward_bounds(&self, bounds: &BoundingBox<D>) -> BoundingBox<D> {
+        BoundingBox::new(self.forward(&bounds.min), self.forward(&bounds.max))
+    }
+
+    /// Maps a bounding box in the computed coordinates back to physical coordinates.
+    pub fn inverse_bounds(&self, bounds: &BoundingBox<D>) -> BoundingBox<D> {
+        BoundingBox::new(self.inverse(&bounds.min), self.inverse(&bounds.max))
+    }
+
+    /// Returns the factor from computed to physical volumes (areas in 2D).
+    pub fn inverse_volume_factor(&self) -> f64 {
+        self.scale.iter().map(|s| 1.0 / s).product()
+    }
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Computes the tessellation in coordinates mapped by `transform`, for example
+    /// [`DomainTransform::normalizing`] of the bounds, for numerical robustness in highly
+    /// stretched physical domains. The bounds and the current generators, including ghosts,
+    /// are mapped from physical coordinates, replacing any previous transform, and the
+    /// computed cells are cleared.
+    ///
+    /// Walls are not mapped, so they are given in the computed coordinates. Physical generators
+    /// are set with [`Tessellation::set_generators_physical`], and the output geometry is mapped
+    /// back with [`Tessellation::physical_vertices`] and the related methods.
+    pub fn set_domain_transform(&mut self, transform: DomainTransform<D>) {
+        self.clear_domain_transform();
+        self.bounds = transform.forward_bounds(&self.bounds);
+        self.generators = transform.forward_all(&self.generators);
+        self.domain_transform = Some(transform);
+        self.cells.clear();
+        self.bin_generators();
+    }
+
+    /// Removes the domain transform, mapping the bounds and generators back to physical
+    /// coordinates and clearing the computed cells. Does nothing without a transform.
+    pub fn clear_domain_transform(&mut self) {
+        let Some(transform) = self.domain_transform.take() else { return };
+        self.bounds = transform.inverse_bounds(&self.bounds);
+        self.generators = transform.inverse_all(&self.generators);
+        self.cells.clear();
+        self.bin_generators();
+    }
+
+    /// Returns the domain transform, if one is set.
+    pub fn domain_transform(&self) -> Option<&DomainTransform<D>> {
+        self.domain_transform.as_ref()
+    }
+
+    /// Sets the generators from physical coordinates like [`Tessellation::set_generators`],
+    /// mapping them with the domain transform.
+    pub fn set_generators_physical(&mut self, generators: &[f64]) -> Result<(), GeneratorError> {
+        match self.domain_transform {
+            Some(transform) => self.set_generators(&transform.forward_all(generators)),
+            None => self.set_generators(generators),
+        }
+    }
+
+    /// Returns the generators in physical coordinates as a flat array `[x, y, (z), ...]`.
+    pub fn physical_generators(&self) -> Vec<f64> {
+        self.to_physical(&self.generators)
+    }
+
+    /// Returns the vertices of a computed cell in physical coordinates, in the order of
+    /// [`Cell::vertices`], or `None` if the cell does not exist.
+    pub fn physical_vertices(&self, cell: usize) -> Option<Vec<f64>> {
+        self.cells.get(cell).map(|c| self.to_physical(c.vertices()))
+    }
+
+    /// Returns the centroid of a computed cell in physical coordinates, or `None` if the cell
+    /// does not exist. The affine map preserves centroids.
+    pub fn physical_centroid(&self, cell: usize) -> Option<[f64; D]> {
+        let centroid = self.cells.get(cell)?.centroid();
+        Some(self.domain_transform.map_or(centroid, |t| t.inverse(&centroid)))
+    }
+
+    /// Returns the physical volume (area in 2D) of a computed cell, or `None` if the cell does not exist.
+    pub fn physical_volume(&self, cell: usize) -> Option<f64> {
+        let volume = self.cells.get(cell)?.volume();
+        Some(self.domain_transform.map_or(volume, |t| volume * t.inverse_volume_factor()))
+    }
+
+    /// Maps a flat array of computed coordinates to physical coordinates.
+    fn to_physical(&self, points: &[f64]) -> Vec<f64> {
+        match &self.domain_transform {
+            Some(transform) => transform.inverse_all(points),
+            None => points.to_vec(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_domain_transform() {
+        let bounds = BoundingBox::new([-500.0, 2.0], [500.0, 3.0]);
+        let transform = DomainTransform::normalizing(&bounds);
+        let normalized = transform.forward_bounds(&bounds);
+        assert_eq!((normalized.min, normalized.max), ([0.0, 0.0], [1.0, 1.0]));
+        assert_eq!(transform.forward(&[0.0, 2.5]), [0.5, 0.5]);
+        assert_eq!(transform.inverse_all(&transform.forward_all(&[250.0, 2.25])), vec![250.0, 2.25]);
+        assert_eq!(transform.inverse_volume_factor(), 1000.0);
+
+        // Axes without extent are only translated.
+        let flat = DomainTransform::normalizing(&BoundingBox::new([1.0, 4.0], [3.0, 4.0]));
+        assert_eq!(flat.forward(&[2.0, 4.0]), [0.5, 0.0]);
+    }
+}
@@ -0,0 +1,249 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::Cell;
+use crate::bounds::box_side;
+use crate::tessellation::{BOUNDARY_TOLERANCE, Tessellation, point_at};
+
+/// A structured summary of the consistency checks performed by [`Tessellation::validate`].
+#[derive(Clone, Debug, Default)]
+pub struct ValidationReport {
+    /// `true` if the number of cells does not match the number of owned generators,
+    /// which means `calculate` has not been called since the generators changed.
+    pub stale: bool,
+    /// The sum of the volumes of all computed cells.
+    pub total_volume: f64,
+    /// The exact volume of the domain, if it is bounded by planar walls only, which the cells fill
+    /// exactly, see [`Tessellation::domain_volume`]. It is `None` with curved walls, which the
+    /// cells only approximate, and with ghost generators, as the owned cells only fill a subdomain.
+    pub expected_volume: Option<f64>,
+    /// Pairs `(i, j)` where cell `i` lists `j` as a neighbor, but `j` does not list `i`.
+    pub asymmetric_neighbors: Vec<(usize, usize)>,
+    /// Indices of cells that have been completely clipped away.
+    pub empty_cells: Vec<usize>,
+    /// Indices of non-empty cells with a non-finite or non-positive volume,
+    /// or with a neighbor ID that does not refer to an existing generator.
+    pub invalid_cells: Vec<usize>,
+    /// Indices of cells that rejected a clip because they exceeded their index capacity,
+    /// see [`Cell::overflowed`]. These cells are larger than they should be.
+    pub overflowed_cells: Vec<usize>,
+}
+
+impl ValidationReport {
+    /// The relative deviation of the total cell volume from the expected domain volume,
+    /// or `None` if the domain volume is unknown.
+    pub fn volume_error(&self) -> Option<f64> {
+        self.expected_volume
+            .filter(|&v| v > 0.0)
+            .map(|v| (self.total_volume - v).abs() / v)
+    }
+
+    /// Returns `true` if all checks passed, with the volume error below `tolerance`.
+    pub fn is_valid(&self, tolerance: f64) -> bool {
+        !self.stale
+            && self.asymmetric_neighbors.is_empty()
+            && self.invalid_cells.is_empty()
+            && self.overflowed_cells.is_empty()
+            && self.volume_error().is_none_or(|e| e <= tolerance)
+    }
+}
+
+/// A cell that has been completely clipped away, as returned by [`Tessellation::empty_cells`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct EmptyCell {
+    /// The index of the cell.
+    pub index: usize,
+    /// The ID of the wall, bounding box side or generator that most likely removed the cell,
+    /// in the convention of [`Cell::neighbors`], or `None` if it cannot be determined.
+    pub cause: Option<i32>,
+}
+
+/// The strategy used by [`Tessellation::repair_asymmetric_neighbors`] to remove sliver faces.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SliverRepair {
+    /// Collapses the sliver face into a single vertex, removing it from the cell.
+    Drop,
+    /// Re-cuts the cell with the wall planes generated for the neighbor's generator,
+    /// in the same way as [`Tessellation::calculate_sealed`] shares curved wall planes.
+    Recut,
+}
+
+impl<const D: usize, C: Cell<D>, A: SpatialAlgorithm<D>> Tessellation<D, C, A> {
+    /// Checks the computed cells for consistency and returns a [`ValidationReport`].
+    ///
+    /// The checks cover the total cell volume against the domain volume (where it is known),
+    /// the reciprocity of the neighbor relations and the validity of the individual cells.
+    /// Faces shared with ghost generators have no cell on the other side and are not checked
+    /// for reciprocity.
+    pub fn validate(&self) -> ValidationReport {
+        let owned = self.count_owned();
+        let count = self.count_generators();
+        let mut report = ValidationReport {
+            stale: self.cells.len() != owned,
+            total_volume: self.total_volume(),
+            expected_volume: if owned < count { None } else { self.planar_domain_volume() },
+            ..Default::default()
+        };
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.is_empty() {
+                report.empty_cells.push(i);
+                continue;
+            }
+
+            if cell.overflowed() {
+                report.overflowed_cells.push(i);
+            }
+
+            let volume = cell.volume();
+            let mut valid = volume.is_finite() && volume > 0.0;
+            for &n in cell.neighbors() {
+                if n < 0 {
+                    continue;
+                }
+                let j = n as usize;
+                if (owned..count).contains(&j) {
+                    continue;
+                }
+                match self.cells.get(j) {
+                    Some(other) if j < owned => {
+                        if !other.neighbors().contains(&(i as i32))
+                            && !report.asymmetric_neighbors.contains(&(i, j))
+                        {
+                            report.asymmetric_neighbors.push((i, j));
+                        }
+                    }
+                    _ => valid = false,
+                }
+            }
+            if !valid {
+                report.invalid_cells.push(i);
+            }
+        }
+
+        report
+    }
+
+    /// Lists the computed cells that have been completely clipped away, with the most likely cause,
+    /// to diagnose generators that [`Tessellation::relax`] leaves in place because their cell
+    /// collapsed, for example after adding a wall.
+    ///
+    /// The cause is the last clip that modified the cell if the `clip-log` feature is enabled.
+    /// Otherwise it is the first wall or bounding box side the generator lies on or beyond, or
+    /// failing that the nearest other generator, as only a nearly coincident one removes a cell.
+    pub fn empty_cells(&self) -> Vec<EmptyCell> {
+        self.cells
+            .iter()
+            .enumerate()
+            .filter(|(_, cell)| cell.is_empty())
+            .map(|(index, cell)| {
+                let logged = cell.clip_log().iter().rev().find(|r| r.modified).map(|r| r.neighbor_id);
+                let cause = logged.or_else(|| (index < self.count_generators()).then(|| self.empty_cell_cause(index)).flatten());
+                EmptyCell { index, cause }
+            })
+            .collect()
+    }
+
+    /// Finds the wall, bounding box side or generator that most likely removed the cell of a generator.
+    fn empty_cell_cause(&self, index: usize) -> Option<i32> {
+        let g: [f64; D] = point_at(&self.generators, index);
+        let wall = self.walls.iter().find(|w| {
+            !w.contains(&g) || w.signed_distance(&g).is_some_and(|d| d <= BOUNDARY_TOLERANCE)
+        });
+        if let Some(wall) = wall {
+            return Some(wall.id());
+        }
+        for (k, &v) in g.iter().enumerate() {
+            if v - self.bounds.min[k] <= BOUNDARY_TOLERANCE {
+                return Some(box_side(k, false));
+            } else if self.bounds.max[k] - v <= BOUNDARY_TOLERANCE {
+                return Some(box_side(k, true));
+            }
+        }
+        (0..self.count_generators())
+            .filter(|&j| j != index)
+            .map(|j| {
+                let p: [f64; D] = point_at(&self.generators, j);
+                (j, (0..D).map(|k| (p[k] - g[k]).powi(2)).sum::<f64>())
+            })
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(j, _)| j as i32)
+    }
+
+    /// Lists all faces for which the neighbor relation is not reciprocal.
+    ///
+    /// Returns `(i, j, face_area)` for every face of cell `i` with neighbor `j`,
+    /// where cell `j` does not list `i` as one of its neighbors. These are typically
+    /// sliver faces caused by the single-plane approximation of curved walls.
+    pub fn find_asymmetric_neighbors(&self) -> Vec<(usize, usize, f64)> {
+        let mut result = Vec::new();
+        for (i, cell) in self.cells.iter().enumerate() {
+            for (face, &n) in cell.neighbors().iter().enumerate() {
+                if n < 0 {
+                    continue;
+                }
+                let j = n as usize;
+                if let Some(other) = self.cells.get(j)
+                    && !other.neighbors().contains(&(i as i32))
+                {
+                    result.push((i, j, cell.face_area(face)));
+                }
+            }
+        }
+        result
+    }
+
+    /// Removes non-reciprocal faces with an area below `area_threshold`.
+    ///
+    /// Returns the number of faces that were removed. Faces above the threshold are left
+    /// untouched, as they likely indicate a real problem rather than a numerical artifact.
+    pub fn repair_asymmetric_neighbors(&mut self, area_threshold: f64, mode: SliverRepair) -> usize {
+        let slivers: Vec<(usize, usize)> = self
+            .find_asymmetric_neighbors()
+            .into_iter()
+            .filter(|&(_, _, area)| area < area_threshold)
+            .map(|(i, j, _)| (i, j))
+            .collect();
+
+        let mut repaired = 0;
+        for (i, j) in slivers {
+            let is_sliver = |cell: &C| {
+                cell.neighbors()
+                    .iter()
+                    .enumerate()
+                    .position(|(f, &n)| n == j as i32 && cell.face_area(f) < area_threshold)
+            };
+
+            match mode {
+                SliverRepair::Drop => {
+                    let cell = &mut self.cells[i];
+                    if let Some(face) = is_sliver(cell)
+                        && cell.collapse_face(face)
+                    {
+                        repaired += 1;
+                    }
+                }
+                SliverRepair::Recut => {
+                    let g_pos: [f64; D] = point_at(&self.generators, i);
+                    let n_pos: [f64; D] = point_at(&self.generators, j);
+                    let cell = &mut self.cells[i];
+                    let mut scratch = C::Scratch::default();
+                    for wall in &self.walls {
+                        wall.cut(&n_pos, &mut |point, normal| {
+                            // Only apply planes which keep our own generator on the valid side.
+                            let mut dot = 0.0;
+                            for k in 0..D {
+                                dot += (g_pos[k] - point[k]) * normal[k];
+                            }
+                            if dot <= 1e-9 {
+                                cell.clip(&point, &normal, wall.id(), &mut scratch, None);
+                            }
+                        });
+                    }
+                    if is_sliver(cell).is_none() {
+                        repaired += 1;
+                    }
+                }
+            }
+        }
+        repaired
+    }
+}
@@ -0,0 +1,44 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::cell::{tetrahedralize_faces, Cell, TetMesh, Tetrahedralization};
+use crate::merge::VertexWelder;
+use crate::tessellation::Tessellation;
+
+impl<C: Cell<3>, A: SpatialAlgorithm<3>> Tessellation<3, C, A> {
+    /// Decomposes all cells into a conforming tetrahedral volume mesh, for finite element solvers.
+    ///
+    /// The vertices are welded across the cells, and each face is triangulated from its vertex
+    /// with the smallest welded index, so the faces shared by neighboring cells are triangulated
+    /// alike. The tetrahedra are positively oriented and grouped by cell in increasing order,
+    /// see [`Cell::tetrahedralize`]. Empty cells are skipped.
+    pub fn tetrahedralize(&self, method: Tetrahedralization) -> TetMesh {
+        // The vertex averages are added after all welded vertices.
+        let mut welder = VertexWelder::new(&self.bounds);
+        let keys: Vec<Vec<usize>> = self
+            .cells
+            .iter()
+            .map(|cell| cell.vertices().chunks_exact(3).map(|p| welder.weld(p)).collect())
+            .collect();
+        let mut mesh = TetMesh { vertices: welder.vertices, ..Default::default() };
+
+        for (i, cell) in self.cells.iter().enumerate() {
+            if cell.is_empty() {
+                continue;
+            }
+            let points: Vec<[f64; 3]> = cell.vertices().chunks_exact(3).map(|v| [v[0], v[1], v[2]]).collect();
+            let tetrahedra = tetrahedralize_faces(&points, &cell.faces(), &keys[i], method);
+            if tetrahedra.is_empty() {
+                continue;
+            }
+            let apex = mesh.vertices.len() / 3;
+            if method == Tetrahedralization::Centroid {
+                let n = points.len() as f64;
+                mesh.vertices.extend((0..3).map(|k| points.iter().map(|p| p[k]).sum::<f64>() / n));
+            }
+            for tet in tetrahedra {
+                mesh.tetrahedra.push(tet.map(|v| if v == points.len() { apex } else { keys[i][v] }));
+                mesh.cells.push(i);
+            }
+        }
+        mesh
+    }
+}
@@ -0,0 +1,345 @@
+//! Closest-point queries on sampled curves, for walls that follow a curve such as tubes.
+//!
+//! A [`SegmentCurve`] approximates a curve by the segments between consecutive sample points,
+//! for example of a parametric curve with [`SegmentCurve::from_fn`]. A custom tube wall around
+//! it is then a [`TubeGeometry`].
+
+use super::WallGeometry;
+
+/// The number of consecutive segments grouped under one bounding sphere.
+const SEGMENTS_PER_CHUNK: usize = 16;
+
+/// The number of probe segments per sampled segment used to measure a curve for resampling.
+const OVERSAMPLING: usize = 16;
+
+/// How a parametric curve is divided into segments, see [`SegmentCurve::from_fn_sampled`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum CurveSampling {
+    /// Uniform steps in the curve parameter, which give long segments where the curve moves fast.
+    #[default]
+    Parameter,
+    /// Segments of equal length along the curve.
+    ArcLength,
+    /// Segments that shorten where the curve bends, to even out the distance of the segments from
+    /// the curve. The density grows with the square root of the curvature, and falls back to
+    /// equal lengths on straight parts.
+    Curvature,
+}
+
+impl CurveSampling {
+    /// Returns the parameters in `[0, 1]` at which to sample a curve `f` with `resolution` segments:
+    /// `resolution + 1` parameters from 0 to 1, or `resolution` parameters leaving out 1 if the curve
+    /// is `periodic`, returning to its start at 1.
+    ///
+    /// The arc length and the curvature are measured on a polyline of `16 * resolution` segments.
+    pub fn parameters<const D: usize>(self, f: &dyn Fn(f64) -> [f64; D], resolution: usize, periodic: bool) -> Vec<f64> {
+        let resolution = resolution.max(1);
+        let count = if periodic { resolution } else { resolution + 1 };
+        let uniform = || (0..count).map(|i| i as f64 / resolution as f64).collect();
+        if self == CurveSampling::Parameter {
+            return uniform();
+        }
+
+        let probes = resolution * OVERSAMPLING;
+        let points: Vec<[f64; D]> = (0..=probes).map(|i| f(i as f64 / probes as f64)).collect();
+        let lengths: Vec<f64> = points.windows(2).map(|w| distance_sq(&w[0], &w[1]).sqrt()).collect();
+        let total: f64 = lengths.iter().sum();
+        let weights: Vec<f64> = match self {
+            CurveSampling::Curvature => {
+                // The turning angle at a probe point, between the probe segments before and after it.
+                let turning = |i: usize| -> f64 {
+                    let (before, after) = if i > 0 && i < probes {
+                        (i - 1, i)
+                    } else if periodic {
+                        (probes - 1, 0)
+                    } else {
+                        return 0.0;
+                    };
+                    let a: [f64; D] = std::array::from_fn(|k| points[before + 1][k] - points[before][k]);
+                    let b: [f64; D] = std::array::from_fn(|k| points[after + 1][k] - points[after][k]);
+                    let dot: f64 = (0..D).map(|k| a[k] * b[k]).sum();
+                    let cross = (lengths[before] * lengths[before] * lengths[after] * lengths[after] - dot * dot).max(0.0).sqrt();
+                    cross.atan2(dot)
+                };
+                lengths
+                    .iter()
+                    .enumerate()
+                    .map(|(i, &ds)| {
+                        let curvature = if ds > 0.0 { 0.5 * (turning(i) + turning(i + 1)) / ds } else { 0.0 };
+                        ds * (1.0 + (curvature * total).sqrt())
+                    })
+                    .collect()
+            }
+            _ => lengths,
+        };
+
+        // Places the parameters at equal steps of the accumulated weight.
+        let sum: f64 = weights.iter().sum();
+        if !(sum > 0.0 && sum.is_finite()) {
+            return uniform();
+        }
+        let mut parameters = Vec::with_capacity(count);
+        let (mut probe, mut accumulated) = (0, 0.0);
+        for i in 0..count {
+            let target = sum * i as f64 / resolution as f64;
+            while probe + 1 < probes && accumulated + weights[probe] < target {
+                accumulated += weights[probe];
+                probe += 1;
+            }
+            let fraction = if weights[probe] > 0.0 { ((target - accumulated) / weights[probe]).clamp(0.0, 1.0) } else { 0.0 };
+            parameters.push((probe as f64 + fraction) / probes as f64);
+        }
+        parameters
+    }
+}
+
+/// The closest point on a curve to a query point, as returned by [`SegmentCurve::closest`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CurvePoint<const D: usize> {
+    /// The closest point.
+    pub point: [f64; D],
+    /// The index of the segment containing the point, which starts at the sample of the same index.
+    pub segment: usize,
+    /// The position of the point within the segment, from 0 at its start to 1 at its end.
+    pub t: f64,
+    /// The distance from the query point.
+    pub distance: f64,
+}
+
+/// A bounding sphere of consecutive segments.
+#[derive(Clone, Debug)]
+struct Chunk<const D: usize> {
+    segments: std::ops::Range<usize>,
+    center: [f64; D],
+    radius: f64,
+}
+
+/// A curve approximated by the segments between consecutive sample points, optionally closed
+/// by a segment from the last sample back to the first.
+///
+/// The segments are grouped under bounding spheres, so a closest-point query skips the groups
+/// that cannot contain a closer point than the best one found so far.
+#[derive(Clone, Debug)]
+pub struct SegmentCurve<const D: usize> {
+    points: Vec<[f64; D]>,
+    closed: bool,
+    chunks: Vec<Chunk<D>>,
+}
+
+impl<const D: usize> SegmentCurve<D> {
+    /// Creates a curve through the given sample points.
+    pub fn new(points: Vec<[f64; D]>, closed: bool) -> Self {
+        let mut curve = Self { points, closed, chunks: Vec::new() };
+        let count = curve.segment_count();
+        curve.chunks = (0..count)
+            .step_by(SEGMENTS_PER_CHUNK)
+            .map(|first| {
+                let segments = first..(first + SEGMENTS_PER_CHUNK).min(count);
+                let points: Vec<[f64; D]> = (segments.start..=segments.end).map(|i| curve.point(i)).collect();
+                let min: [f64; D] = std::array::from_fn(|k| points.iter().map(|p| p[k]).fold(f64::INFINITY, f64::min));
+                let max: [f64; D] = std::array::from_fn(|k| points.iter().map(|p| p[k]).fold(f64::NEG_INFINITY, f64::max));
+                let center: [f64; D] = std::array::from_fn(|k| 0.5 * (min[k] + max[k]));
+                let radius = points.iter().map(|p| distance_sq(p, &center)).fold(0.0, f64::max).sqrt();
+                Chunk { segments, center, radius }
+            })
+            .collect();
+        curve
+    }
+
+    /// Samples a parametric curve `f(t)` for `t` in `[0, 1]` with `resolution` segments, at uniform
+    /// steps of `t`. A closed curve is sampled at `resolution` points, leaving out `t = 1` which
+    /// coincides with `t = 0`.
+    pub fn from_fn(f: impl Fn(f64) -> [f64; D], resolution: usize, closed: bool) -> Self {
+        Self::from_fn_sampled(f, resolution, closed, CurveSampling::Parameter)
+    }
+
+    /// Samples a parametric curve like [`SegmentCurve::from_fn`], placing the samples by `sampling`.
+    pub fn from_fn_sampled(f: impl Fn(f64) -> [f64; D], resolution: usize, closed: bool, sampling: CurveSampling) -> Self {
+        let parameters = sampling.parameters(&f, resolution, closed);
+        Self::new(parameters.into_iter().map(f).collect(), closed)
+    }
+
+    /// Returns the sample points.
+    pub fn points(&self) -> &[[f64; D]] {
+        &self.points
+    }
+
+    /// Returns `true` if the curve is closed.
+    pub fn is_closed(&self) -> bool {
+        self.closed
+    }
+
+    /// Returns the number of segments.
+    pub fn segment_count(&self) -> usize {
+        match self.points.len() {
+            0 => 0,
+            1 => 1,
+            n if self.closed => n,
+            n => n - 1,
+        }
+    }
+
+    /// Returns the total length of the segments.
+    pub fn length(&self) -> f64 {
+        (0..self.segment_count()).map(|i| distance_sq(&self.point(i), &self.point(i + 1)).sqrt()).sum()
+    }
+
+    /// Returns the closest point on the curve to `point`, or `None` if the curve has no points.
+    pub fn closest(&self, point: &[f64; D]) -> Option<CurvePoint<D>> {
+        let mut bounds: Vec<(f64, usize)> = self
+            .chunks
+            .iter()
+            .enumerate()
+            .map(|(c, chunk)| ((distance_sq(point, &chunk.center).sqrt() - chunk.radius).max(0.0), c))
+            .collect();
+        bounds.sort_unstable_by(|a, b| a.0.total_cmp(&b.0));
+
+        let mut best: Option<(f64, CurvePoint<D>)> = None;
+        for (bound, c) in bounds {
+            if best.as_ref().is_some_and(|(d2, _)| bound * bound > *d2) {
+                break;
+            }
+            for segment in self.chunks[c].segments.clone() {
+                let (p0, p1) = (self.point(segment), self.point(segment + 1));
+                let v: [f64; D] = std::array::from_fn(|k| p1[k] - p0[k]);
+                let c1: f64 = (0..D).map(|k| (point[k] - p0[k]) * v[k]).sum();
+                let c2: f64 = v.iter().map(|x| x * x).sum();
+                let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
+                let proj: [f64; D] = std::array::from_fn(|k| p0[k] + v[k] * t);
+                let d2 = distance_sq(point, &proj);
+                if best.as_ref().is_none_or(|(best_d2, _)| d2 < *best_d2) {
+                    best = Some((d2, CurvePoint { point: proj, segment, t, distance: d2.sqrt() }));
+                }
+            }
+        }
+        best.map(|(_, closest)| closest)
+    }
+
+    /// Returns `true` if `point` is within `radius` of the curve.
+    pub fn tube_contains(&self, point: &[f64; D], radius: f64) -> bool {
+        self.closest(point).is_some_and(|c| c.distance <= radius)
+    }
+
+    /// Reports the tangent plane of the tube of `radius` around the curve at the surface point
+    /// closest to `generator`, with the normal pointing away from the curve, as in
+    /// [`WallGeometry::cut`]. Nothing is reported for a generator on the curve.
+    pub fn tube_cut(&self, generator: &[f64; D], radius: f64, callback: &mut dyn FnMut([f64; D], [f64; D])) {
+        let Some(closest) = self.closest(generator) else { return };
+        if closest.distance == 0.0 {
+            return;
+        }
+        let normal: [f64; D] = std::array::from_fn(|k| (generator[k] - closest.point[k]) / closest.distance);
+        callback(std::array::from_fn(|k| closest.point[k] + normal[k] * radius), normal);
+    }
+
+    /// Returns the distance from `point` to the surface of the tube of `radius` around the curve,
+    /// positive inside the tube, or `None` if the curve has no points.
+    pub fn tube_signed_distance(&self, point: &[f64; D], radius: f64) -> Option<f64> {
+        self.closest(point).map(|c| radius - c.distance)
+    }
+
+    /// Returns the start of a segment, or the end of the previous one.
+    fn point(&self, i: usize) -> [f64; D] {
+        self.points[i % self.points.len()]
+    }
+}
+
+/// A wall defined by a tube around a [`SegmentCurve`], in 2D a band of constant thickness.
+///
+/// The valid region is inside the tube.
+#[derive(Clone, Debug)]
+pub struct TubeGeometry<const D: usize> {
+    /// The center line of the tube.
+    pub curve: SegmentCurve<D>,
+    /// The radius of the tube.
+    pub radius: f64,
+}
+
+impl<const D: usize> TubeGeometry<D> {
+    /// Creates a new `TubeGeometry` around a curve.
+    pub fn new(curve: SegmentCurve<D>, radius: f64) -> Self {
+        Self { curve, radius }
+    }
+}
+
+impl<const D: usize> WallGeometry<D> for TubeGeometry<D> {
+    fn contains(&self, point: &[f64; D]) -> bool {
+        self.curve.tube_contains(point, self.radius)
+    }
+
+    fn cut(&self, generator: &[f64; D], callback: &mut dyn FnMut([f64; D], [f64; D])) {
+        self.curve.tube_cut(generator, self.radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; D]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.radius)
+    }
+}
+
+fn distance_sq<const D: usize>(a: &[f64; D], b: &[f64; D]) -> f64 {
+    (0..D).map(|k| (a[k] - b[k]) * (a[k] - b[k])).sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_segment_curve() {
+        // The accelerated query agrees with a brute force search over all segments.
+        let curve = SegmentCurve::from_fn(|t| {
+            let a = std::f64::consts::TAU * t;
+            [a.sin() + 2.0 * (2.0 * a).sin(), a.cos() - 2.0 * (2.0 * a).cos(), -(3.0 * a).sin()]
+        }, 300, true);
+        assert_eq!(curve.segment_count(), 300);
+        for i in 0..50 {
+            let x = i as f64;
+            let p = [(x * 0.37).sin() * 4.0, (x * 0.71).cos() * 4.0, (x * 0.13).sin() * 2.0];
+            let brute = (0..300)
+                .map(|s| {
+                    let (a, b) = (curve.point(s), curve.point(s + 1));
+                    let v: [f64; 3] = std::array::from_fn(|k| b[k] - a[k]);
+                    let t = ((0..3).map(|k| (p[k] - a[k]) * v[k]).sum::<f64>() / distance_sq(&a, &b)).clamp(0.0, 1.0);
+                    distance_sq(&p, &std::array::from_fn(|k| a[k] + t * v[k])).sqrt()
+                })
+                .fold(f64::INFINITY, f64::min);
+            assert!((curve.closest(&p).unwrap().distance - brute).abs() < 1e-12);
+        }
+
+        // An open polyline in 2D, with the tube cut at the closest surface point.
+        let line = SegmentCurve::new(vec![[0.0, 0.0], [2.0, 0.0], [2.0, 2.0]], false);
+        assert_eq!(line.length(), 4.0);
+        let closest = line.closest(&[1.0, 0.5]).unwrap();
+        assert_eq!((closest.point, closest.segment, closest.t, closest.distance), ([1.0, 0.0], 0, 0.5, 0.5));
+        let tube = TubeGeometry::new(line, 0.25);
+        assert!(!tube.contains(&[1.0, 0.5]));
+        assert_eq!(tube.signed_distance(&[1.0, 0.5]), Some(-0.25));
+        let mut planes = Vec::new();
+        tube.cut(&[1.0, 0.5], &mut |p, n| planes.push((p, n)));
+        assert_eq!(planes, vec![([1.0, 0.25], [0.0, 1.0])]);
+        assert!(SegmentCurve::<2>::new(Vec::new(), true).closest(&[0.0, 0.0]).is_none());
+    }
+
+    #[test]
+    fn test_curve_sampling() {
+        let segment_lengths = |curve: &SegmentCurve<2>| -> Vec<f64> {
+            (0..curve.segment_count()).map(|i| distance_sq(&curve.point(i), &curve.point(i + 1)).sqrt()).collect()
+        };
+
+        // A straight line traversed at increasing speed gets equal segments by arc length.
+        let accelerating = |t: f64| [10.0 * t * t * t, 0.0];
+        let uniform = SegmentCurve::from_fn(accelerating, 20, false);
+        let even = SegmentCurve::from_fn_sampled(accelerating, 20, false, CurveSampling::ArcLength);
+        assert_eq!(even.points().len(), 21);
+        assert_eq!((even.points()[0], even.points()[20]), ([0.0, 0.0], [10.0, 0.0]));
+        assert!(segment_lengths(&uniform)[0] < 0.01);
+        assert!(segment_lengths(&even).iter().all(|l| (l - 0.5).abs() < 0.01));
+
+        // A parabola gets shorter segments at its tip, and the uniform parameters by default.
+        let parabola = |t: f64| [2.0 * t - 1.0, 4.0 * (2.0 * t - 1.0).powi(2)];
+        let adaptive = SegmentCurve::from_fn_sampled(parabola, 20, false, CurveSampling::Curvature);
+        let lengths = segment_lengths(&adaptive);
+        assert!(lengths[10] < 0.5 * lengths[0]);
+        assert_eq!(CurveSampling::default().parameters(&parabola, 4, true), vec![0.0, 0.25, 0.5, 0.75]);
+    }
+}
@@ -1,6 +1,13 @@
+use crate::bounds::BoundingBox;
+use crate::error::WallError;
+
+pub mod curve;
+mod preview;
 pub mod wall_2d;
 pub mod wall_3d;
 
+pub use preview::WallMesh;
+
 /// The maximum ID for walls. Wall IDs must be less than or equal to this value
 /// to avoid conflicts with non-negative generator IDs and the bounding box IDs.
 /// The number of D-1 dimensional faces of a hypercube is 2*D so with walls
@@ -21,14 +28,27 @@ pub struct Wall<const D: usize> {
 
 impl<const D: usize> Wall<D> {
     /// Creates a new `Wall` from a Rust struct that implements the `WallGeometry` trait.
+    ///
+    /// # Panics
+    /// Panics if `id` is greater than [`WALL_ID_MAX`], see [`Wall::try_new`] for a fallible version.
+    /// Not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
     pub fn new(id: i32, geometry: Box<dyn WallGeometry<D>>) -> Self {
+        match Self::try_new(id, geometry) {
+            Ok(wall) => wall,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a new `Wall`, returning an error if `id` is greater than [`WALL_ID_MAX`].
+    pub fn try_new(id: i32, geometry: Box<dyn WallGeometry<D>>) -> Result<Self, WallError> {
         if id > WALL_ID_MAX {
-            panic!("Wall ID must be <= {}", WALL_ID_MAX);
+            return Err(WallError::InvalidId(id));
         }
-        Self {
+        Ok(Self {
             id,
             inner: geometry,
-        }
+        })
     }
 
     pub fn id(&self) -> i32 {
@@ -46,6 +66,27 @@ impl<const D: usize> Wall<D> {
     pub fn is_planar(&self) -> bool {
         self.inner.is_planar()
     }
+
+    pub fn curvature(&self, point: &[f64; D]) -> Option<[[f64; D]; D]> {
+        self.inner.curvature(point)
+    }
+
+    pub fn signed_distance(&self, point: &[f64; D]) -> Option<f64> {
+        self.inner.signed_distance(point)
+    }
+
+    pub fn volume_in(&self, bounds: &BoundingBox<D>) -> Option<f64> {
+        self.inner.volume_in(bounds)
+    }
+
+    pub fn preview_mesh(&self, bounds: &BoundingBox<D>, resolution: usize) -> WallMesh<D> {
+        self.inner.preview_mesh(bounds, resolution)
+    }
+}
+
+/// Returns whether the ball of `radius` around `center` lies within `bounds`.
+fn ball_within<const D: usize>(bounds: &BoundingBox<D>, center: &[f64; D], radius: f64) -> bool {
+    (0..D).all(|k| center[k] - radius >= bounds.min[k] && center[k] + radius <= bounds.max[k])
 }
 
 /// Trait defining the geometry and logic of a wall.
@@ -68,4 +109,58 @@ pub trait WallGeometry<const D: usize>: Send + Sync + std::fmt::Debug {
     fn is_planar(&self) -> bool {
         false
     }
-}
\ No newline at end of file
+
+    /// Returns the curvature of the wall at a `point` on its surface, as returned by `cut`,
+    /// in the form of the second fundamental form: the surface deviates from its tangent plane by
+    /// `0.5 * d^T S d` towards the valid region for a small offset `d` within the plane.
+    /// The curvature is positive where the valid region is convex.
+    ///
+    /// This is used to estimate the volume error of the single-plane approximation, see
+    /// [`crate::Tessellation::volume_corrections`]. The default implementation returns `None`,
+    /// which means no correction is estimated.
+    fn curvature(&self, _point: &[f64; D]) -> Option<[[f64; D]; D]> {
+        None
+    }
+
+    /// Returns the distance from a `point` to the wall, positive inside the valid region and
+    /// negative outside, or `None` if it cannot be determined.
+    ///
+    /// The default implementation takes the smallest distance to the planes returned by `cut`
+    /// for the point. This is exact for planar walls and for curved walls whose cut is the tangent
+    /// plane at the closest surface point, such as spheres and cylinders.
+    fn signed_distance(&self, point: &[f64; D]) -> Option<f64> {
+        let mut distance: Option<f64> = None;
+        self.cut(point, &mut |plane_point, normal| {
+            let length = normal.iter().map(|v| v * v).sum::<f64>().sqrt();
+            if length > 0.0 {
+                let d = (0..D).map(|k| normal[k] * (plane_point[k] - point[k])).sum::<f64>() / length;
+                distance = Some(distance.map_or(d, |m| m.min(d)));
+            }
+        });
+        distance
+    }
+
+    /// Returns the volume of the valid region within `bounds`, the area in 2D, if it is known in
+    /// closed form, see [`crate::Tessellation::domain_volume`].
+    ///
+    /// The default implementation returns `None`, which means the volume is estimated by sampling
+    /// [`WallGeometry::contains`]. Implementations may also return `None` for positions where the
+    /// formula does not apply, such as a sphere crossing the bounding box.
+    fn volume_in(&self, _bounds: &BoundingBox<D>) -> Option<f64> {
+        None
+    }
+
+    /// Returns a mesh of the wall surface within `bounds` for rendering the wall along with the
+    /// cells: triangles in 3D and line segments in 2D, with `resolution` grid cells along each axis.
+    ///
+    /// The default implementation extracts the surface where [`WallGeometry::signed_distance`]
+    /// changes its sign, falling back to [`WallGeometry::contains`] where the distance is unknown,
+    /// by marching tetrahedra over a grid. Features smaller than a grid cell may be missed, and
+    /// without a distance the vertices are only accurate to the grid spacing.
+    fn preview_mesh(&self, bounds: &BoundingBox<D>, resolution: usize) -> WallMesh<D> {
+        let field = |point: &[f64; D]| {
+            self.signed_distance(point).unwrap_or_else(|| if self.contains(point) { 1.0 } else { -1.0 })
+        };
+        preview::marching_simplices(&field, bounds, resolution)
+    }
+}
@@ -0,0 +1,176 @@
+use crate::bounds::BoundingBox;
+use std::collections::HashMap;
+
+/// A mesh approximating the surface of a wall for rendering, as returned by
+/// [`WallGeometry::preview_mesh`](crate::WallGeometry::preview_mesh): triangles in 3D and line
+/// segments in 2D.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct WallMesh<const D: usize> {
+    /// The vertex coordinates as a flat array `[x, y, (z), ...]`.
+    pub vertices: Vec<f64>,
+    /// The triangles (segments in 2D) as indices into the vertices, oriented so that their
+    /// normals point out of the valid region, like the normals of [`crate::Wall::cut`].
+    pub elements: Vec<[usize; D]>,
+}
+
+/// Extracts the surface where `field` changes its sign within `bounds`, with `resolution` grid
+/// cells along each axis, by marching tetrahedra in 3D and triangles in 2D. Each grid cell is split
+/// into simplices along its diagonal, so the surface is linear within each simplex and the
+/// simplices of neighboring grid cells share their faces, which keeps the mesh watertight.
+/// The field is positive in the valid region. Other dimensions give an empty mesh.
+pub(crate) fn marching_simplices<const D: usize>(
+    field: &dyn Fn(&[f64; D]) -> f64,
+    bounds: &BoundingBox<D>,
+    resolution: usize,
+) -> WallMesh<D> {
+    let mut mesh = WallMesh::default();
+    if D != 2 && D != 3 {
+        return mesh;
+    }
+    let n = resolution.max(1);
+    let point = |index: [usize; D]| -> [f64; D] {
+        std::array::from_fn(|k| bounds.min[k] + (bounds.max[k] - bounds.min[k]) * index[k] as f64 / n as f64)
+    };
+    let digits = |mut linear: usize, base: usize| -> [usize; D] {
+        std::array::from_fn(|_| {
+            let digit = linear % base;
+            linear /= base;
+            digit
+        })
+    };
+    let linear = |index: &[usize; D]| index.iter().rev().fold(0, |acc, &i| acc * (n + 1) + i);
+    let values: Vec<f64> = (0..(n + 1).pow(D as u32)).map(|l| field(&point(digits(l, n + 1)))).collect();
+
+    let mut crossings: HashMap<(usize, usize), usize> = HashMap::new();
+    let mut crossing = |mesh: &mut WallMesh<D>, a: &[usize; D], b: &[usize; D]| -> usize {
+        let (ia, ib) = (linear(a), linear(b));
+        *crossings.entry((ia.min(ib), ia.max(ib))).or_insert_with(|| {
+            let t = (values[ia] / (values[ia] - values[ib])).clamp(0.0, 1.0);
+            let (pa, pb) = (point(*a), point(*b));
+            mesh.vertices.extend((0..D).map(|k| pa[k] + t * (pb[k] - pa[k])));
+            mesh.vertices.len() / D - 1
+        })
+    };
+
+    let permutations = permutations(D);
+    for cube in 0..n.pow(D as u32) {
+        let origin = digits(cube, n);
+        for permutation in &permutations {
+            // The simplex walks from the origin of the grid cell to the opposite corner.
+            let mut corners = vec![origin];
+            for &axis in permutation {
+                let mut next = corners[corners.len() - 1];
+                next[axis] += 1;
+                corners.push(next);
+            }
+            let (inside, outside): (Vec<[usize; D]>, Vec<[usize; D]>) =
+                corners.iter().partition(|c| values[linear(c)] > 0.0);
+            if inside.is_empty() || outside.is_empty() {
+                continue;
+            }
+
+            // The direction out of the valid region, to orient the elements.
+            let centroid = |corners: &[[usize; D]]| -> [f64; D] {
+                std::array::from_fn(|k| corners.iter().map(|&c| point(c)[k]).sum::<f64>() / corners.len() as f64)
+            };
+            let (inner, outer) = (centroid(&inside), centroid(&outside));
+            let out: [f64; D] = std::array::from_fn(|k| outer[k] - inner[k]);
+
+            let polygon: Vec<usize> = if inside.len() == 2 && outside.len() == 2 {
+                // A quadrilateral in 3D, ordered around its edges.
+                vec![
+                    crossing(&mut mesh, &inside[0], &outside[0]),
+                    crossing(&mut mesh, &inside[0], &outside[1]),
+                    crossing(&mut mesh, &inside[1], &outside[1]),
+                    crossing(&mut mesh, &inside[1], &outside[0]),
+                ]
+            } else {
+                let mut polygon = Vec::with_capacity(D);
+                for a in &inside {
+                    for b in &outside {
+                        polygon.push(crossing(&mut mesh, a, b));
+                    }
+                }
+                polygon
+            };
+            for k in 1..polygon.len() + 2 - D {
+                let mut element: [usize; D] = std::array::from_fn(|m| if m == 0 { polygon[0] } else { polygon[k + m - 1] });
+                if orientation(&mesh.vertices, &element, &out) < 0.0 {
+                    element.swap(D - 2, D - 1);
+                }
+                mesh.elements.push(element);
+            }
+        }
+    }
+    mesh
+}
+
+/// Returns the dot product of the normal of an element with `direction`.
+fn orientation<const D: usize>(vertices: &[f64], element: &[usize; D], direction: &[f64; D]) -> f64 {
+    let p = |v: usize| &vertices[v * D..(v + 1) * D];
+    match D {
+        2 => {
+            let (a, b) = (p(element[0]), p(element[1]));
+            (b[1] - a[1]) * direction[0] - (b[0] - a[0]) * direction[1]
+        }
+        3 => {
+            let (a, b, c) = (p(element[0]), p(element[1]), p(element[2]));
+            let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+            let n = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+            n[0] * direction[0] + n[1] * direction[1] + n[2] * direction[2]
+        }
+        _ => 0.0,
+    }
+}
+
+/// Returns all orderings of the axes `0..d`.
+fn permutations(d: usize) -> Vec<Vec<usize>> {
+    if d == 0 {
+        return vec![Vec::new()];
+    }
+    let mut result = Vec::new();
+    for shorter in permutations(d - 1) {
+        for position in 0..=shorter.len() {
+            let mut permutation = shorter.clone();
+            permutation.insert(position, d - 1);
+            result.push(permutation);
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_marching_simplices() {
+        // A circle of radius 1 gives a closed polyline with its vertices on the circle.
+        let bounds = BoundingBox::new([-2.0, -2.0], [2.0, 2.0]);
+        let circle = |p: &[f64; 2]| 1.0 - (p[0] * p[0] + p[1] * p[1]).sqrt();
+        let mesh = marching_simplices(&circle, &bounds, 16);
+        assert!(mesh.vertices.chunks_exact(2).all(|v| ((v[0] * v[0] + v[1] * v[1]).sqrt() - 1.0).abs() < 0.05));
+        let mut degree = vec![0; mesh.vertices.len() / 2];
+        mesh.elements.iter().flatten().for_each(|&v| degree[v] += 1);
+        assert!(degree.iter().all(|&d| d == 2));
+
+        // The triangles of a sphere form a closed surface with outward normals,
+        // which enclose nearly the volume of the sphere.
+        let bounds = BoundingBox::new([-2.0, -2.0, -2.0], [2.0, 2.0, 2.0]);
+        let sphere = |p: &[f64; 3]| 1.0 - (p[0] * p[0] + p[1] * p[1] + p[2] * p[2]).sqrt();
+        let mesh = marching_simplices(&sphere, &bounds, 20);
+        let p = |v: usize| &mesh.vertices[v * 3..v * 3 + 3];
+        let volume: f64 = mesh
+            .elements
+            .iter()
+            .map(|&[a, b, c]| {
+                let (a, b, c) = (p(a), p(b), p(c));
+                a[0] * (b[1] * c[2] - b[2] * c[1]) + a[1] * (b[2] * c[0] - b[0] * c[2]) + a[2] * (b[0] * c[1] - b[1] * c[0])
+            })
+            .sum::<f64>()
+            / 6.0;
+        let exact = 4.0 / 3.0 * std::f64::consts::PI;
+        assert!((volume - exact).abs() / exact < 0.05, "{volume}");
+        assert!(marching_simplices(&|_: &[f64; 3]| 1.0, &bounds, 4).elements.is_empty());
+    }
+}
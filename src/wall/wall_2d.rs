@@ -1,4 +1,7 @@
-use super::WallGeometry;
+use super::{WallGeometry, ball_within};
+use crate::bounds::BoundingBox;
+use super::curve::{CurveSampling, SegmentCurve};
+use crate::error::WallError;
 
 /// A wall defined by a line in 2D.
 ///
@@ -75,6 +78,14 @@ impl WallGeometry<2> for CircleGeometry {
 
         callback([px, py], [nx, ny]);
     }
+
+    fn curvature(&self, point: &[f64; 2]) -> Option<[[f64; 2]; 2]> {
+        circle_curvature(&self.center, self.radius, point)
+    }
+
+    fn volume_in(&self, bounds: &BoundingBox<2>) -> Option<f64> {
+        ball_within(bounds, &self.center, self.radius).then_some(std::f64::consts::PI * self.radius * self.radius)
+    }
 }
 
 /// A wall defined by a convex polygon in 2D.
@@ -84,7 +95,26 @@ pub struct ConvexPolygonGeometry2D {
 }
 
 impl ConvexPolygonGeometry2D {
+    /// Creates a new convex polygon from flat arrays of points on the edges and their outward normals.
+    ///
+    /// # Panics
+    /// Panics if the arrays differ in length or are not a multiple of 2,
+    /// see [`ConvexPolygonGeometry2D::try_new`] for a fallible version.
+    /// Not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
     pub fn new(points: &[f64], normals: &[f64]) -> Self {
+        match Self::try_new(points, normals) {
+            Ok(geometry) => geometry,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a new convex polygon, returning an error if the points and normals
+    /// differ in length or are not a multiple of 2.
+    pub fn try_new(points: &[f64], normals: &[f64]) -> Result<Self, WallError> {
+        if points.len() != normals.len() || !points.len().is_multiple_of(2) {
+            return Err(WallError::InvalidGeometry("Points and normals must have same length and be multiple of 2"));
+        }
         let count = points.len() / 2;
         let mut lines = Vec::with_capacity(count);
         for i in 0..count {
@@ -93,7 +123,7 @@ impl ConvexPolygonGeometry2D {
                 [normals[i*2], normals[i*2+1]]
             ));
         }
-        Self { lines }
+        Ok(Self { lines })
     }
     
     pub fn new_regular(center: [f64; 2], radius: f64, sides: usize) -> Self {
@@ -183,24 +213,55 @@ impl WallGeometry<2> for AnnulusGeometry {
         // Normal points OUT of valid region (towards center)
         callback([p_inner_x, p_inner_y], [-dir_x, -dir_y]);
     }
+
+    fn curvature(&self, point: &[f64; 2]) -> Option<[[f64; 2]; 2]> {
+        let dist = ((point[0] - self.center[0]).powi(2) + (point[1] - self.center[1]).powi(2)).sqrt();
+        // The inner circle bounds the valid region from outside, so it curves the other way.
+        if (dist - self.inner_radius).abs() < (dist - self.outer_radius).abs() {
+            circle_curvature(&self.center, self.inner_radius, point).map(|s| s.map(|row| row.map(|v| -v)))
+        } else {
+            circle_curvature(&self.center, self.outer_radius, point)
+        }
+    }
+
+    fn volume_in(&self, bounds: &BoundingBox<2>) -> Option<f64> {
+        let (inner, outer) = (self.inner_radius.max(0.0), self.outer_radius);
+        (inner <= outer && ball_within(bounds, &self.center, outer))
+            .then_some(std::f64::consts::PI * (outer * outer - inner * inner))
+    }
+}
+
+/// The curvature of a circle at a point on it, as seen from inside the circle.
+fn circle_curvature(center: &[f64; 2], radius: f64, point: &[f64; 2]) -> Option<[[f64; 2]; 2]> {
+    let dx = point[0] - center[0];
+    let dy = point[1] - center[1];
+    let dist = (dx * dx + dy * dy).sqrt();
+    if dist == 0.0 { return None; }
+
+    // The projection onto the tangent, scaled by the inverse radius.
+    let (tx, ty) = (-dy / dist, dx / dist);
+    Some([[tx * tx, tx * ty], [ty * tx, ty * ty]].map(|row| row.map(|v| v / radius)))
 }
 
 /// A wall defined by a cubic bezier curve with thickness in 2D.
 #[derive(Debug)]
 pub struct CubicBezierGeometry2D {
-    pub samples: Vec<[f64; 2]>,
+    pub curve: SegmentCurve<2>,
     pub radius: f64,
-    pub closed: bool,
 }
 
 impl CubicBezierGeometry2D {
     pub fn new(p0: [f64; 2], p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], radius: f64, resolution: usize, closed: bool) -> Self {
-        let mut samples = Vec::with_capacity(resolution + 1);
-        for i in 0..=resolution {
-            let t = i as f64 / resolution as f64;
-            samples.push(Self::calculate_point(p0, p1, p2, p3, t));
-        }
-        Self { samples, radius, closed }
+        Self::with_sampling(p0, p1, p2, p3, radius, resolution, closed, CurveSampling::Parameter)
+    }
+
+    /// Creates a new `CubicBezierGeometry2D` with the sample points placed by `sampling`,
+    /// for example at equal arc length.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_sampling(p0: [f64; 2], p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], radius: f64, resolution: usize, closed: bool, sampling: CurveSampling) -> Self {
+        let point = |t| Self::calculate_point(p0, p1, p2, p3, t);
+        let samples = sampling.parameters(&point, resolution, false).into_iter().map(point).collect();
+        Self { curve: SegmentCurve::new(samples, closed), radius }
     }
     
     fn calculate_point(p0: [f64; 2], p1: [f64; 2], p2: [f64; 2], p3: [f64; 2], t: f64) -> [f64; 2] {
@@ -215,61 +276,19 @@ impl CubicBezierGeometry2D {
             mt3 * p0[1] + 3.0 * mt2 * t * p1[1] + 3.0 * mt * t2 * p2[1] + t3 * p3[1],
         ]
     }
-    
-    fn get_closest_point(&self, point: &[f64; 2]) -> [f64; 2] {
-        if self.samples.is_empty() { return [0.0, 0.0]; }
-        let mut min_dist_sq = f64::MAX;
-        let mut closest_pt = self.samples[0];
-        let n = self.samples.len();
-        let limit = if self.closed { n } else { n - 1 };
-        
-        for i in 0..limit {
-            let p0 = self.samples[i];
-            let p1 = self.samples[(i + 1) % n];
-            
-            let v = [p1[0] - p0[0], p1[1] - p0[1]];
-            let w = [point[0] - p0[0], point[1] - p0[1]];
-            
-            let c1 = w[0]*v[0] + w[1]*v[1];
-            let c2 = v[0]*v[0] + v[1]*v[1];
-            let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
-            
-            let proj = [p0[0] + v[0] * t, p0[1] + v[1] * t];
-            let dx = point[0] - proj[0];
-            let dy = point[1] - proj[1];
-            let d2 = dx*dx + dy*dy;
-            
-            if d2 < min_dist_sq {
-                min_dist_sq = d2;
-                closest_pt = proj;
-            }
-        }
-        closest_pt
-    }
 }
 
 impl WallGeometry<2> for CubicBezierGeometry2D {
     fn contains(&self, point: &[f64; 2]) -> bool {
-        let closest = self.get_closest_point(point);
-        let dist_sq = (point[0] - closest[0]).powi(2) + (point[1] - closest[1]).powi(2);
-        dist_sq <= self.radius.powi(2)
+        self.curve.tube_contains(point, self.radius)
     }
 
     fn cut(&self, generator: &[f64; 2], callback: &mut dyn FnMut([f64; 2], [f64; 2])) {
-        let closest = self.get_closest_point(generator);
-        let dx = generator[0] - closest[0];
-        let dy = generator[1] - closest[1];
-        let dist = (dx*dx + dy*dy).sqrt();
-        
-        if dist == 0.0 { return; }
-        
-        let nx = dx / dist;
-        let ny = dy / dist;
-        
-        let px = closest[0] + nx * self.radius;
-        let py = closest[1] + ny * self.radius;
-        
-        callback([px, py], [nx, ny]);
+        self.curve.tube_cut(generator, self.radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; 2]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.radius)
     }
 }
 
@@ -278,24 +297,26 @@ impl WallGeometry<2> for CubicBezierGeometry2D {
 /// The valid region is inside the tube following the curve.
 #[derive(Debug)]
 pub struct CatmullRomGeometry2D {
-    /// Sample points along the curve.
-    pub samples: Vec<[f64; 2]>,
+    /// The sampled curve, which is closed if the tube loops back to its start.
+    pub curve: SegmentCurve<2>,
     /// The radius (thickness) of the tube.
     pub radius: f64,
-    /// Whether the tube is closed (loops back to start).
-    pub closed: bool,
 }
 
 impl CatmullRomGeometry2D {
     pub fn new(points: Vec<[f64; 2]>, radius: f64, resolution: usize, closed: bool) -> Self {
-        let mut samples = Vec::with_capacity(resolution + 1);
+        Self::with_sampling(points, radius, resolution, closed, CurveSampling::Parameter)
+    }
+
+    /// Creates a new `CatmullRomGeometry2D` with the sample points placed by `sampling`,
+    /// for example at equal arc length.
+    pub fn with_sampling(points: Vec<[f64; 2]>, radius: f64, resolution: usize, closed: bool, sampling: CurveSampling) -> Self {
+        let mut samples = Vec::new();
         if points.len() >= 2 {
-            for i in 0..=resolution {
-                let t = i as f64 / resolution as f64;
-                samples.push(Self::get_point(t, &points, closed));
-            }
+            let point = |t| Self::get_point(t, &points, closed);
+            samples = sampling.parameters(&point, resolution, false).into_iter().map(point).collect();
         }
-        Self { samples, radius, closed }
+        Self { curve: SegmentCurve::new(samples, closed), radius }
     }
 
     fn get_point(t: f64, points: &[[f64; 2]], closed: bool) -> [f64; 2] {
@@ -314,33 +335,28 @@ impl CatmullRomGeometry2D {
             int_point = l as isize - 2;
         }
 
-        let p0;
-        let p1;
-        let p2;
-        let p3;
-
-        if closed || int_point > 0 {
-             p0 = points[((int_point - 1) % l as isize + l as isize) as usize % l];
+        let p0 = if closed || int_point > 0 {
+             points[((int_point - 1) % l as isize + l as isize) as usize % l]
         } else {
-             p0 = [
+             [
                  points[0][0] - (points[1][0] - points[0][0]),
                  points[0][1] - (points[1][1] - points[0][1]),
-             ];
-        }
+             ]
+        };
 
-        p1 = points[int_point as usize % l];
-        p2 = points[(int_point + 1) as usize % l];
+        let p1 = points[int_point as usize % l];
+        let p2 = points[(int_point + 1) as usize % l];
 
-        if closed || int_point + 2 < l as isize {
-            p3 = points[(int_point + 2) as usize % l];
+        let p3 = if closed || int_point + 2 < l as isize {
+            points[(int_point + 2) as usize % l]
         } else {
             let last = points[l-1];
             let prev = points[l-2];
-            p3 = [
+            [
                 last[0] - (prev[0] - last[0]),
                 last[1] - (prev[1] - last[1]),
-            ];
-        }
+            ]
+        };
 
         let pow = 0.25;
         let mut dt0 = dist_sq_2d(p0, p1).powf(pow);
@@ -359,61 +375,19 @@ impl CatmullRomGeometry2D {
             py.calc(weight),
         ]
     }
-
-    fn get_closest_point(&self, point: &[f64; 2]) -> [f64; 2] {
-        if self.samples.is_empty() { return [0.0, 0.0]; }
-        let mut min_dist_sq = f64::MAX;
-        let mut closest_pt = self.samples[0];
-        let n = self.samples.len();
-        let limit = if self.closed { n } else { n - 1 };
-        
-        for i in 0..limit {
-            let p0 = self.samples[i];
-            let p1 = self.samples[(i + 1) % n];
-            
-            let v = [p1[0] - p0[0], p1[1] - p0[1]];
-            let w = [point[0] - p0[0], point[1] - p0[1]];
-            
-            let c1 = w[0]*v[0] + w[1]*v[1];
-            let c2 = v[0]*v[0] + v[1]*v[1];
-            let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
-            
-            let proj = [p0[0] + v[0] * t, p0[1] + v[1] * t];
-            let dx = point[0] - proj[0];
-            let dy = point[1] - proj[1];
-            let d2 = dx*dx + dy*dy;
-            
-            if d2 < min_dist_sq {
-                min_dist_sq = d2;
-                closest_pt = proj;
-            }
-        }
-        closest_pt
-    }
 }
 
 impl WallGeometry<2> for CatmullRomGeometry2D {
     fn contains(&self, point: &[f64; 2]) -> bool {
-        let closest = self.get_closest_point(point);
-        let dist_sq = (point[0] - closest[0]).powi(2) + (point[1] - closest[1]).powi(2);
-        dist_sq <= self.radius.powi(2)
+        self.curve.tube_contains(point, self.radius)
     }
 
     fn cut(&self, generator: &[f64; 2], callback: &mut dyn FnMut([f64; 2], [f64; 2])) {
-        let closest = self.get_closest_point(generator);
-        let dx = generator[0] - closest[0];
-        let dy = generator[1] - closest[1];
-        let dist = (dx*dx + dy*dy).sqrt();
-        
-        if dist == 0.0 { return; }
-        
-        let nx = dx / dist;
-        let ny = dy / dist;
-        
-        let px = closest[0] + nx * self.radius;
-        let py = closest[1] + ny * self.radius;
-        
-        callback([px, py], [nx, ny]);
+        self.curve.tube_cut(generator, self.radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; 2]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.radius)
     }
 }
 
@@ -1,4 +1,9 @@
-use super::WallGeometry;
+use super::{WallGeometry, ball_within};
+use crate::bounds::BoundingBox;
+use crate::cell::Cell;
+use crate::cell::cell_3d_faces::Cell3DFaces;
+use super::curve::{CurveSampling, SegmentCurve};
+use crate::error::WallError;
 
 /// A wall defined by a plane.
 ///
@@ -19,7 +24,7 @@ impl PlaneGeometry {
     ///
     /// * `point` - A point on the plane.
     /// * `normal` - The normal vector of the plane, pointing towards the valid region.
-    ///              It will be normalized.
+    ///   It will be normalized.
     pub fn new(point: [f64; 3], normal: [f64; 3]) -> Self {
         let len = (normal[0] * normal[0] + normal[1] * normal[1] + normal[2] * normal[2]).sqrt();
         let n = if len == 0.0 { [0.0, 0.0, 1.0] } else { [normal[0] / len, normal[1] / len, normal[2] / len] };
@@ -98,6 +103,22 @@ impl WallGeometry<3> for SphereGeometry {
 
         callback([px, py, pz], [nx, ny, nz]);
     }
+
+    fn curvature(&self, point: &[f64; 3]) -> Option<[[f64; 3]; 3]> {
+        let d = [0, 1, 2].map(|k| point[k] - self.center[k]);
+        let dist = (d[0] * d[0] + d[1] * d[1] + d[2] * d[2]).sqrt();
+        if dist == 0.0 { return None; }
+        let n = d.map(|v| v / dist);
+
+        // The projection onto the tangent plane, scaled by the inverse radius.
+        Some(std::array::from_fn(|i| std::array::from_fn(|j| {
+            (if i == j { 1.0 } else { 0.0 } - n[i] * n[j]) / self.radius
+        })))
+    }
+
+    fn volume_in(&self, bounds: &BoundingBox<3>) -> Option<f64> {
+        ball_within(bounds, &self.center, self.radius).then(|| 4.0 / 3.0 * std::f64::consts::PI * self.radius.powi(3))
+    }
 }
 
 /// A wall defined by an infinite cylinder.
@@ -168,6 +189,32 @@ impl WallGeometry<3> for CylinderGeometry {
 
         callback([px, py, pz], [nx, ny, nz]);
     }
+
+    fn curvature(&self, point: &[f64; 3]) -> Option<[[f64; 3]; 3]> {
+        let d = [0, 1, 2].map(|k| point[k] - self.center[k]);
+        let dot = d[0] * self.axis[0] + d[1] * self.axis[1] + d[2] * self.axis[2];
+        let perp = [0, 1, 2].map(|k| d[k] - dot * self.axis[k]);
+        let dist = (perp[0] * perp[0] + perp[1] * perp[1] + perp[2] * perp[2]).sqrt();
+        if dist == 0.0 { return None; }
+        let n = perp.map(|v| v / dist);
+
+        // The surface only curves around the axis, along the remaining tangent direction.
+        let a = self.axis;
+        Some(std::array::from_fn(|i| std::array::from_fn(|j| {
+            (if i == j { 1.0 } else { 0.0 } - n[i] * n[j] - a[i] * a[j]) / self.radius
+        })))
+    }
+
+    fn volume_in(&self, bounds: &BoundingBox<3>) -> Option<f64> {
+        // Only a cylinder along a box axis has a constant cross section within the box.
+        let axis = (0..3).find(|&k| self.axis[k].abs() == 1.0)?;
+        let mut center = self.center;
+        center[axis] = 0.5 * (bounds.min[axis] + bounds.max[axis]);
+        let contained = (0..3).all(|k| {
+            k == axis || (center[k] - self.radius >= bounds.min[k] && center[k] + self.radius <= bounds.max[k])
+        });
+        contained.then(|| std::f64::consts::PI * self.radius * self.radius * (bounds.max[axis] - bounds.min[axis]))
+    }
 }
 
 /// A wall defined by an infinite cone.
@@ -361,6 +408,13 @@ impl WallGeometry<3> for TorusGeometry {
 
         callback([px, py, pz], [nx, ny, nz]);
     }
+
+    fn volume_in(&self, bounds: &BoundingBox<3>) -> Option<f64> {
+        // By Pappus's theorem, as long as the tube does not overlap itself.
+        let contained = self.minor_radius <= self.major_radius
+            && ball_within(bounds, &self.center, self.major_radius + self.minor_radius);
+        contained.then(|| 2.0 * std::f64::consts::PI.powi(2) * self.major_radius * self.minor_radius.powi(2))
+    }
 }
 
 /// A wall defined by a trefoil knot tube.
@@ -374,8 +428,8 @@ pub struct TrefoilKnotGeometry {
     pub scale: f64,
     /// The radius of the tube.
     pub tube_radius: f64,
-    /// Sample points along the knot curve.
-    pub samples: Vec<[f64; 3]>,
+    /// The knot curve.
+    pub curve: SegmentCurve<3>,
 }
 
 impl TrefoilKnotGeometry {
@@ -388,70 +442,35 @@ impl TrefoilKnotGeometry {
     /// * `tube_radius` - The radius of the tube.
     /// * `resolution` - The number of sample points along the curve.
     pub fn new(center: [f64; 3], scale: f64, tube_radius: f64, resolution: usize) -> Self {
-        let mut samples = Vec::with_capacity(resolution);
-        for i in 0..resolution {
-            let t = (i as f64 / resolution as f64) * std::f64::consts::TAU;
+        Self::with_sampling(center, scale, tube_radius, resolution, CurveSampling::Parameter)
+    }
+
+    /// Creates a new `TrefoilKnotGeometry` with the sample points placed by `sampling`,
+    /// for example at equal arc length.
+    pub fn with_sampling(center: [f64; 3], scale: f64, tube_radius: f64, resolution: usize, sampling: CurveSampling) -> Self {
+        let curve = SegmentCurve::from_fn_sampled(|t| {
+            let t = t * std::f64::consts::TAU;
             // Parametric equations for a trefoil knot
             let x = t.sin() + 2.0 * (2.0 * t).sin();
             let y = t.cos() - 2.0 * (2.0 * t).cos();
             let z = -(3.0 * t).sin();
-            
-            samples.push([
-                center[0] + x * scale,
-                center[1] + y * scale,
-                center[2] + z * scale,
-            ]);
-        }
-        Self { center, scale, tube_radius, samples }
-    }
-
-    fn get_closest_point(&self, point: &[f64; 3]) -> [f64; 3] {
-        let mut min_dist_sq = f64::MAX;
-        let mut closest_pt = self.samples[0];
-        let n = self.samples.len();
-        
-        for i in 0..n {
-            let p0 = self.samples[i];
-            let p1 = self.samples[(i + 1) % n];
-            
-            // Project point onto segment p0-p1
-            let v = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
-            let w = [point[0] - p0[0], point[1] - p0[1], point[2] - p0[2]];
-            
-            let c1 = w[0]*v[0] + w[1]*v[1] + w[2]*v[2];
-            let c2 = v[0]*v[0] + v[1]*v[1] + v[2]*v[2];
-            
-            let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
-            
-            let proj = [p0[0] + v[0] * t, p0[1] + v[1] * t, p0[2] + v[2] * t];
-            let dx = point[0] - proj[0];
-            let dy = point[1] - proj[1];
-            let dz = point[2] - proj[2];
-            let d2 = dx*dx + dy*dy + dz*dz;
-            
-            if d2 < min_dist_sq {
-                min_dist_sq = d2;
-                closest_pt = proj;
-            }
-        }
-        closest_pt
+            [center[0] + x * scale, center[1] + y * scale, center[2] + z * scale]
+        }, resolution, true, sampling);
+        Self { center, scale, tube_radius, curve }
     }
 }
 
 impl WallGeometry<3> for TrefoilKnotGeometry {
     fn contains(&self, point: &[f64; 3]) -> bool {
-        let closest = self.get_closest_point(point);
-        let dist_sq = (point[0] - closest[0]).powi(2) + (point[1] - closest[1]).powi(2) + (point[2] - closest[2]).powi(2);
-        dist_sq <= self.tube_radius.powi(2)
+        self.curve.tube_contains(point, self.tube_radius)
     }
 
     fn cut(&self, generator: &[f64; 3], callback: &mut dyn FnMut([f64; 3], [f64; 3])) {
-        let closest = self.get_closest_point(generator);
-        let dist = ((generator[0] - closest[0]).powi(2) + (generator[1] - closest[1]).powi(2) + (generator[2] - closest[2]).powi(2)).sqrt();
-        if dist == 0.0 { return; }
-        let normal = [(generator[0] - closest[0]) / dist, (generator[1] - closest[1]) / dist, (generator[2] - closest[2]) / dist];
-        let surface_point = [closest[0] + normal[0] * self.tube_radius, closest[1] + normal[1] * self.tube_radius, closest[2] + normal[2] * self.tube_radius];
-        callback(surface_point, normal);
+        self.curve.tube_cut(generator, self.tube_radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; 3]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.tube_radius)
     }
 }
 
@@ -472,10 +491,26 @@ impl ConvexPolyhedronGeometry {
     ///
     /// * `points` - A flat array of points on the planes [x1, y1, z1, x2, y2, z2, ...].
     /// * `normals` - A flat array of normal vectors for the planes [nx1, ny1, nz1, ...].
-    ///               Normals should point OUT of the valid region.
+    ///   Normals should point OUT of the valid region.
+    ///
+    /// # Panics
+    /// Panics if the arrays differ in length or are not a multiple of 3,
+    /// see [`ConvexPolyhedronGeometry::try_new`] for a fallible version.
+    /// Not available with the `no-panic` feature.
+    #[cfg(not(feature = "no-panic"))]
     pub fn new(points: &[f64], normals: &[f64]) -> Self {
-        if points.len() != normals.len() || points.len() % 3 != 0 {
-            panic!("Points and normals must have same length and be multiple of 3");
+        match Self::try_new(points, normals) {
+            Ok(geometry) => geometry,
+            Err(e) => panic!("{}", e),
+        }
+    }
+
+    /// Creates a new `ConvexPolyhedronGeometry`, returning an error if the points and normals
+    /// differ in length or are not a multiple of 3, or if the planes fail
+    /// [`ConvexPolyhedronGeometry::validate`].
+    pub fn try_new(points: &[f64], normals: &[f64]) -> Result<Self, WallError> {
+        if points.len() != normals.len() || !points.len().is_multiple_of(3) {
+            return Err(WallError::InvalidGeometry("Points and normals must have same length and be multiple of 3"));
         }
         
         let count = points.len() / 3;
@@ -487,7 +522,68 @@ impl ConvexPolyhedronGeometry {
                 [normals[i*3], normals[i*3+1], normals[i*3+2]]
             ));
         }
-        Self { planes }
+        let geometry = Self { planes };
+        geometry.validate()?;
+        Ok(geometry)
+    }
+
+    /// Creates the convex hull of a set of vertices, given as a flat array [x1, y1, z1, ...],
+    /// see [`crate::convex_hull`].
+    pub fn from_vertices(points: &[f64]) -> Result<Self, WallError> {
+        crate::hull::convex_hull(points).map(|hull| hull.geometry)
+    }
+
+    /// Checks that the plane points and normals are finite, that the normals are non-zero,
+    /// and that the planes bound a region with a positive volume. A plane soup whose half-spaces
+    /// do not overlap would otherwise leave every cell empty.
+    pub fn validate(&self) -> Result<(), WallError> {
+        if self.planes.iter().any(|(p, n)| p.iter().chain(n).any(|v| !v.is_finite())) {
+            return Err(WallError::InvalidGeometry("Plane points and normals must be finite"));
+        }
+        if self.planes.iter().any(|(_, n)| dot(*n, *n) == 0.0) {
+            return Err(WallError::InvalidGeometry("Plane normals must be non-zero"));
+        }
+        if self.region().is_none() {
+            return Err(WallError::InvalidGeometry("The planes do not bound a region with positive volume"));
+        }
+        Ok(())
+    }
+
+    /// Removes the planes that do not touch the valid region along a face, such as duplicates or
+    /// planes that lie entirely outside of the others, which saves their cuts in every cell.
+    /// Returns the number of removed planes. Does nothing if the region is empty.
+    pub fn prune_redundant_planes(&mut self) -> usize {
+        let Some(region) = self.region() else { return 0 };
+        let used: std::collections::HashSet<i32> = Cell::neighbors(&region).iter().copied().filter(|&n| n >= 0).collect();
+        let before = self.planes.len();
+        let mut index = -1;
+        self.planes.retain(|_| {
+            index += 1;
+            used.contains(&index)
+        });
+        before - self.planes.len()
+    }
+
+    /// Clips a box much larger than the plane points with all planes, labeling each face with the
+    /// index of its plane. Returns `None` if nothing with a positive volume remains.
+    fn region(&self) -> Option<Cell3DFaces> {
+        let lo: [f64; 3] = std::array::from_fn(|k| self.planes.iter().map(|(p, _)| p[k]).fold(f64::INFINITY, f64::min));
+        let hi: [f64; 3] = std::array::from_fn(|k| self.planes.iter().map(|(p, _)| p[k]).fold(f64::NEG_INFINITY, f64::max));
+        let extent = (0..3).map(|k| hi[k] - lo[k]).fold(0.0, f64::max);
+        let (center, scale) = if self.planes.is_empty() {
+            ([0.0; 3], 1.0)
+        } else {
+            (std::array::from_fn(|k| 0.5 * (lo[k] + hi[k])), if extent > 0.0 { extent } else { 1.0 })
+        };
+        let half = 1e3 * scale;
+        let mut cell = Cell3DFaces::new(0, BoundingBox::new(center.map(|c| c - half), center.map(|c| c + half)));
+        for (i, (p, n)) in self.planes.iter().enumerate() {
+            cell.clip(p, n, i as i32);
+            if Cell::is_empty(&cell) {
+                return None;
+            }
+        }
+        (cell.volume() > 1e-12 * scale * scale * scale).then_some(cell)
     }
 
     /// Creates a regular tetrahedron wall.
@@ -716,12 +812,10 @@ impl WallGeometry<3> for ConvexPolyhedronGeometry {
 /// The valid region is inside the tube following the curve.
 #[derive(Debug)]
 pub struct CubicBezierGeometry {
-    /// Sample points along the curve.
-    pub samples: Vec<[f64; 3]>,
+    /// The sampled curve, which is closed if the tube loops back to its start.
+    pub curve: SegmentCurve<3>,
     /// The radius of the tube.
     pub tube_radius: f64,
-    /// Whether the tube is closed (loops back to start).
-    pub closed: bool,
 }
 
 impl CubicBezierGeometry {
@@ -737,12 +831,16 @@ impl CubicBezierGeometry {
     /// * `resolution` - The number of segments to approximate the curve.
     /// * `closed` - Whether the tube should be closed (looping).
     pub fn new(p0: [f64; 3], p1: [f64; 3], p2: [f64; 3], p3: [f64; 3], tube_radius: f64, resolution: usize, closed: bool) -> Self {
-        let mut samples = Vec::with_capacity(resolution + 1);
-        for i in 0..=resolution {
-            let t = i as f64 / resolution as f64;
-            samples.push(Self::calculate_cubic_bezier_point(p0, p1, p2, p3, t));
-        }
-        Self { samples, tube_radius, closed }
+        Self::with_sampling(p0, p1, p2, p3, tube_radius, resolution, closed, CurveSampling::Parameter)
+    }
+
+    /// Creates a new `CubicBezierGeometry` with the sample points placed by `sampling`,
+    /// for example at equal arc length.
+    #[allow(clippy::too_many_arguments)]
+    pub fn with_sampling(p0: [f64; 3], p1: [f64; 3], p2: [f64; 3], p3: [f64; 3], tube_radius: f64, resolution: usize, closed: bool, sampling: CurveSampling) -> Self {
+        let point = |t| Self::calculate_cubic_bezier_point(p0, p1, p2, p3, t);
+        let samples = sampling.parameters(&point, resolution, false).into_iter().map(point).collect();
+        Self { curve: SegmentCurve::new(samples, closed), tube_radius }
     }
 
     fn calculate_cubic_bezier_point(p0: [f64; 3], p1: [f64; 3], p2: [f64; 3], p3: [f64; 3], t: f64) -> [f64; 3] {
@@ -758,59 +856,19 @@ impl CubicBezierGeometry {
             mt3 * p0[2] + 3.0 * mt2 * t * p1[2] + 3.0 * mt * t2 * p2[2] + t3 * p3[2],
         ]
     }
-
-    fn get_closest_point(&self, point: &[f64; 3]) -> [f64; 3] {
-        if self.samples.is_empty() {
-            return [0.0, 0.0, 0.0];
-        }
-        let mut min_dist_sq = f64::MAX;
-        let mut closest_pt = self.samples[0];
-        let n = self.samples.len();
-        
-        // Iterate over segments
-        let limit = if self.closed { n } else { n - 1 };
-        
-        for i in 0..limit {
-            let p0 = self.samples[i];
-            let p1 = self.samples[(i + 1) % n];
-            
-            let v = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
-            let w = [point[0] - p0[0], point[1] - p0[1], point[2] - p0[2]];
-            
-            let c1 = w[0]*v[0] + w[1]*v[1] + w[2]*v[2];
-            let c2 = v[0]*v[0] + v[1]*v[1] + v[2]*v[2];
-            
-            let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
-            
-            let proj = [p0[0] + v[0] * t, p0[1] + v[1] * t, p0[2] + v[2] * t];
-            let dx = point[0] - proj[0];
-            let dy = point[1] - proj[1];
-            let dz = point[2] - proj[2];
-            let d2 = dx*dx + dy*dy + dz*dz;
-            
-            if d2 < min_dist_sq {
-                min_dist_sq = d2;
-                closest_pt = proj;
-            }
-        }
-        closest_pt
-    }
 }
 
 impl WallGeometry<3> for CubicBezierGeometry {
     fn contains(&self, point: &[f64; 3]) -> bool {
-        let closest = self.get_closest_point(point);
-        let dist_sq = (point[0] - closest[0]).powi(2) + (point[1] - closest[1]).powi(2) + (point[2] - closest[2]).powi(2);
-        dist_sq <= self.tube_radius.powi(2)
+        self.curve.tube_contains(point, self.tube_radius)
     }
 
     fn cut(&self, generator: &[f64; 3], callback: &mut dyn FnMut([f64; 3], [f64; 3])) {
-        let closest = self.get_closest_point(generator);
-        let dist = ((generator[0] - closest[0]).powi(2) + (generator[1] - closest[1]).powi(2) + (generator[2] - closest[2]).powi(2)).sqrt();
-        if dist == 0.0 { return; }
-        let normal = [(generator[0] - closest[0]) / dist, (generator[1] - closest[1]) / dist, (generator[2] - closest[2]) / dist];
-        let surface_point = [closest[0] + normal[0] * self.tube_radius, closest[1] + normal[1] * self.tube_radius, closest[2] + normal[2] * self.tube_radius];
-        callback(surface_point, normal);
+        self.curve.tube_cut(generator, self.tube_radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; 3]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.tube_radius)
     }
 }
 
@@ -819,24 +877,26 @@ impl WallGeometry<3> for CubicBezierGeometry {
 /// The valid region is inside the tube following the curve.
 #[derive(Debug)]
 pub struct CatmullRomGeometry {
-    /// Sample points along the curve.
-    pub samples: Vec<[f64; 3]>,
+    /// The sampled curve, which is closed if the tube loops back to its start.
+    pub curve: SegmentCurve<3>,
     /// The radius of the tube.
     pub tube_radius: f64,
-    /// Whether the tube is closed (loops back to start).
-    pub closed: bool,
 }
 
 impl CatmullRomGeometry {
     pub fn new(points: Vec<[f64; 3]>, tube_radius: f64, resolution: usize, closed: bool) -> Self {
-        let mut samples = Vec::with_capacity(resolution + 1);
+        Self::with_sampling(points, tube_radius, resolution, closed, CurveSampling::Parameter)
+    }
+
+    /// Creates a new `CatmullRomGeometry` with the sample points placed by `sampling`,
+    /// for example at equal arc length.
+    pub fn with_sampling(points: Vec<[f64; 3]>, tube_radius: f64, resolution: usize, closed: bool, sampling: CurveSampling) -> Self {
+        let mut samples = Vec::new();
         if points.len() >= 2 {
-            for i in 0..=resolution {
-                let t = i as f64 / resolution as f64;
-                samples.push(Self::get_point(t, &points, closed));
-            }
+            let point = |t| Self::get_point(t, &points, closed);
+            samples = sampling.parameters(&point, resolution, false).into_iter().map(point).collect();
         }
-        Self { samples, tube_radius, closed }
+        Self { curve: SegmentCurve::new(samples, closed), tube_radius }
     }
 
     fn get_point(t: f64, points: &[[f64; 3]], closed: bool) -> [f64; 3] {
@@ -855,35 +915,30 @@ impl CatmullRomGeometry {
             int_point = l as isize - 2;
         }
 
-        let p0;
-        let p1;
-        let p2;
-        let p3;
-
-        if closed || int_point > 0 {
-             p0 = points[( (int_point - 1) % l as isize + l as isize) as usize % l];
+        let p0 = if closed || int_point > 0 {
+             points[( (int_point - 1) % l as isize + l as isize) as usize % l]
         } else {
-             p0 = [
+             [
                  points[0][0] - (points[1][0] - points[0][0]),
                  points[0][1] - (points[1][1] - points[0][1]),
                  points[0][2] - (points[1][2] - points[0][2]),
-             ];
-        }
+             ]
+        };
 
-        p1 = points[int_point as usize % l];
-        p2 = points[(int_point + 1) as usize % l];
+        let p1 = points[int_point as usize % l];
+        let p2 = points[(int_point + 1) as usize % l];
 
-        if closed || int_point + 2 < l as isize {
-            p3 = points[(int_point + 2) as usize % l];
+        let p3 = if closed || int_point + 2 < l as isize {
+            points[(int_point + 2) as usize % l]
         } else {
             let last = points[l-1];
             let prev = points[l-2];
-            p3 = [
+            [
                 last[0] - (prev[0] - last[0]),
                 last[1] - (prev[1] - last[1]),
                 last[2] - (prev[2] - last[2]),
-            ];
-        }
+            ]
+        };
 
         let pow = 0.25;
         let mut dt0 = dist_sq(p0, p1).powf(pow);
@@ -904,59 +959,19 @@ impl CatmullRomGeometry {
             pz.calc(weight),
         ]
     }
-
-    fn get_closest_point(&self, point: &[f64; 3]) -> [f64; 3] {
-        if self.samples.is_empty() {
-            return [0.0, 0.0, 0.0];
-        }
-        let mut min_dist_sq = f64::MAX;
-        let mut closest_pt = self.samples[0];
-        let n = self.samples.len();
-        
-        // Iterate over segments
-        let limit = if self.closed { n } else { n - 1 };
-        
-        for i in 0..limit {
-            let p0 = self.samples[i];
-            let p1 = self.samples[(i + 1) % n];
-            
-            let v = [p1[0] - p0[0], p1[1] - p0[1], p1[2] - p0[2]];
-            let w = [point[0] - p0[0], point[1] - p0[1], point[2] - p0[2]];
-            
-            let c1 = w[0]*v[0] + w[1]*v[1] + w[2]*v[2];
-            let c2 = v[0]*v[0] + v[1]*v[1] + v[2]*v[2];
-            
-            let t = if c2 <= 0.0 { 0.0 } else { (c1 / c2).clamp(0.0, 1.0) };
-            
-            let proj = [p0[0] + v[0] * t, p0[1] + v[1] * t, p0[2] + v[2] * t];
-            let dx = point[0] - proj[0];
-            let dy = point[1] - proj[1];
-            let dz = point[2] - proj[2];
-            let d2 = dx*dx + dy*dy + dz*dz;
-            
-            if d2 < min_dist_sq {
-                min_dist_sq = d2;
-                closest_pt = proj;
-            }
-        }
-        closest_pt
-    }
 }
 
 impl WallGeometry<3> for CatmullRomGeometry {
     fn contains(&self, point: &[f64; 3]) -> bool {
-        let closest = self.get_closest_point(point);
-        let dist_sq = (point[0] - closest[0]).powi(2) + (point[1] - closest[1]).powi(2) + (point[2] - closest[2]).powi(2);
-        dist_sq <= self.tube_radius.powi(2)
+        self.curve.tube_contains(point, self.tube_radius)
     }
 
     fn cut(&self, generator: &[f64; 3], callback: &mut dyn FnMut([f64; 3], [f64; 3])) {
-        let closest = self.get_closest_point(generator);
-        let dist = ((generator[0] - closest[0]).powi(2) + (generator[1] - closest[1]).powi(2) + (generator[2] - closest[2]).powi(2)).sqrt();
-        if dist == 0.0 { return; }
-        let normal = [(generator[0] - closest[0]) / dist, (generator[1] - closest[1]) / dist, (generator[2] - closest[2]) / dist];
-        let surface_point = [closest[0] + normal[0] * self.tube_radius, closest[1] + normal[1] * self.tube_radius, closest[2] + normal[2] * self.tube_radius];
-        callback(surface_point, normal);
+        self.curve.tube_cut(generator, self.tube_radius, callback);
+    }
+
+    fn signed_distance(&self, point: &[f64; 3]) -> Option<f64> {
+        self.curve.tube_signed_distance(point, self.tube_radius)
     }
 }
 
@@ -992,4 +1007,8 @@ fn init_nonuniform_catmull_rom(x0: f64, x1: f64, x2: f64, x3: f64, dt0: f64, dt1
     let c3 = 2.0 * x1 - 2.0 * x2 + t1 + t2;
 
     CubicPoly { c0, c1, c2, c3 }
-}
\ No newline at end of file
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
@@ -1,13 +1,70 @@
 use wasm_bindgen::prelude::*;
 use js_sys::{Array};
+use std::mem::ManuallyDrop;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::thread::ThreadId;
+use crate::wall::curve::CurveSampling;
 
-#[cfg(target_arch = "wasm32")]
+#[cfg(all(target_arch = "wasm32", feature = "threads"))]
 use wasm_bindgen_rayon::init_thread_pool;
 
-#[cfg(target_arch = "wasm32")]
+/// Whether `init_threads` started a thread pool, which would evaluate walls on worker threads.
+static THREAD_POOL_ACTIVE: AtomicBool = AtomicBool::new(false);
+
+/// The number of live JavaScript objects used by custom walls.
+static JS_OBJECTS: AtomicUsize = AtomicUsize::new(0);
+
+/// Starts a thread pool with `n` Web Workers for the parallel calculations.
+///
+/// @throws If custom JavaScript walls exist, which can only be evaluated on the main thread.
+#[cfg(all(target_arch = "wasm32", feature = "threads"))]
 #[wasm_bindgen]
-pub fn init_threads(n: usize) -> js_sys::Promise {
-    init_thread_pool(n)
+pub fn init_threads(n: usize) -> Result<js_sys::Promise, JsError> {
+    if JS_OBJECTS.load(Ordering::SeqCst) > 0 {
+        return Err(JsError::new("init_threads cannot be used while custom JavaScript walls exist"));
+    }
+    THREAD_POOL_ACTIVE.store(true, Ordering::SeqCst);
+    Ok(init_thread_pool(n))
+}
+
+/// A JavaScript object used by a custom wall geometry.
+///
+/// JavaScript values belong to the thread that created them, and accessing them on a worker
+/// thread reads the wrong objects. Custom walls are therefore rejected once `init_threads` started
+/// a thread pool, and the object is only handed out on the thread that created it.
+pub(crate) struct JsObject {
+    val: ManuallyDrop<JsValue>,
+    thread: ThreadId,
+}
+
+// SAFETY: the value is only accessed and dropped on the thread that created it, see `get` and `drop`.
+unsafe impl Send for JsObject {}
+unsafe impl Sync for JsObject {}
+
+impl JsObject {
+    pub(crate) fn new(val: JsValue) -> Result<Self, JsError> {
+        if THREAD_POOL_ACTIVE.load(Ordering::SeqCst) {
+            return Err(JsError::new("Custom JavaScript walls cannot be evaluated by the thread pool of init_threads"));
+        }
+        JS_OBJECTS.fetch_add(1, Ordering::SeqCst);
+        Ok(JsObject { val: ManuallyDrop::new(val), thread: std::thread::current().id() })
+    }
+
+    /// Returns the object, or `None` on any other thread than the one that created it.
+    pub(crate) fn get(&self) -> Option<&JsValue> {
+        (std::thread::current().id() == self.thread).then_some(&*self.val)
+    }
+}
+
+impl Drop for JsObject {
+    fn drop(&mut self) {
+        // Releasing the value on another thread would release an unrelated object, so it is leaked.
+        if std::thread::current().id() == self.thread {
+            // SAFETY: the value is not used after this.
+            unsafe { ManuallyDrop::drop(&mut self.val) };
+        }
+        JS_OBJECTS.fetch_sub(1, Ordering::SeqCst);
+    }
 }
 
 #[wasm_bindgen(typescript_custom_section)]
@@ -31,8 +88,28 @@ pub fn parse_js_point<const D: usize>(val: &JsValue) -> Option<[f64; D]> {
         return None;
     }
     let mut point = [0.0; D];
-    for i in 0..D {
-        point[i] = arr.get(i as u32).as_f64()?;
+    for (i, value) in point.iter_mut().enumerate() {
+        *value = arr.get(i as u32).as_f64()?;
     }
     Some(point)
-}
\ No newline at end of file
+}
+/// Selects how a curve wall places its sample points along the curve.
+#[wasm_bindgen(js_name = CurveSampling)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum WasmCurveSampling {
+    /// Uniform steps in the curve parameter.
+    Parameter,
+    /// Segments of equal length along the curve.
+    ArcLength,
+    /// Segments that shorten where the curve bends.
+    Curvature,
+}
+
+/// Converts an optional sampling from JavaScript, defaulting to uniform parameter steps.
+pub(crate) fn curve_sampling(sampling: Option<WasmCurveSampling>) -> CurveSampling {
+    match sampling {
+        None | Some(WasmCurveSampling::Parameter) => CurveSampling::Parameter,
+        Some(WasmCurveSampling::ArcLength) => CurveSampling::ArcLength,
+        Some(WasmCurveSampling::Curvature) => CurveSampling::Curvature,
+    }
+}
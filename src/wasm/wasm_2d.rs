@@ -1,12 +1,16 @@
 use crate::algorithm::algo_2d_grid::Algorithm2DGrid;
 use crate::bounds::BoundingBox;
+use crate::cell::Cell;
 use crate::cell::cell_2d::Cell2D;
+use crate::snapshot::CellSnapshot;
 use crate::tessellation::Tessellation;
 use crate::wall::{Wall, WallGeometry};
 use crate::wall::wall_2d::*;
-use crate::wasm::utils::parse_js_point;
+use crate::wasm::wasm_3d::Cell3D;
+use crate::wasm::utils::{JsObject, WasmCurveSampling, curve_sampling, parse_js_point};
 use wasm_bindgen::prelude::*;
-use js_sys::{Reflect, Function, Array};
+use js_sys::{Reflect, Function, Array, Float64Array};
+use std::sync::Arc;
 
 // --- Bounding Box ---
 
@@ -45,6 +49,7 @@ impl From<BoundingBox2D> for BoundingBox<2> {
 /// WASM wrapper for 2D Walls.
 #[wasm_bindgen]
 pub struct Wall2D {
+    id: i32,
     inner: Option<Wall<2>>,
 }
 
@@ -53,74 +58,83 @@ impl Wall2D {
     /// Creates a custom wall from a JavaScript object.
     ///
     /// The object must implement the `contains(point)` and `cut(generator, callback)` methods.
+    /// It is evaluated on the main thread, so custom walls cannot be combined with `init_threads`.
+    ///
+    /// @throws If the thread pool of `init_threads` is active.
     #[wasm_bindgen(js_name = newCustom)]
-    pub fn new_custom(val: JsValue, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(JsWallGeometry2D { val }))) }
+    pub fn new_custom(val: JsValue, id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(JsWallGeometry2D { val: JsObject::new(val)? }))
     }
 
     /// Returns the unique identifier of the wall.
     #[wasm_bindgen(getter)]
-    pub fn id(&self) -> i32 { self.inner.as_ref().unwrap().id() }
+    pub fn id(&self) -> i32 { self.id }
 
     /// Checks if a point is contained within the wall.
     pub fn contains(&self, x: f64, y: f64) -> bool {
-        self.inner.as_ref().unwrap().contains(&[x, y])
+        self.inner.as_ref().is_some_and(|w| w.contains(&[x, y]))
     }
 
     /// Creates a linear wall (half-plane) defined by a point and a normal vector.
-    pub fn new_line(px: f64, py: f64, nx: f64, ny: f64, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(LineGeometry::new([px, py], [nx, ny])))) }
+    pub fn new_line(px: f64, py: f64, nx: f64, ny: f64, id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(LineGeometry::new([px, py], [nx, ny])))
     }
 
     /// Creates a circular wall.
-    pub fn new_circle(cx: f64, cy: f64, radius: f64, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(CircleGeometry::new([cx, cy], radius)))) }
+    pub fn new_circle(cx: f64, cy: f64, radius: f64, id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(CircleGeometry::new([cx, cy], radius)))
     }
 
     /// Creates a convex polygon wall from a list of points and normals.
-    pub fn new_polygon(points: &[f64], normals: &[f64], id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(ConvexPolygonGeometry2D::new(points, normals)))) }
+    pub fn new_polygon(points: &[f64], normals: &[f64], id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(ConvexPolygonGeometry2D::try_new(points, normals)?))
     }
 
     /// Creates a regular polygon wall.
-    pub fn new_regular_polygon(cx: f64, cy: f64, radius: f64, sides: usize, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(ConvexPolygonGeometry2D::new_regular([cx, cy], radius, sides)))) }
+    pub fn new_regular_polygon(cx: f64, cy: f64, radius: f64, sides: usize, id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(ConvexPolygonGeometry2D::new_regular([cx, cy], radius, sides)))
     }
 
     /// Creates an annulus (ring) wall.
-    pub fn new_annulus(cx: f64, cy: f64, inner_r: f64, outer_r: f64, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(AnnulusGeometry::new([cx, cy], inner_r, outer_r)))) }
+    pub fn new_annulus(cx: f64, cy: f64, inner_r: f64, outer_r: f64, id: i32) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(AnnulusGeometry::new([cx, cy], inner_r, outer_r)))
     }
 
     /// Creates a wall defined by a cubic Bezier curve.
-    pub fn new_bezier(p0x: f64, p0y: f64, p1x: f64, p1y: f64, p2x: f64, p2y: f64, p3x: f64, p3y: f64, radius: f64, resolution: usize, closed: bool, id: i32) -> Wall2D {
-        Wall2D { inner: Some(Wall::new(id, Box::new(CubicBezierGeometry2D::new([p0x, p0y], [p1x, p1y], [p2x, p2y], [p3x, p3y], radius, resolution, closed)))) }
+    ///
+    /// @param sampling How the sample points are placed along the curve, by default at uniform parameter steps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_bezier(p0x: f64, p0y: f64, p1x: f64, p1y: f64, p2x: f64, p2y: f64, p3x: f64, p3y: f64, radius: f64, resolution: usize, closed: bool, id: i32, sampling: Option<WasmCurveSampling>) -> Result<Wall2D, JsError> {
+        Wall2D::wrap(id, Box::new(CubicBezierGeometry2D::with_sampling([p0x, p0y], [p1x, p1y], [p2x, p2y], [p3x, p3y], radius, resolution, closed, curve_sampling(sampling))))
     }
 
     /// Creates a wall defined by a Catmull-Rom spline tube.
-    pub fn new_catmull_rom(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32) -> Wall2D {
-        if points.len() % 2 != 0 {
-            panic!("Catmull-Rom curve points must be a multiple of 2 coordinates");
+    ///
+    /// @param sampling How the sample points are placed along the curve, by default at uniform parameter steps.
+    pub fn new_catmull_rom(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32, sampling: Option<WasmCurveSampling>) -> Result<Wall2D, JsError> {
+        if !points.len().is_multiple_of(2) {
+            return Err(JsError::new("Catmull-Rom curve points must be a multiple of 2 coordinates"));
         }
         let mut control_points = Vec::with_capacity(points.len() / 2);
         for i in (0..points.len()).step_by(2) {
             control_points.push([points[i], points[i+1]]);
         }
-        Wall2D { inner: Some(Wall::new(id, Box::new(CatmullRomGeometry2D::new(control_points, radius, resolution, closed)))) }
+        Wall2D::wrap(id, Box::new(CatmullRomGeometry2D::with_sampling(control_points, radius, resolution, closed, curve_sampling(sampling))))
     }
 }
 
 impl Wall2D {
+    fn wrap(id: i32, geometry: Box<dyn WallGeometry<2>>) -> Result<Wall2D, JsError> {
+        Ok(Wall2D { id, inner: Some(Wall::try_new(id, geometry)?) })
+    }
+
     pub fn take_inner(&mut self) -> Option<Wall<2>> { self.inner.take() }
 }
 
 struct JsWallGeometry2D {
-    val: JsValue,
+    val: JsObject,
 }
 
-unsafe impl Send for JsWallGeometry2D {}
-unsafe impl Sync for JsWallGeometry2D {}
-
 impl std::fmt::Debug for JsWallGeometry2D {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "JsWallGeometry2D")
@@ -129,9 +143,10 @@ impl std::fmt::Debug for JsWallGeometry2D {
 
 impl WallGeometry<2> for JsWallGeometry2D {
     fn contains(&self, point: &[f64; 2]) -> bool {
-        if let Ok(func) = Reflect::get(&self.val, &"contains".into()).and_then(|f| f.dyn_into::<Function>()) {
+        let Some(val) = self.val.get() else { return false };
+        if let Ok(func) = Reflect::get(val, &"contains".into()).and_then(|f| f.dyn_into::<Function>()) {
             let args = Array::of2(&point[0].into(), &point[1].into());
-            if let Ok(res) = func.apply(&self.val, &args) {
+            if let Ok(res) = func.apply(val, &args) {
                 return res.as_bool().unwrap_or(false);
             }
         }
@@ -139,9 +154,10 @@ impl WallGeometry<2> for JsWallGeometry2D {
     }
 
     fn cut(&self, generator: &[f64; 2], callback: &mut dyn FnMut([f64; 2], [f64; 2])) {
-        if let Ok(func) = Reflect::get(&self.val, &"cut".into()).and_then(|f| f.dyn_into::<Function>()) {
+        let Some(val) = self.val.get() else { return };
+        if let Ok(func) = Reflect::get(val, &"cut".into()).and_then(|f| f.dyn_into::<Function>()) {
             let args = Array::of2(&generator[0].into(), &generator[1].into());
-            if let Ok(res) = func.apply(&self.val, &args) {
+            if let Ok(res) = func.apply(val, &args) {
                 if res.is_null() || res.is_undefined() { return; }
                 
                 let process_item = |item: &JsValue| -> Option<([f64; 2], [f64; 2])> {
@@ -151,16 +167,14 @@ impl WallGeometry<2> for JsWallGeometry2D {
                 };
 
                 if Array::is_array(&res) {
-                    let arr: Array = res.dyn_into().unwrap();
+                    let arr: &Array = res.unchecked_ref();
                     for i in 0..arr.length() {
                         if let Some((p, n)) = process_item(&arr.get(i)) {
                             callback(p, n);
                         }
                     }
-                } else {
-                    if let Some((p, n)) = process_item(&res) {
-                        callback(p, n);
-                    }
+                } else if let Some((p, n)) = process_item(&res) {
+                    callback(p, n);
                 }
             }
         }
@@ -169,6 +183,28 @@ impl WallGeometry<2> for JsWallGeometry2D {
 
 // --- Cell Wrapper ---
 
+/// The cells of a `Tessellation2D` published by `swap`, which stay unchanged while the
+/// tessellation calculates its next results.
+#[wasm_bindgen(js_name = CellSnapshot2D)]
+pub struct CellSnapshot2D {
+    inner: Arc<CellSnapshot<Cell2D>>,
+}
+
+#[wasm_bindgen(js_class = CellSnapshot2D)]
+impl CellSnapshot2D {
+    /// The number of swaps before this snapshot was published, or 0 before the first swap.
+    #[wasm_bindgen(getter)]
+    pub fn generation(&self) -> u64 { self.inner.generation }
+    /// The number of cells in the snapshot.
+    #[wasm_bindgen(getter)]
+    pub fn count_cells(&self) -> usize { self.inner.cells.len() }
+    /// The generators of the cells as a flat array [x0, y0, x1, y1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn generators(&self) -> Vec<f64> { self.inner.generators.clone() }
+    /// Returns the cell at a given index.
+    pub fn get_cell(&self, index: usize) -> Option<Cell2DWASM> { self.inner.cells.get(index).map(|c| Cell2DWASM { inner: c.clone() }) }
+}
+
 /// Represents a 2D Voronoi cell.
 #[wasm_bindgen(js_name = Cell2D)]
 pub struct Cell2DWASM {
@@ -188,6 +224,8 @@ impl Cell2DWASM {
     pub fn edge_neighbors(&self) -> Vec<i32> { self.inner.edge_neighbors() }
     /// Calculates the area of the cell.
     pub fn area(&self) -> f64 { self.inner.area() }
+    /// Calculates the diameter of the cell, the largest distance between two of its vertices.
+    pub fn diameter(&self) -> f64 { Cell::diameter(&self.inner) }
     /// Calculates the centroid of the cell.
     pub fn centroid(&self) -> Vec<f64> { self.inner.centroid().to_vec() }
 }
@@ -212,24 +250,132 @@ impl Tessellation2D {
         let b: BoundingBox<2> = bounds.into();
         Tessellation2D { inner: Tessellation::new(b, Algorithm2DGrid::new(nx, ny, &b)) }
     }
+    /// Creates a new 2D tessellation with a grid resolution chosen for the number of generators
+    /// and the aspect ratio of the bounding box.
+    ///
+    /// @param bounds The bounding box of the simulation.
+    /// @param count The expected number of generators.
+    pub fn new_auto(bounds: BoundingBox2D, count: usize) -> Tessellation2D {
+        let b: BoundingBox<2> = bounds.into();
+        Tessellation2D { inner: Tessellation::new(b, Algorithm2DGrid::auto(count, &b)) }
+    }
+    /// The number of grid bins along each axis, [nx, ny].
+    #[wasm_bindgen(getter)]
+    pub fn grid_resolution(&self) -> Vec<usize> { self.inner.algorithm.resolution().to_vec() }
+    /// Returns the linear index of the grid bin holding a generator, `x + nx * y`,
+    /// or undefined if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> { self.inner.algorithm.bin_of_generator(index) }
+    /// Returns the number of generators in each grid bin, in the order of the linear bin indices.
+    pub fn bin_occupancy(&self) -> Vec<usize> { self.inner.algorithm.bin_occupancy() }
     /// Sets the generator points.
     ///
     /// @param generators A flat array of coordinates [x0, y0, x1, y1, ...].
-    pub fn set_generators(&mut self, generators: &[f64]) { self.inner.set_generators(generators); }
+    /// @throws If any coordinate is NaN or infinite.
+    pub fn set_generators(&mut self, generators: &[f64]) -> Result<(), JsError> { Ok(self.inner.set_generators(generators)?) }
+    /// Sets the generator points without checking for NaN or infinite coordinates.
+    pub fn set_generators_unchecked(&mut self, generators: &[f64]) { self.inner.set_generators_unchecked(generators); }
+    /// Registers an external ID for each generator, which stays attached when walls prune generators.
+    ///
+    /// @param ids One unique ID per generator, including ghosts.
+    pub fn set_generator_ids(&mut self, ids: Vec<u64>) -> Result<(), JsError> { Ok(self.inner.set_generator_ids(&ids)?) }
+    /// Returns the external ID of a generator, or its index if no IDs are registered.
+    pub fn generator_id(&self, index: usize) -> u64 { self.inner.generator_id(index) }
+    /// Groups the generators into logical sites whose cells are merged, such as the sub-points of a particle.
+    ///
+    /// @param sites The site ID of each generator, including ghosts.
+    pub fn set_sites(&mut self, sites: Vec<usize>) -> Result<(), JsError> { Ok(self.inner.set_sites(&sites)?) }
+    /// Returns the site ID of a generator, or its index if no sites are set.
+    pub fn site_of(&self, index: usize) -> usize { self.inner.site_of(index) }
+    /// Returns the index of the generator with an external ID, if any.
+    pub fn generator_index(&self, id: u64) -> Option<usize> { self.inner.generator_index(id) }
     /// Updates a specific generator's position.
     pub fn set_generator(&mut self, index: usize, x: f64, y: f64) { self.inner.set_generator(index, &[x, y]); }
+    /// Finds the generator closest to a point within a radius, such as the one under the cursor.
+    ///
+    /// @param radius The largest distance from the point.
+    pub fn find_generator_near(&self, x: f64, y: f64, radius: f64) -> Option<usize> { self.inner.find_generator_near(&[x, y], radius) }
+    /// Updates the positions of many generators in one call.
+    ///
+    /// @param indices The indices of the generators to move.
+    /// @param positions The new positions as a flat array, one per index.
+    /// @returns The number of generators moved.
+    pub fn set_generators_sparse(&mut self, indices: &[u32], positions: &[f64]) -> Result<usize, JsError> {
+        let indices: Vec<usize> = indices.iter().map(|&i| i as usize).collect();
+        Ok(self.inner.set_generators_sparse(&indices, positions)?)
+    }
+    /// Moves each generator towards its target by at most a step length, staying inside the walls.
+    ///
+    /// @param new_positions A flat array of target coordinates [x0, y0, x1, y1, ...] for all generators.
+    /// @param max_step The maximum distance a generator moves.
+    /// @returns The number of generators held back from their target.
+    /// @throws If the number of positions does not match or any coordinate is NaN or infinite.
+    pub fn limit_displacement(&mut self, new_positions: &[f64], max_step: f64) -> Result<usize, JsError> { Ok(self.inner.limit_displacement(new_positions, max_step)?) }
+    /// Moves generators to the closest point on a wall or bounding box side, at an inward offset.
+    ///
+    /// @param indices The indices of the generators to move.
+    /// @param wall_id The ID of the wall or bounding box side.
+    /// @param offset The distance inside the surface to place the generators at.
+    /// @returns The number of generators moved.
+    pub fn project_generators_to_wall(&mut self, indices: &[u32], wall_id: i32, offset: f64) -> usize {
+        let indices: Vec<usize> = indices.iter().map(|&i| i as usize).collect();
+        self.inner.project_generators_to_wall(&indices, wall_id, offset)
+    }
     /// Generates random points within the bounds and walls.
     pub fn random_generators(&mut self, count: usize) { self.inner.random_generators(count); }
+    /// Generates random points within the bounds and walls from a fixed seed, for reproducible results.
+    pub fn random_generators_seeded(&mut self, count: usize, seed: u32) { self.inner.random_generators_seeded(count, seed as u64); }
+    /// Places one generator in each sub-cell of a regular grid, moved from its center by up to
+    /// `jitter` times half the sub-cell size, as a fast initialization for relaxation.
+    pub fn stratified_generators(&mut self, nx: usize, ny: usize, jitter: f64) { self.inner.stratified_generators([nx, ny], jitter); }
     /// Reads generators from a string representation.
     ///
     /// Each line should contain an ID followed by coordinates (e.g., "id x y").
-    pub fn read_generators(&mut self, input: &str) { self.inner.read_generators(input); }
+    pub fn read_generators(&mut self, input: &str) -> Result<(), JsError> { Ok(self.inner.read_generators(input)?) }
+    /// Reads generators from a binary buffer, such as a Node.js `Buffer`.
+    ///
+    /// @param bytes Little-endian 64-bit floats [x0, y0, x1, y1, ...].
+    /// @throws If the length is not a multiple of the generator size, or any coordinate is NaN or infinite.
+    pub fn read_generators_buffer(&mut self, bytes: &[u8]) -> Result<(), JsError> { Ok(self.inner.read_generators_bytes(bytes)?) }
     /// Adds a wall to the tessellation.
     pub fn add_wall(&mut self, mut wall: Wall2D) { if let Some(w) = wall.take_inner() { self.inner.add_wall(w); } }
     /// Removes all walls.
     pub fn clear_walls(&mut self) { self.inner.clear_walls(); }
     /// Calculates the Voronoi tessellation.
     pub fn calculate(&mut self) { self.inner.calculate(); }
+    /// Calculates the Voronoi tessellation, warm-started from the previous cells for generators
+    /// that moved only slightly, as between the frames of a simulation.
+    pub fn calculate_warm(&mut self) { self.inner.calculate_warm(); }
+    /// Calculates approximate cells quickly for interactive previews, clipping each cell by at most
+    /// a bounded number of neighbor candidates.
+    ///
+    /// @param max_neighbors_per_cell The number of candidates that may clip each cell.
+    pub fn preview_calculate(&mut self, max_neighbors_per_cell: usize) { self.inner.preview_calculate(max_neighbors_per_cell); }
+    /// Returns whether a cell is exact, which is false only for cells of `preview_calculate` that ran out of budget.
+    pub fn is_cell_exact(&self, index: usize) -> bool { self.inner.is_cell_exact(index) }
+    /// Opens or closes a side of the bounding box, clipping cells beyond it by a margin.
+    ///
+    /// @param axis The axis of the side.
+    /// @param is_max Whether it is the side at the maximum of the axis.
+    /// @param margin The margin beyond the side, or omitted to close it.
+    pub fn set_open_face(&mut self, axis: usize, is_max: bool, margin: Option<f64>) -> Result<(), JsError> { Ok(self.inner.set_open_face(axis, is_max, margin)?) }
+    /// Returns whether a cell touches an open side of the bounding box.
+    pub fn is_cell_unbounded(&self, index: usize) -> bool { self.inner.is_cell_unbounded(index) }
+    /// Sets how many neighbor candidates are held back to clip the nearest first, or restores the
+    /// default of the spatial algorithm when omitted.
+    ///
+    /// @param buffer The number of held back candidates, 0 for the native order.
+    pub fn set_candidate_buffer(&mut self, buffer: Option<usize>) { self.inner.set_candidate_buffer(buffer); }
+    /// Returns the indices of the generators a cell is clipped against, in the order of clipping.
+    pub fn candidate_order(&self, index: usize) -> Vec<u32> { self.inner.candidate_order(index).into_iter().map(|j| j as u32).collect() }
+    /// Publishes the current cells as a new snapshot, returning its generation.
+    pub fn swap(&mut self) -> u64 { self.inner.swap() }
+    /// Returns the latest snapshot published by `swap`, which stays unchanged by later calculations.
+    pub fn snapshot(&self) -> CellSnapshot2D { CellSnapshot2D { inner: self.inner.published() } }
+    /// Recalculates only the cells whose generators lie in a region, or whose previous cells intersect it.
+    ///
+    /// @param region The region enclosing the edited generators and the cells they touch.
+    /// @returns The indices of the recalculated cells.
+    pub fn recalculate_region(&mut self, region: BoundingBox2D) -> Vec<usize> { self.inner.recalculate_region(&region.into()) }
     /// Calculates the Voronoi tessellation and seals the boundaries.
     pub fn calculate_sealed(&mut self) { self.inner.calculate_sealed(); }
     /// Runs a post-processing pass to prune the cell faces at the boundaries.
@@ -242,16 +388,57 @@ impl Tessellation2D {
     /// Returns the number of computed cells.
     #[wasm_bindgen(getter)]
     pub fn count_cells(&self) -> usize { self.inner.count_cells() }
-    /// Gets a generator's position by index.
-    pub fn get_generator(&self, index: usize) -> Vec<f64> { self.inner.get_generator(index).to_vec() }
+    /// Returns the largest diameter of the computed cells, or zero without cells.
+    pub fn max_cell_diameter(&self) -> f64 { self.inner.max_cell_diameter() }
+    /// Gets a generator's position by index, throwing an error if it is out of range.
+    pub fn get_generator(&self, index: usize) -> Result<Vec<f64>, JsError> {
+        self.inner.try_get_generator(index).map(|g| g.to_vec()).ok_or_else(|| JsError::new("Generator index out of range"))
+    }
     /// Gets a cell by index.
     pub fn get_cell(&self, index: usize) -> Option<Cell2DWASM> { self.inner.get_cell(index).map(|inner| Cell2DWASM { inner }) }
     /// Returns all generators as a flat array.
     #[wasm_bindgen(getter)]
     pub fn generators(&self) -> Vec<f64> { self.inner.generators() }
+    /// Returns the generators as a binary buffer of little-endian 64-bit floats.
+    pub fn generators_buffer(&self) -> Vec<u8> { self.inner.generators_bytes() }
+    /// Returns the cells within a number of steps through shared faces, starting with the cell itself.
+    ///
+    /// @param index The index of the cell at the center.
+    /// @param depth The largest number of steps.
+    pub fn neighbor_shell(&self, index: usize, depth: usize) -> Vec<usize> { self.inner.neighbor_shell(index, depth) }
+    /// Returns a color index per cell such that adjacent cells never share a color, or an empty
+    /// array if more colors would be needed.
+    ///
+    /// @param max_colors The number of available colors.
+    pub fn color_cells(&self, max_colors: usize) -> Vec<usize> { self.inner.color_cells(max_colors).unwrap_or_default() }
     /// Returns all cells.
     #[wasm_bindgen(getter)]
     pub fn cells(&self) -> Vec<Cell2DWASM> { self.inner.cells().into_iter().map(|inner| Cell2DWASM { inner }).collect() }
+    /// Extrudes the cells into stacked layers of 3D prisms, ordered by layer, with the prism of
+    /// cell i in layer l at index l * count_cells + i.
+    ///
+    /// @param levels The increasing z coordinates of the layer boundaries, e.g. [0, height] for a single layer.
+    pub fn extrude_layers(&self, levels: Vec<f64>) -> Vec<Cell3D> { self.inner.extrude_layers(&levels).into_iter().map(|inner| Cell3D { inner }).collect() }
+    /// Returns a hash of the computed cells which is stable across platforms, to detect drift of the results.
+    pub fn fingerprint(&self) -> u64 { self.inner.fingerprint() }
+    /// Returns the nearest wall or bounding box side of each generator as a flat array
+    /// [wall_id, signed_distance, ...], with positive distances inside.
+    pub fn wall_distances(&self) -> Vec<f64> {
+        self.inner.wall_distances().into_iter().flat_map(|(id, d)| [id as f64, d]).collect()
+    }
+    /// Returns the translation of each cell for an exploded view as a flat array [x, y, ...].
+    ///
+    /// @param origin The point to push the cells away from, or the centroid of all cells if omitted.
+    /// @param factor The offset of each cell relative to the offset of its centroid from the origin.
+    pub fn exploded_transforms(&self, origin: Option<Vec<f64>>, factor: f64) -> Vec<f64> {
+        let origin = origin.filter(|o| o.len() >= 2).map(|o| std::array::from_fn(|k| o[k]));
+        self.inner.exploded_transforms(origin, factor).into_iter().flatten().collect()
+    }
+    /// Returns the deduplicated vertices of each cell without face data, as an array with one
+    /// Float64Array [x, y, ...] per cell, for the convex hull colliders of physics engines.
+    pub fn convex_hull_points(&self) -> Array {
+        self.inner.convex_hull_points().iter().map(|points| Float64Array::from(points.as_slice())).collect()
+    }
     /// Returns the seal log as a flat array [cell_id, neighbor_id, wall_id, ...].
     #[wasm_bindgen(getter)]
     pub fn seal_log(&self) -> Vec<i32> { self.inner.seal_log.clone() }
@@ -1,12 +1,22 @@
+use crate::algorithm::SpatialAlgorithm;
+use crate::algorithm::algo_3d_adaptive_grid::AlgorithmAdaptiveGrid;
+use crate::algorithm::algo_3d_binned_bvh::AlgorithmBinnedBvh;
 use crate::algorithm::algo_3d_grid::Algorithm3DGrid;
+use crate::algorithm::algo_3d_octree::Algorithm3DOctree;
 use crate::bounds::BoundingBox;
 use crate::cell::cell_3d_faces::Cell3DFaces;
+use crate::cell::{Cell, TetMesh, Tetrahedralization};
+use crate::morph::CellMorph;
+use crate::quantize::QuantizedCell;
+use crate::snapshot::CellSnapshot;
+use crate::solid::SolidMesh;
 use crate::tessellation::Tessellation;
-use crate::wall::{Wall, WallGeometry};
+use crate::wall::{Wall, WallGeometry, WallMesh};
 use crate::wall::wall_3d::*;
-use crate::wasm::utils::parse_js_point;
+use crate::wasm::utils::{JsObject, WasmCurveSampling, curve_sampling, parse_js_point};
 use wasm_bindgen::prelude::*;
-use js_sys::{Reflect, Function, Array, Uint16Array};
+use js_sys::{Reflect, Function, Array, Float64Array, Uint16Array};
+use std::sync::Arc;
 
 // --- Bounding Box ---
 
@@ -68,6 +78,7 @@ impl From<BoundingBox3D> for BoundingBox<3> {
 /// WASM wrapper for 3D Walls.
 #[wasm_bindgen]
 pub struct Wall3D {
+    id: i32,
     inner: Option<Wall<3>>,
 }
 
@@ -76,122 +87,139 @@ impl Wall3D {
     /// Creates a custom wall from a JavaScript object.
     ///
     /// The object must implement the `contains(point)` and `cut(generator, callback)` methods.
+    /// It is evaluated on the main thread, so custom walls cannot be combined with `init_threads`.
+    ///
+    /// @throws If the thread pool of `init_threads` is active.
     #[wasm_bindgen(js_name = newCustom)]
-    pub fn new_custom(val: JsValue, id: i32) -> Wall3D {
-        Wall3D {
-            inner: Some(Wall::new(id, Box::new(JsWallGeometry3D { val }))),
-        }
+    pub fn new_custom(val: JsValue, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(JsWallGeometry3D { val: JsObject::new(val)? }))
     }
 
     /// Returns the unique identifier of the wall.
     #[wasm_bindgen(getter)]
     pub fn id(&self) -> i32 {
-        self.inner.as_ref().unwrap().id()
+        self.id
     }
 
     /// Checks if a point is contained within the wall.
     pub fn contains(&self, x: f64, y: f64, z: f64) -> bool {
-        self.inner.as_ref().unwrap().contains(&[x, y, z])
+        self.inner.as_ref().is_some_and(|w| w.contains(&[x, y, z]))
     }
 
     /// Creates a plane wall defined by a point and a normal vector.
-    pub fn new_plane(px: f64, py: f64, pz: f64, nx: f64, ny: f64, nz: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(PlaneGeometry::new([px, py, pz], [nx, ny, nz])))) }
+    pub fn new_plane(px: f64, py: f64, pz: f64, nx: f64, ny: f64, nz: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(PlaneGeometry::new([px, py, pz], [nx, ny, nz])))
     }
 
     /// Creates a spherical wall.
-    pub fn new_sphere(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(SphereGeometry::new([cx, cy, cz], radius)))) }
+    pub fn new_sphere(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(SphereGeometry::new([cx, cy, cz], radius)))
     }
 
     /// Creates a cylindrical wall.
-    pub fn new_cylinder(cx: f64, cy: f64, cz: f64, ax: f64, ay: f64, az: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(CylinderGeometry::new([cx, cy, cz], [ax, ay, az], radius)))) }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_cylinder(cx: f64, cy: f64, cz: f64, ax: f64, ay: f64, az: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(CylinderGeometry::new([cx, cy, cz], [ax, ay, az], radius)))
     }
 
     /// Creates a conical wall.
-    pub fn new_cone(tx: f64, ty: f64, tz: f64, ax: f64, ay: f64, az: f64, angle: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConeGeometry::new([tx, ty, tz], [ax, ay, az], angle)))) }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_cone(tx: f64, ty: f64, tz: f64, ax: f64, ay: f64, az: f64, angle: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConeGeometry::new([tx, ty, tz], [ax, ay, az], angle)))
     }
 
     /// Creates a torus wall.
-    pub fn new_torus(cx: f64, cy: f64, cz: f64, ax: f64, ay: f64, az: f64, major: f64, minor: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(TorusGeometry::new([cx, cy, cz], [ax, ay, az], major, minor)))) }
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_torus(cx: f64, cy: f64, cz: f64, ax: f64, ay: f64, az: f64, major: f64, minor: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(TorusGeometry::new([cx, cy, cz], [ax, ay, az], major, minor)))
     }
 
     /// Creates a trefoil knot wall.
-    pub fn new_trefoil(cx: f64, cy: f64, cz: f64, scale: f64, tube_radius: f64, resolution: usize, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(TrefoilKnotGeometry::new([cx, cy, cz], scale, tube_radius, resolution)))) }
+    ///
+    /// @param sampling How the sample points are placed along the curve, by default at uniform parameter steps.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new_trefoil(cx: f64, cy: f64, cz: f64, scale: f64, tube_radius: f64, resolution: usize, id: i32, sampling: Option<WasmCurveSampling>) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(TrefoilKnotGeometry::with_sampling([cx, cy, cz], scale, tube_radius, resolution, curve_sampling(sampling))))
     }
 
     /// Creates a convex polyhedron wall from a list of points and normals.
-    pub fn new_convex_polyhedron(points: &[f64], normals: &[f64], id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new(points, normals)))) }
+    pub fn new_convex_polyhedron(points: &[f64], normals: &[f64], id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::try_new(points, normals)?))
+    }
+
+    /// Creates a convex polyhedron wall from the convex hull of a list of vertices.
+    pub fn new_convex_hull(points: &[f64], id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::from_vertices(points)?))
     }
 
     /// Creates a tetrahedron wall.
-    pub fn new_tetrahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new_tetrahedron([cx, cy, cz], radius)))) }
+    pub fn new_tetrahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::new_tetrahedron([cx, cy, cz], radius)))
     }
 
     /// Creates a hexahedron (cube) wall.
-    pub fn new_hexahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new_hexahedron([cx, cy, cz], radius)))) }
+    pub fn new_hexahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::new_hexahedron([cx, cy, cz], radius)))
     }
 
     /// Creates an octahedron wall.
-    pub fn new_octahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new_octahedron([cx, cy, cz], radius)))) }
+    pub fn new_octahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::new_octahedron([cx, cy, cz], radius)))
     }
 
     /// Creates a dodecahedron wall.
-    pub fn new_dodecahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([cx, cy, cz], radius)))) }
+    pub fn new_dodecahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([cx, cy, cz], radius)))
     }
 
     /// Creates an icosahedron wall.
-    pub fn new_icosahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Wall3D {
-        Wall3D { inner: Some(Wall::new(id, Box::new(ConvexPolyhedronGeometry::new_icosahedron([cx, cy, cz], radius)))) }
+    pub fn new_icosahedron(cx: f64, cy: f64, cz: f64, radius: f64, id: i32) -> Result<Wall3D, JsError> {
+        Wall3D::wrap(id, Box::new(ConvexPolyhedronGeometry::new_icosahedron([cx, cy, cz], radius)))
     }
 
     /// Creates a wall defined by a cubic Bezier curve tube.
-    pub fn new_bezier(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32) -> Wall3D {
+    ///
+    /// @param sampling How the sample points are placed along the curve, by default at uniform parameter steps.
+    pub fn new_bezier(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32, sampling: Option<WasmCurveSampling>) -> Result<Wall3D, JsError> {
         if points.len() != 12 {
-            panic!("Cubic Bezier curve requires exactly 4 control points (12 coordinates)");
+            return Err(JsError::new("Cubic Bezier curve requires exactly 4 control points (12 coordinates)"));
         }
         let p0 = [points[0], points[1], points[2]];
         let p1 = [points[3], points[4], points[5]];
         let p2 = [points[6], points[7], points[8]];
         let p3 = [points[9], points[10], points[11]];
-        Wall3D { inner: Some(Wall::new(id, Box::new(CubicBezierGeometry::new(p0, p1, p2, p3, radius, resolution, closed)))) }
+        Wall3D::wrap(id, Box::new(CubicBezierGeometry::with_sampling(p0, p1, p2, p3, radius, resolution, closed, curve_sampling(sampling))))
     }
 
     /// Creates a wall defined by a Catmull-Rom spline tube.
-    pub fn new_catmull_rom(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32) -> Wall3D {
-        if points.len() % 3 != 0 {
-            panic!("Catmull-Rom curve points must be a multiple of 3 coordinates");
+    ///
+    /// @param sampling How the sample points are placed along the curve, by default at uniform parameter steps.
+    pub fn new_catmull_rom(points: &[f64], radius: f64, resolution: usize, closed: bool, id: i32, sampling: Option<WasmCurveSampling>) -> Result<Wall3D, JsError> {
+        if !points.len().is_multiple_of(3) {
+            return Err(JsError::new("Catmull-Rom curve points must be a multiple of 3 coordinates"));
         }
         let mut control_points = Vec::with_capacity(points.len() / 3);
         for i in (0..points.len()).step_by(3) {
             control_points.push([points[i], points[i+1], points[i+2]]);
         }
-        Wall3D { inner: Some(Wall::new(id, Box::new(CatmullRomGeometry::new(control_points, radius, resolution, closed)))) }
+        Wall3D::wrap(id, Box::new(CatmullRomGeometry::with_sampling(control_points, radius, resolution, closed, curve_sampling(sampling))))
     }
 }
 
 impl Wall3D {
+    fn wrap(id: i32, geometry: Box<dyn WallGeometry<3>>) -> Result<Wall3D, JsError> {
+        Ok(Wall3D { id, inner: Some(Wall::try_new(id, geometry)?) })
+    }
+
     pub fn take_inner(&mut self) -> Option<Wall<3>> {
         self.inner.take()
     }
 }
 
 struct JsWallGeometry3D {
-    val: JsValue,
+    val: JsObject,
 }
 
-unsafe impl Send for JsWallGeometry3D {}
-unsafe impl Sync for JsWallGeometry3D {}
-
 impl std::fmt::Debug for JsWallGeometry3D {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "JsWallGeometry3D")
@@ -200,9 +228,10 @@ impl std::fmt::Debug for JsWallGeometry3D {
 
 impl WallGeometry<3> for JsWallGeometry3D {
     fn contains(&self, point: &[f64; 3]) -> bool {
-        if let Ok(func) = Reflect::get(&self.val, &"contains".into()).and_then(|f| f.dyn_into::<Function>()) {
+        let Some(val) = self.val.get() else { return false };
+        if let Ok(func) = Reflect::get(val, &"contains".into()).and_then(|f| f.dyn_into::<Function>()) {
             let args = Array::of3(&point[0].into(), &point[1].into(), &point[2].into());
-            if let Ok(res) = func.apply(&self.val, &args) {
+            if let Ok(res) = func.apply(val, &args) {
                 return res.as_bool().unwrap_or(false);
             }
         }
@@ -210,9 +239,10 @@ impl WallGeometry<3> for JsWallGeometry3D {
     }
 
     fn cut(&self, generator: &[f64; 3], callback: &mut dyn FnMut([f64; 3], [f64; 3])) {
-        if let Ok(func) = Reflect::get(&self.val, &"cut".into()).and_then(|f| f.dyn_into::<Function>()) {
+        let Some(val) = self.val.get() else { return };
+        if let Ok(func) = Reflect::get(val, &"cut".into()).and_then(|f| f.dyn_into::<Function>()) {
             let args = Array::of3(&generator[0].into(), &generator[1].into(), &generator[2].into());
-            if let Ok(res) = func.apply(&self.val, &args) {
+            if let Ok(res) = func.apply(val, &args) {
                 if res.is_null() || res.is_undefined() { return; }
                 
                 let process_item = |item: &JsValue| -> Option<([f64; 3], [f64; 3])> {
@@ -222,16 +252,14 @@ impl WallGeometry<3> for JsWallGeometry3D {
                 };
 
                 if Array::is_array(&res) {
-                    let arr: Array = res.dyn_into().unwrap();
+                    let arr: &Array = res.unchecked_ref();
                     for i in 0..arr.length() {
                         if let Some((p, n)) = process_item(&arr.get(i)) {
                             callback(p, n);
                         }
                     }
-                } else {
-                    if let Some((p, n)) = process_item(&res) {
-                        callback(p, n);
-                    }
+                } else if let Some((p, n)) = process_item(&res) {
+                    callback(p, n);
                 }
             }
         }
@@ -243,7 +271,7 @@ impl WallGeometry<3> for JsWallGeometry3D {
 /// Represents a 3D Voronoi cell.
 #[wasm_bindgen(js_name = Cell3D)]
 pub struct Cell3D {
-    inner: Cell3DFaces,
+    pub(crate) inner: Cell3DFaces,
 }
 
 #[wasm_bindgen(js_class = Cell3D)]
@@ -263,12 +291,27 @@ impl Cell3D {
     /// The neighbor IDs for each face.
     #[wasm_bindgen(getter)]
     pub fn face_neighbors(&self) -> Vec<i32> { self.inner.face_neighbors() }
+    /// Whether a clip was rejected because the cell exceeded its vertex index capacity.
+    #[wasm_bindgen(getter)]
+    pub fn overflowed(&self) -> bool { self.inner.overflowed() }
     /// Calculates the volume of the cell.
     pub fn volume(&self) -> f64 { self.inner.volume() }
+    /// Calculates the diameter of the cell, the largest distance between two of its vertices.
+    pub fn diameter(&self) -> f64 { Cell::diameter(&self.inner) }
     /// Calculates the centroid of the cell.
     pub fn centroid(&self) -> Vec<f64> { self.inner.centroid().to_vec() }
     /// Calculates the area of a specific face.
     pub fn face_area(&self, face_index: usize) -> f64 { self.inner.face_area(face_index) }
+    /// Calculates the interior dihedral angle (in radians) at each edge.
+    pub fn dihedral_angles(&self) -> Vec<f64> { self.inner.dihedral_angles() }
+    /// Calculates the interior solid angle (in steradians) at each vertex.
+    pub fn solid_angles(&self) -> Vec<f64> { self.inner.solid_angles() }
+    /// Calculates the interface tensor, the sum of the face areas times the outer products of the
+    /// face normals, as a row-major 3x3 matrix.
+    pub fn interface_tensor(&self) -> Vec<f64> { self.inner.interface_tensor().concat() }
+    /// Calculates planar texture coordinates in world units for the vertices of each face,
+    /// as a flat array [u0, v0, u1, v1, ...] in the order of `face_indices`.
+    pub fn face_uvs(&self) -> Vec<f64> { self.inner.face_uvs().into_iter().flatten().flatten().collect() }
     // Workaround for the fact that wasm-bindgen does not support nested vectors directly
     #[wasm_bindgen(js_name = faces)]
     pub fn wasm_faces(&self) -> Array {
@@ -288,12 +331,291 @@ impl Cell3D {
     }
 }
 
+/// The data to interpolate a cell between two states of a `Tessellation3D`, for smooth animations.
+#[wasm_bindgen(js_name = CellMorph3D)]
+pub struct CellMorph3D {
+    inner: CellMorph<3>,
+}
+
+#[wasm_bindgen(js_class = CellMorph3D)]
+impl CellMorph3D {
+    /// The index of the cell in both states.
+    #[wasm_bindgen(getter)]
+    pub fn index(&self) -> usize { self.inner.index }
+    /// Computes the cell between the states.
+    ///
+    /// @param t The interpolation parameter, from 0 for the previous to 1 for the current state.
+    pub fn at(&self, t: f64) -> Cell3D { Cell3D { inner: self.inner.at(t) } }
+}
+
+/// A 3D Voronoi cell with its vertices quantized to 16 bits within its bounding box.
+#[wasm_bindgen(js_name = QuantizedCell3D)]
+pub struct QuantizedCell3D {
+    inner: QuantizedCell<3>,
+}
+
+#[wasm_bindgen(js_class = QuantizedCell3D)]
+impl QuantizedCell3D {
+    /// The lower corner of the bounding box of the vertices.
+    #[wasm_bindgen(getter)]
+    pub fn min(&self) -> Vec<f64> { self.inner.min.to_vec() }
+    /// The upper corner of the bounding box of the vertices.
+    #[wasm_bindgen(getter)]
+    pub fn max(&self) -> Vec<f64> { self.inner.max.to_vec() }
+    /// The quantized vertices as a flat array, mapping 0 to 65535 to the bounding box.
+    #[wasm_bindgen(getter)]
+    pub fn quantized_vertices(&self) -> Vec<u16> { self.inner.vertices.clone() }
+    /// The dequantized vertices as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn vertices(&self) -> Vec<f64> { self.inner.dequantized_vertices() }
+    /// The number of vertices for each face.
+    #[wasm_bindgen(getter)]
+    pub fn face_counts(&self) -> Vec<u16> { self.inner.face_counts.clone() }
+    /// The indices of vertices forming the faces.
+    #[wasm_bindgen(getter)]
+    pub fn face_indices(&self) -> Vec<u16> { self.inner.face_indices.clone() }
+    /// The neighbor IDs for each face.
+    #[wasm_bindgen(getter)]
+    pub fn face_neighbors(&self) -> Vec<i32> { self.inner.neighbors.clone() }
+    /// The volume of the cell, computed before quantization.
+    #[wasm_bindgen(getter)]
+    pub fn volume(&self) -> f64 { self.inner.volume }
+}
+
+/// A closed triangle mesh of a solid generated from a `Tessellation3D`.
+#[wasm_bindgen(js_name = Mesh3D)]
+pub struct Mesh3D {
+    inner: SolidMesh,
+}
+
+#[wasm_bindgen(js_class = Mesh3D)]
+impl Mesh3D {
+    /// The vertices of the mesh as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn vertices(&self) -> Vec<f64> { self.inner.vertices.clone() }
+    /// The vertex indices of the triangles as a flat array, counter-clockwise seen from outside.
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> { self.inner.triangles.iter().flatten().map(|&i| i as u32).collect() }
+    /// Calculates the enclosed volume.
+    pub fn volume(&self) -> f64 { self.inner.volume() }
+}
+
+/// A triangle mesh of the surface of a wall, for rendering the wall along with the cells.
+#[wasm_bindgen(js_name = WallMesh3D)]
+pub struct WallMesh3D {
+    inner: WallMesh<3>,
+}
+
+#[wasm_bindgen(js_class = WallMesh3D)]
+impl WallMesh3D {
+    /// The vertices of the mesh as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn vertices(&self) -> Vec<f64> { self.inner.vertices.clone() }
+    /// The vertex indices of the triangles as a flat array, counter-clockwise seen from outside the valid region.
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> { self.inner.elements.iter().flatten().map(|&i| i as u32).collect() }
+    /// Creates the mesh of the convex hull of a list of points, matching `Wall3D.new_convex_hull`.
+    ///
+    /// @param points The points as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    pub fn convex_hull(points: &[f64]) -> Result<WallMesh3D, JsError> { Ok(WallMesh3D { inner: crate::convex_hull(points)?.mesh }) }
+}
+
+/// The cells of a `Tessellation3D` published by `swap`, which stay unchanged while the
+/// tessellation calculates its next results.
+#[wasm_bindgen(js_name = CellSnapshot3D)]
+pub struct CellSnapshot3D {
+    inner: Arc<CellSnapshot<Cell3DFaces>>,
+}
+
+#[wasm_bindgen(js_class = CellSnapshot3D)]
+impl CellSnapshot3D {
+    /// The number of swaps before this snapshot was published, or 0 before the first swap.
+    #[wasm_bindgen(getter)]
+    pub fn generation(&self) -> u64 { self.inner.generation }
+    /// The number of cells in the snapshot.
+    #[wasm_bindgen(getter)]
+    pub fn count_cells(&self) -> usize { self.inner.cells.len() }
+    /// The generators of the cells as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn generators(&self) -> Vec<f64> { self.inner.generators.clone() }
+    /// Returns the cell at a given index.
+    pub fn get_cell(&self, index: usize) -> Option<Cell3D> { self.inner.cells.get(index).map(|c| Cell3D { inner: c.clone() }) }
+}
+
+/// A tetrahedral volume mesh generated from a `Tessellation3D`.
+#[wasm_bindgen(js_name = TetMesh3D)]
+pub struct TetMesh3D {
+    inner: TetMesh,
+}
+
+#[wasm_bindgen(js_class = TetMesh3D)]
+impl TetMesh3D {
+    /// The vertices of the mesh as a flat array [x0, y0, z0, x1, y1, z1, ...].
+    #[wasm_bindgen(getter)]
+    pub fn vertices(&self) -> Vec<f64> { self.inner.vertices.clone() }
+    /// The vertex indices of the positively oriented tetrahedra as a flat array.
+    #[wasm_bindgen(getter)]
+    pub fn indices(&self) -> Vec<u32> { self.inner.tetrahedra.iter().flatten().map(|&i| i as u32).collect() }
+    /// The cell of each tetrahedron.
+    #[wasm_bindgen(getter)]
+    pub fn cells(&self) -> Vec<u32> { self.inner.cells.iter().map(|&i| i as u32).collect() }
+}
+
+// --- Spatial Algorithm ---
+
+/// Selects the spatial algorithm used for the neighbor search of a `Tessellation3D`.
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpatialAlgorithm3D {
+    /// A uniform grid.
+    Grid,
+    /// A grid with bin sizes adapted to the density of the generators.
+    AdaptiveGrid,
+    /// A uniform grid searched through a hierarchy over its occupied bins, for clustered generators.
+    BinnedBvh,
+    /// An octree with up to 8 generators per leaf.
+    Octree,
+}
+
+/// Dispatches to the spatial algorithm selected by a [`SpatialAlgorithm3D`].
+enum WasmAlgorithm3D {
+    Grid(Algorithm3DGrid),
+    AdaptiveGrid(AlgorithmAdaptiveGrid),
+    BinnedBvh(AlgorithmBinnedBvh),
+    Octree(Algorithm3DOctree),
+}
+
+impl WasmAlgorithm3D {
+    /// Creates the selected algorithm with a grid resolution chosen for `count` generators,
+    /// or an octree with up to 8 generators per leaf.
+    fn auto(kind: SpatialAlgorithm3D, count: usize, bounds: &BoundingBox<3>) -> Self {
+        match kind {
+            SpatialAlgorithm3D::Grid => WasmAlgorithm3D::Grid(Algorithm3DGrid::auto(count, bounds)),
+            SpatialAlgorithm3D::AdaptiveGrid => WasmAlgorithm3D::AdaptiveGrid(AlgorithmAdaptiveGrid::auto(count, bounds)),
+            SpatialAlgorithm3D::BinnedBvh => WasmAlgorithm3D::BinnedBvh(AlgorithmBinnedBvh::auto(count, bounds)),
+            SpatialAlgorithm3D::Octree => WasmAlgorithm3D::Octree(Algorithm3DOctree::new(*bounds, 8)),
+        }
+    }
+
+    /// Creates the selected algorithm from `params`, the resolution [nx, ny, nz] for the grids or
+    /// the leaf capacity for the octree, falling back to [`WasmAlgorithm3D::auto`] without them.
+    fn with_params(kind: SpatialAlgorithm3D, params: &[usize], count: usize, bounds: &BoundingBox<3>) -> Result<Self, JsError> {
+        let positive = params.iter().all(|&p| p > 0);
+        match (kind, params) {
+            (_, []) => Ok(Self::auto(kind, count, bounds)),
+            (SpatialAlgorithm3D::Grid, &[nx, ny, nz]) if positive => Ok(WasmAlgorithm3D::Grid(Algorithm3DGrid::new(nx, ny, nz, bounds))),
+            (SpatialAlgorithm3D::AdaptiveGrid, &[nx, ny, nz]) if positive => Ok(WasmAlgorithm3D::AdaptiveGrid(AlgorithmAdaptiveGrid::new(nx, ny, nz, bounds))),
+            (SpatialAlgorithm3D::BinnedBvh, &[nx, ny, nz]) if positive => Ok(WasmAlgorithm3D::BinnedBvh(AlgorithmBinnedBvh::new(nx, ny, nz, bounds))),
+            (SpatialAlgorithm3D::Octree, &[capacity]) if positive => Ok(WasmAlgorithm3D::Octree(Algorithm3DOctree::new(*bounds, capacity))),
+            _ => Err(JsError::new("Expected a positive resolution [nx, ny, nz] for a grid or a positive leaf capacity for the octree")),
+        }
+    }
+
+    fn kind(&self) -> SpatialAlgorithm3D {
+        match self {
+            WasmAlgorithm3D::Grid(_) => SpatialAlgorithm3D::Grid,
+            WasmAlgorithm3D::AdaptiveGrid(_) => SpatialAlgorithm3D::AdaptiveGrid,
+            WasmAlgorithm3D::BinnedBvh(_) => SpatialAlgorithm3D::BinnedBvh,
+            WasmAlgorithm3D::Octree(_) => SpatialAlgorithm3D::Octree,
+        }
+    }
+
+    fn resolution(&self) -> Vec<usize> {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.resolution().to_vec(),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.resolution().to_vec(),
+            WasmAlgorithm3D::BinnedBvh(a) => a.resolution().to_vec(),
+            WasmAlgorithm3D::Octree(_) => Vec::new(),
+        }
+    }
+
+    fn bin_of_generator(&self, index: usize) -> Option<usize> {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.bin_of_generator(index),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.bin_of_generator(index),
+            WasmAlgorithm3D::BinnedBvh(a) => a.bin_of_generator(index),
+            WasmAlgorithm3D::Octree(_) => None,
+        }
+    }
+
+    fn bin_occupancy(&self) -> Vec<usize> {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.bin_occupancy(),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.bin_occupancy(),
+            WasmAlgorithm3D::BinnedBvh(a) => a.bin_occupancy(),
+            WasmAlgorithm3D::Octree(_) => Vec::new(),
+        }
+    }
+}
+
+impl SpatialAlgorithm<3> for WasmAlgorithm3D {
+    fn set_generators(&mut self, generators: &[f64], bounds: &BoundingBox<3>) {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.set_generators(generators, bounds),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.set_generators(generators, bounds),
+            WasmAlgorithm3D::BinnedBvh(a) => a.set_generators(generators, bounds),
+            WasmAlgorithm3D::Octree(a) => a.set_generators(generators, bounds),
+        }
+    }
+
+    fn update_generator(&mut self, index: usize, old_pos: &[f64; 3], new_pos: &[f64; 3], bounds: &BoundingBox<3>) {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.update_generator(index, old_pos, new_pos, bounds),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.update_generator(index, old_pos, new_pos, bounds),
+            WasmAlgorithm3D::BinnedBvh(a) => a.update_generator(index, old_pos, new_pos, bounds),
+            WasmAlgorithm3D::Octree(a) => a.update_generator(index, old_pos, new_pos, bounds),
+        }
+    }
+
+    fn visit_neighbors<F>(&self, generators: &[f64], index: usize, pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.visit_neighbors(generators, index, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.visit_neighbors(generators, index, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::BinnedBvh(a) => a.visit_neighbors(generators, index, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::Octree(a) => a.visit_neighbors(generators, index, pos, max_dist_sq, visitor),
+        }
+    }
+
+    fn visit_near_point<F>(&self, generators: &[f64], pos: [f64; 3], max_dist_sq: &mut f64, visitor: F)
+    where
+        F: FnMut(usize, [f64; 3], f64) -> f64,
+    {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.visit_near_point(generators, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.visit_near_point(generators, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::BinnedBvh(a) => a.visit_near_point(generators, pos, max_dist_sq, visitor),
+            WasmAlgorithm3D::Octree(a) => a.visit_near_point(generators, pos, max_dist_sq, visitor),
+        }
+    }
+
+    fn candidate_buffer(&self) -> usize {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.candidate_buffer(),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.candidate_buffer(),
+            WasmAlgorithm3D::BinnedBvh(a) => a.candidate_buffer(),
+            WasmAlgorithm3D::Octree(a) => a.candidate_buffer(),
+        }
+    }
+
+    fn candidate_priority(&self, pos: &[f64; 3], candidate: &[f64; 3]) -> f64 {
+        match self {
+            WasmAlgorithm3D::Grid(a) => a.candidate_priority(pos, candidate),
+            WasmAlgorithm3D::AdaptiveGrid(a) => a.candidate_priority(pos, candidate),
+            WasmAlgorithm3D::BinnedBvh(a) => a.candidate_priority(pos, candidate),
+            WasmAlgorithm3D::Octree(a) => a.candidate_priority(pos, candidate),
+        }
+    }
+}
+
 // --- Tessellation ---
 
 /// The main 3D Voronoi tessellation class.
 #[wasm_bindgen(js_name = Tessellation3D)]
 pub struct Tessellation3D {
-    inner: Tessellation<3, Cell3DFaces, Algorithm3DGrid>,
+    inner: Tessellation<3, Cell3DFaces, WasmAlgorithm3D>,
 }
 
 #[wasm_bindgen(js_class = Tessellation3D)]
@@ -307,26 +629,174 @@ impl Tessellation3D {
     #[wasm_bindgen(constructor)]
     pub fn new(bounds: BoundingBox3D, nx: usize, ny: usize, nz: usize) -> Tessellation3D {
         let b: BoundingBox<3> = bounds.into();
-        Tessellation3D { inner: Tessellation::new(b, Algorithm3DGrid::new(nx, ny, nz, &b)) }
+        Tessellation3D { inner: Tessellation::new(b, WasmAlgorithm3D::Grid(Algorithm3DGrid::new(nx, ny, nz, &b))) }
+    }
+    /// Creates a new 3D tessellation with a grid resolution chosen for the number of generators
+    /// and the aspect ratio of the bounding box.
+    ///
+    /// @param bounds The bounding box of the simulation.
+    /// @param count The expected number of generators.
+    pub fn new_auto(bounds: BoundingBox3D, count: usize) -> Tessellation3D {
+        let b: BoundingBox<3> = bounds.into();
+        Tessellation3D { inner: Tessellation::new(b, WasmAlgorithm3D::Grid(Algorithm3DGrid::auto(count, &b))) }
+    }
+    /// Creates a new 3D tessellation using the given spatial algorithm, with a grid resolution
+    /// chosen for the number of generators and the aspect ratio of the bounding box.
+    ///
+    /// @param bounds The bounding box of the simulation.
+    /// @param algorithm The spatial algorithm for the neighbor search.
+    /// @param count The expected number of generators.
+    pub fn new_with_algorithm(bounds: BoundingBox3D, algorithm: SpatialAlgorithm3D, count: usize) -> Tessellation3D {
+        let b: BoundingBox<3> = bounds.into();
+        Tessellation3D { inner: Tessellation::new(b, WasmAlgorithm3D::auto(algorithm, count, &b)) }
     }
+    /// Switches the spatial algorithm and indexes the current generators with it, for example
+    /// after measuring how clustered they are. The computed cells remain valid.
+    ///
+    /// @param algorithm The spatial algorithm for the neighbor search.
+    /// @param params The resolution [nx, ny, nz] for a grid or the leaf capacity for the octree.
+    ///   If omitted, the resolution is chosen for the current generators and the capacity is 8.
+    /// @throws If the parameters do not match the algorithm.
+    pub fn set_algorithm(&mut self, algorithm: SpatialAlgorithm3D, params: Option<Vec<usize>>) -> Result<(), JsError> {
+        let count = self.inner.count_generators();
+        let algorithm = WasmAlgorithm3D::with_params(algorithm, &params.unwrap_or_default(), count, &self.inner.bounds)?;
+        self.inner.set_algorithm(algorithm);
+        Ok(())
+    }
+    /// The spatial algorithm used for the neighbor search.
+    #[wasm_bindgen(getter)]
+    pub fn algorithm(&self) -> SpatialAlgorithm3D { self.inner.algorithm.kind() }
+    /// The number of grid bins along each axis, [nx, ny, nz], or an empty array for the octree.
+    #[wasm_bindgen(getter)]
+    pub fn grid_resolution(&self) -> Vec<usize> { self.inner.algorithm.resolution() }
+    /// Returns the linear index of the grid bin holding a generator, `x + nx * (y + ny * z)`,
+    /// or undefined for the octree or if there is no such generator.
+    pub fn bin_of_generator(&self, index: usize) -> Option<usize> { self.inner.algorithm.bin_of_generator(index) }
+    /// Returns the number of generators in each grid bin, in the order of the linear bin indices,
+    /// or an empty array for the octree.
+    pub fn bin_occupancy(&self) -> Vec<usize> { self.inner.algorithm.bin_occupancy() }
     /// Sets the generator points.
     ///
     /// @param generators A flat array of coordinates [x0, y0, z0, x1, y1, z1, ...].
-    pub fn set_generators(&mut self, generators: &[f64]) { self.inner.set_generators(generators); }
+    /// @throws If any coordinate is NaN or infinite.
+    pub fn set_generators(&mut self, generators: &[f64]) -> Result<(), JsError> { Ok(self.inner.set_generators(generators)?) }
+    /// Sets the generator points without checking for NaN or infinite coordinates.
+    pub fn set_generators_unchecked(&mut self, generators: &[f64]) { self.inner.set_generators_unchecked(generators); }
+    /// Registers an external ID for each generator, which stays attached when walls prune generators.
+    ///
+    /// @param ids One unique ID per generator, including ghosts.
+    pub fn set_generator_ids(&mut self, ids: Vec<u64>) -> Result<(), JsError> { Ok(self.inner.set_generator_ids(&ids)?) }
+    /// Returns the external ID of a generator, or its index if no IDs are registered.
+    pub fn generator_id(&self, index: usize) -> u64 { self.inner.generator_id(index) }
+    /// Groups the generators into logical sites whose cells are merged, such as the sub-points of a particle.
+    ///
+    /// @param sites The site ID of each generator, including ghosts.
+    pub fn set_sites(&mut self, sites: Vec<usize>) -> Result<(), JsError> { Ok(self.inner.set_sites(&sites)?) }
+    /// Returns the site ID of a generator, or its index if no sites are set.
+    pub fn site_of(&self, index: usize) -> usize { self.inner.site_of(index) }
+    /// Returns the index of the generator with an external ID, if any.
+    pub fn generator_index(&self, id: u64) -> Option<usize> { self.inner.generator_index(id) }
     /// Updates a specific generator's position.
     pub fn set_generator(&mut self, index: usize, x: f64, y: f64, z: f64) { self.inner.set_generator(index, &[x, y, z]); }
+    /// Finds the generator closest to a point within a radius, such as the one under the cursor.
+    ///
+    /// @param radius The largest distance from the point.
+    pub fn find_generator_near(&self, x: f64, y: f64, z: f64, radius: f64) -> Option<usize> { self.inner.find_generator_near(&[x, y, z], radius) }
+    /// Updates the positions of many generators in one call.
+    ///
+    /// @param indices The indices of the generators to move.
+    /// @param positions The new positions as a flat array, one per index.
+    /// @returns The number of generators moved.
+    pub fn set_generators_sparse(&mut self, indices: &[u32], positions: &[f64]) -> Result<usize, JsError> {
+        let indices: Vec<usize> = indices.iter().map(|&i| i as usize).collect();
+        Ok(self.inner.set_generators_sparse(&indices, positions)?)
+    }
+    /// Moves each generator towards its target by at most a step length, staying inside the walls.
+    ///
+    /// @param new_positions A flat array of target coordinates [x0, y0, z0, x1, y1, z1, ...] for all generators.
+    /// @param max_step The maximum distance a generator moves.
+    /// @returns The number of generators held back from their target.
+    /// @throws If the number of positions does not match or any coordinate is NaN or infinite.
+    pub fn limit_displacement(&mut self, new_positions: &[f64], max_step: f64) -> Result<usize, JsError> { Ok(self.inner.limit_displacement(new_positions, max_step)?) }
+    /// Moves generators to the closest point on a wall or bounding box side, at an inward offset.
+    ///
+    /// @param indices The indices of the generators to move.
+    /// @param wall_id The ID of the wall or bounding box side.
+    /// @param offset The distance inside the surface to place the generators at.
+    /// @returns The number of generators moved.
+    pub fn project_generators_to_wall(&mut self, indices: &[u32], wall_id: i32, offset: f64) -> usize {
+        let indices: Vec<usize> = indices.iter().map(|&i| i as usize).collect();
+        self.inner.project_generators_to_wall(&indices, wall_id, offset)
+    }
     /// Generates random points within the bounds and walls.
-    pub fn random_generators(&mut self, count: usize) { self.inner.random_generators(count); }   
+    pub fn random_generators(&mut self, count: usize) { self.inner.random_generators(count); }
+    /// Generates random points within the bounds and walls from a fixed seed, for reproducible results.
+    pub fn random_generators_seeded(&mut self, count: usize, seed: u32) { self.inner.random_generators_seeded(count, seed as u64); }
+    /// Places one generator in each sub-cell of a regular grid, moved from its center by up to
+    /// `jitter` times half the sub-cell size, as a fast initialization for relaxation.
+    pub fn stratified_generators(&mut self, nx: usize, ny: usize, nz: usize, jitter: f64) { self.inner.stratified_generators([nx, ny, nz], jitter); }
     /// Reads generators from a string representation.
     ///
     /// Each line should contain an ID followed by coordinates (e.g., "id x y z").
-    pub fn read_generators(&mut self, input: &str) { self.inner.read_generators(input); } 
+    pub fn read_generators(&mut self, input: &str) -> Result<(), JsError> { Ok(self.inner.read_generators(input)?) } 
+    /// Reads generators from a binary buffer, such as a Node.js `Buffer`.
+    ///
+    /// @param bytes Little-endian 64-bit floats [x0, y0, z0, x1, y1, z1, ...].
+    /// @throws If the length is not a multiple of the generator size, or any coordinate is NaN or infinite.
+    pub fn read_generators_buffer(&mut self, bytes: &[u8]) -> Result<(), JsError> { Ok(self.inner.read_generators_bytes(bytes)?) }
     /// Adds a wall to the tessellation.
     pub fn add_wall(&mut self, mut wall: Wall3D) { if let Some(w) = wall.take_inner() { self.inner.add_wall(w); } }
     /// Removes all walls.
     pub fn clear_walls(&mut self) { self.inner.clear_walls(); }
+    /// Meshes the surface of a wall within the bounds for rendering, or returns undefined if there is no wall with the ID.
+    ///
+    /// @param wall_id The ID of the wall.
+    /// @param resolution The number of grid cells along each axis.
+    pub fn wall_preview_mesh(&self, wall_id: i32, resolution: usize) -> Option<WallMesh3D> {
+        let wall = self.inner.walls.iter().find(|w| w.id() == wall_id)?;
+        Some(WallMesh3D { inner: wall.preview_mesh(&self.inner.bounds, resolution) })
+    }
     /// Calculates the Voronoi tessellation.
     pub fn calculate(&mut self) { self.inner.calculate(); }
+    /// Calculates the Voronoi tessellation, warm-started from the previous cells for generators
+    /// that moved only slightly, as between the frames of a simulation.
+    pub fn calculate_warm(&mut self) { self.inner.calculate_warm(); }
+    /// Calculates approximate cells quickly for interactive previews, clipping each cell by at most
+    /// a bounded number of neighbor candidates.
+    ///
+    /// @param max_neighbors_per_cell The number of candidates that may clip each cell.
+    pub fn preview_calculate(&mut self, max_neighbors_per_cell: usize) { self.inner.preview_calculate(max_neighbors_per_cell); }
+    /// Returns whether a cell is exact, which is false only for cells of `preview_calculate` that ran out of budget.
+    pub fn is_cell_exact(&self, index: usize) -> bool { self.inner.is_cell_exact(index) }
+    /// Opens or closes a side of the bounding box, clipping cells beyond it by a margin.
+    ///
+    /// @param axis The axis of the side.
+    /// @param is_max Whether it is the side at the maximum of the axis.
+    /// @param margin The margin beyond the side, or omitted to close it.
+    pub fn set_open_face(&mut self, axis: usize, is_max: bool, margin: Option<f64>) -> Result<(), JsError> { Ok(self.inner.set_open_face(axis, is_max, margin)?) }
+    /// Returns whether a cell touches an open side of the bounding box.
+    pub fn is_cell_unbounded(&self, index: usize) -> bool { self.inner.is_cell_unbounded(index) }
+    /// Sets how many neighbor candidates are held back to clip the nearest first, or restores the
+    /// default of the spatial algorithm when omitted.
+    ///
+    /// @param buffer The number of held back candidates, 0 for the native order.
+    pub fn set_candidate_buffer(&mut self, buffer: Option<usize>) { self.inner.set_candidate_buffer(buffer); }
+    /// Returns the indices of the generators a cell is clipped against, in the order of clipping.
+    pub fn candidate_order(&self, index: usize) -> Vec<u32> { self.inner.candidate_order(index).into_iter().map(|j| j as u32).collect() }
+    /// Publishes the current cells as a new snapshot, returning its generation.
+    pub fn swap(&mut self) -> u64 { self.inner.swap() }
+    /// Returns the latest snapshot published by `swap`, which stays unchanged by later calculations.
+    pub fn snapshot(&self) -> CellSnapshot3D { CellSnapshot3D { inner: self.inner.published() } }
+    /// Recalculates only the cells whose generators lie in a region, or whose previous cells intersect it.
+    ///
+    /// @param region The region enclosing the edited generators and the cells they touch.
+    /// @returns The indices of the recalculated cells.
+    pub fn recalculate_region(&mut self, region: BoundingBox3D) -> Vec<usize> { self.inner.recalculate_region(&region.into()) }
+    /// Calculates the Voronoi tessellation without storing it, returning the cells quantized
+    /// to about a quarter of the memory.
+    pub fn calculate_quantized(&self) -> Vec<QuantizedCell3D> {
+        self.inner.calculate_quantized().into_iter().map(|inner| QuantizedCell3D { inner }).collect()
+    }
     /// Calculates the Voronoi tessellation and seals the boundaries.
     pub fn calculate_sealed(&mut self) { self.inner.calculate_sealed(); }
     /// Runs a post-processing pass to prune the cell faces at the boundaries.
@@ -339,16 +809,84 @@ impl Tessellation3D {
     /// Returns the number of computed cells.
     #[wasm_bindgen(getter)]
     pub fn count_cells(&self) -> usize { self.inner.count_cells() }
-    /// Gets a generator's position by index.
-    pub fn get_generator(&self, index: usize) -> Vec<f64> { self.inner.get_generator(index).to_vec() }
+    /// Returns the largest diameter of the computed cells, or zero without cells.
+    pub fn max_cell_diameter(&self) -> f64 { self.inner.max_cell_diameter() }
+    /// Gets a generator's position by index, throwing an error if it is out of range.
+    pub fn get_generator(&self, index: usize) -> Result<Vec<f64>, JsError> {
+        self.inner.try_get_generator(index).map(|g| g.to_vec()).ok_or_else(|| JsError::new("Generator index out of range"))
+    }
     /// Gets a cell by index.
     pub fn get_cell(&self, index: usize) -> Option<Cell3D> { self.inner.get_cell(index).map(|inner| Cell3D { inner }) }
     /// Returns all generators as a flat array.
     #[wasm_bindgen(getter)]
     pub fn generators(&self) -> Vec<f64> { self.inner.generators() }
+    /// Returns the generators as a binary buffer of little-endian 64-bit floats.
+    pub fn generators_buffer(&self) -> Vec<u8> { self.inner.generators_bytes() }
+    /// Returns the cells within a number of steps through shared faces, starting with the cell itself.
+    ///
+    /// @param index The index of the cell at the center.
+    /// @param depth The largest number of steps.
+    pub fn neighbor_shell(&self, index: usize, depth: usize) -> Vec<usize> { self.inner.neighbor_shell(index, depth) }
+    /// Returns a color index per cell such that adjacent cells never share a color, or an empty
+    /// array if more colors would be needed.
+    ///
+    /// @param max_colors The number of available colors.
+    pub fn color_cells(&self, max_colors: usize) -> Vec<usize> { self.inner.color_cells(max_colors).unwrap_or_default() }
     /// Returns all cells.
     #[wasm_bindgen(getter)]
     pub fn cells(&self) -> Vec<Cell3D> { self.inner.cells().into_iter().map(|inner| Cell3D { inner }).collect() }
+    /// Returns a hash of the computed cells which is stable across platforms, to detect drift of the results.
+    pub fn fingerprint(&self) -> u64 { self.inner.fingerprint() }
+    /// Returns the data to interpolate each cell towards the cell with the same index in another state.
+    ///
+    /// @param other The next state, calculated with the same bounds and walls.
+    pub fn morph_to(&self, other: &Tessellation3D) -> Vec<CellMorph3D> {
+        self.inner.morph_to(&other.inner).into_iter().map(|inner| CellMorph3D { inner }).collect()
+    }
+    /// Converts the edge network of the cells into a solid lattice of struts joined at spherical nodes.
+    ///
+    /// @param radius The radius of the struts and nodes.
+    /// @param segments The number of sides of the struts and around the nodes.
+    pub fn strut_mesh(&self, radius: f64, segments: usize) -> Mesh3D { Mesh3D { inner: self.inner.strut_mesh(radius, segments) } }
+    /// Generates the solid material between the cell boundaries and the cells inset by a wall thickness.
+    ///
+    /// @param thickness The thickness of the walls between and around the cells.
+    pub fn shell_mesh(&self, thickness: f64) -> Mesh3D { Mesh3D { inner: self.inner.shell_mesh(thickness) } }
+    /// Decomposes all cells into a conforming tetrahedral volume mesh.
+    ///
+    /// @param fan Whether to connect a vertex of each cell to its faces instead of adding the vertex average.
+    pub fn tetrahedralize(&self, fan: bool) -> TetMesh3D {
+        let method = if fan { Tetrahedralization::Fan } else { Tetrahedralization::Centroid };
+        TetMesh3D { inner: self.inner.tetrahedralize(method) }
+    }
+    /// Generates a mesh of each cell with the faces subdivided and displaced by seeded noise,
+    /// keeping the faces shared by neighboring cells consistent.
+    ///
+    /// @param subdivisions The number of extra subdivisions of each face triangle edge.
+    /// @param amplitude The largest displacement along the face normals.
+    /// @param frequency The spatial frequency of the noise.
+    /// @param seed The seed of the noise.
+    pub fn displaced_cell_meshes(&self, subdivisions: usize, amplitude: f64, frequency: f64, seed: u32) -> Vec<Mesh3D> {
+        self.inner.displaced_cell_meshes(subdivisions, amplitude, frequency, seed as u64).into_iter().map(|inner| Mesh3D { inner }).collect()
+    }
+    /// Returns the nearest wall or bounding box side of each generator as a flat array
+    /// [wall_id, signed_distance, ...], with positive distances inside.
+    pub fn wall_distances(&self) -> Vec<f64> {
+        self.inner.wall_distances().into_iter().flat_map(|(id, d)| [id as f64, d]).collect()
+    }
+    /// Returns the translation of each cell for an exploded view as a flat array [x, y, z, ...].
+    ///
+    /// @param origin The point to push the cells away from, or the centroid of all cells if omitted.
+    /// @param factor The offset of each cell relative to the offset of its centroid from the origin.
+    pub fn exploded_transforms(&self, origin: Option<Vec<f64>>, factor: f64) -> Vec<f64> {
+        let origin = origin.filter(|o| o.len() >= 3).map(|o| std::array::from_fn(|k| o[k]));
+        self.inner.exploded_transforms(origin, factor).into_iter().flatten().collect()
+    }
+    /// Returns the deduplicated vertices of each cell without face data, as an array with one
+    /// Float64Array [x, y, z, ...] per cell, for the convex hull colliders of physics engines.
+    pub fn convex_hull_points(&self) -> Array {
+        self.inner.convex_hull_points().iter().map(|points| Float64Array::from(points.as_slice())).collect()
+    }
     /// Returns the seal log as a flat array [cell_id, neighbor_id, wall_id, ...].
     #[wasm_bindgen(getter)]
     pub fn seal_log(&self) -> Vec<i32> { self.inner.seal_log.clone() }
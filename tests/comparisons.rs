@@ -23,10 +23,10 @@ fn test_comparisons_face_counts() {
     let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, algo);
 
     // Add a spherical wall with radius 8
-    tess.add_wall(Wall::new(
+    tess.add_wall(Wall::try_new(
         WALL_ID_MAX,
         Box::new(SphereGeometry::new([0.0, 0.0, 0.0], 8.0)),
-    ));
+    ).unwrap());
 
     // Import generators from the input file
     tess.import_generators(&input_path)
@@ -124,10 +124,10 @@ fn test_comparisons_vertex_positions() {
     let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, algo);
 
     // Add a spherical wall with radius 8
-    tess.add_wall(Wall::new(
+    tess.add_wall(Wall::try_new(
         WALL_ID_MAX,
         Box::new(SphereGeometry::new([0.0, 0.0, 0.0], 8.0)),
-    ));
+    ).unwrap());
 
     // Import generators from the input file
     tess.import_generators(&input_path)
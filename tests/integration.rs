@@ -1,4 +1,5 @@
-use voronoid::{BoundingBox, Cell3DFaces, Algorithm3DGrid, Tessellation};
+use voronoid::{BoundingBox, BoundaryPolicy, Cell3DFaces, Algorithm3DGrid, AlgorithmAdaptiveGrid, GeneratorError, NestedTessellation, Tessellation, Wall, WallError, WALL_ID_MAX};
+use voronoid::wall_3d::{ConvexPolyhedronGeometry, PlaneGeometry};
 
 #[test]
 fn test_cell_metrics() {
@@ -19,6 +20,105 @@ fn test_cell_metrics() {
     assert!((c[2] - 15.0).abs() < 1e-6, "Centroid Z mismatch");
 }
 
+/// A cell implementing only the required methods of `Cell` and the faces, to check the defaults.
+#[derive(Clone)]
+struct MinimalCell<C>(C);
+
+impl<const D: usize, C: voronoid::Cell<D>> voronoid::Cell<D> for MinimalCell<C> {
+    type Scratch = C::Scratch;
+
+    fn new(id: usize, bounds: BoundingBox<D>) -> Self {
+        MinimalCell(C::new(id, bounds))
+    }
+
+    fn clip(&mut self, point: &[f64; D], normal: &[f64; D], neighbor_id: i32, scratch: &mut Self::Scratch, generator: Option<&[f64; D]>) -> (bool, f64) {
+        self.0.clip(point, normal, neighbor_id, scratch, generator)
+    }
+
+    fn max_radius_sq(&self, center: &[f64; D]) -> f64 {
+        self.0.max_radius_sq(center)
+    }
+
+    fn centroid(&self) -> [f64; D] {
+        self.0.centroid()
+    }
+
+    fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    fn neighbors(&self) -> &[i32] {
+        self.0.neighbors()
+    }
+
+    fn vertices(&self) -> &[f64] {
+        self.0.vertices()
+    }
+
+    fn faces(&self) -> Vec<Vec<usize>> {
+        self.0.faces()
+    }
+
+    fn for_each_simplex(&self, f: &mut dyn FnMut(&[[f64; D]], f64)) {
+        self.0.for_each_simplex(f)
+    }
+}
+
+#[test]
+fn test_cell_trait_defaults() {
+    use voronoid::{Cell, Tetrahedralization};
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, MinimalCell<Cell3DFaces>, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(50, 4);
+    tess.calculate();
+    for cell in &tess.cells {
+        for k in 0..cell.neighbors().len() {
+            assert!((cell.face_area(k) - Cell::face_area(&cell.0, k)).abs() < 1e-9);
+        }
+        assert!((cell.volume() - Cell::volume(&cell.0)).abs() < 1e-9);
+        let (tensor, expected) = (cell.interface_tensor(), Cell::interface_tensor(&cell.0));
+        for i in 0..3 {
+            for j in 0..3 {
+                assert!((tensor[i][j] - expected[i][j]).abs() < 1e-9);
+            }
+        }
+        assert_eq!(cell.edge_lengths(), Cell::edge_lengths(&cell.0));
+        assert_eq!(cell.dihedral_angles(), Cell::dihedral_angles(&cell.0));
+        assert_eq!(cell.solid_angles(), Cell::solid_angles(&cell.0));
+        assert_eq!(cell.face_uvs(), Cell::face_uvs(&cell.0));
+        assert_eq!(cell.unfold_net(), Cell::unfold_net(&cell.0));
+        for method in [Tetrahedralization::Centroid, Tetrahedralization::Fan] {
+            let (mesh, expected) = (cell.tetrahedralize(method), Cell::tetrahedralize(&cell.0, method));
+            assert!(!mesh.tetrahedra.is_empty());
+            assert_eq!(mesh.tetrahedra, expected.tetrahedra);
+            assert_eq!(mesh.vertices, expected.vertices);
+        }
+    }
+    assert!((tess.total_volume() - 1000.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_cell_trait_defaults_2d() {
+    use voronoid::{Algorithm2DGrid, Cell, Cell2D};
+
+    let bounds = BoundingBox::new([0.0, 0.0], [10.0, 10.0]);
+    let mut tess = Tessellation::<2, MinimalCell<Cell2D>, _>::new(bounds, Algorithm2DGrid::new(4, 4, &bounds));
+    tess.random_generators_seeded(30, 4);
+    tess.calculate();
+    for cell in &tess.cells {
+        let (tensor, expected) = (cell.interface_tensor(), Cell::interface_tensor(&cell.0));
+        for i in 0..2 {
+            for j in 0..2 {
+                assert!((tensor[i][j] - expected[i][j]).abs() < 1e-9);
+            }
+        }
+        assert_eq!(cell.edge_lengths(), Cell::edge_lengths(&cell.0));
+        assert_eq!(cell.dihedral_angles(), Cell::dihedral_angles(&cell.0));
+        assert_eq!(cell.solid_angles(), Cell::solid_angles(&cell.0));
+    }
+}
+
 #[test]
 fn test_tessellation_workflow() {
     let bounds = BoundingBox::new([0.0, 0.0, 0.0], [100.0, 100.0, 100.0]);
@@ -29,7 +129,7 @@ fn test_tessellation_workflow() {
         90.0, 90.0, 90.0,
     ];
 
-    tess.set_generators(&points);
+    tess.set_generators(&points).unwrap();
     tess.calculate();
 
     assert_eq!(tess.count_generators(), 2);
@@ -61,7 +161,7 @@ fn test_tessellation_cells_octet() {
         75.0, 75.0, 75.0        
     ];
 
-    tess.set_generators(&points);
+    tess.set_generators(&points).unwrap();
     tess.calculate();
 
     assert_eq!(tess.count_generators(), 8);
@@ -77,3 +177,1611 @@ fn test_tessellation_cells_octet() {
     }
     assert!((total_vol - 1_000_000.0).abs() < 1e-3, "Total volume should be 1,000,000, got {}", total_vol);
 }
+
+#[test]
+fn test_set_generators_non_finite() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [100.0, 100.0, 100.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+    tess.set_generators(&[10.0, 10.0, 10.0]).unwrap();
+
+    let points = vec![
+        10.0, 10.0, 10.0,
+        f64::NAN, 50.0, 50.0,
+        90.0, 90.0, 90.0,
+        50.0, f64::INFINITY, 50.0,
+    ];
+
+    // Invalid input is rejected and the previous generators are kept.
+    let err = tess.set_generators(&points).unwrap_err();
+    assert_eq!(err, GeneratorError::NonFinite(vec![1, 3]));
+    assert_eq!(tess.count_generators(), 1);
+
+    // A non-finite update of a single generator is ignored.
+    tess.set_generator(0, &[f64::NAN, 0.0, 0.0]);
+    assert_eq!(tess.try_get_generator(0).unwrap(), [10.0, 10.0, 10.0]);
+
+    assert!(tess.read_generators("0 1.0 2.0 nan").is_err());
+}
+
+#[test]
+fn test_boundary_policy() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let points = vec![
+        5.0, 5.0, 2.0, // on the wall
+        5.0, 5.0, 5.0, // inside
+        0.0, 5.0, 5.0, // on the box face
+    ];
+
+    let apply = |policy: BoundaryPolicy| {
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+        assert_eq!(tess.boundary_policy(), BoundaryPolicy::Keep);
+        tess.set_boundary_policy(policy);
+        assert_eq!(tess.boundary_policy(), policy);
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(PlaneGeometry::new([0.0, 0.0, 2.0], [0.0, 0.0, 1.0]))).unwrap());
+        tess.set_generators(&points).unwrap();
+        tess.calculate();
+        assert!(tess.validate().is_valid(1e-9));
+        tess.generators()
+    };
+
+    assert_eq!(apply(BoundaryPolicy::Keep), points);
+    assert_eq!(apply(BoundaryPolicy::Reject), vec![5.0, 5.0, 5.0]);
+
+    let snapped = apply(BoundaryPolicy::SnapInside(0.1));
+    assert_eq!(snapped.len(), 9);
+    assert!((snapped[2] - 2.1).abs() < 1e-12);
+    assert!((snapped[6] - 0.1).abs() < 1e-12);
+}
+
+#[test]
+fn test_fallible_constructors() {
+    use voronoid::WallGeometry;
+    let plane = || Box::new(PlaneGeometry::new([0.0, 0.0, 0.0], [0.0, 0.0, 1.0]));
+    assert!(matches!(Wall::try_new(5, plane()), Err(WallError::InvalidId(5))));
+    assert!(Wall::try_new(WALL_ID_MAX, plane()).is_ok());
+
+    assert!(ConvexPolyhedronGeometry::try_new(&[0.0, 0.0], &[1.0, 0.0]).is_err());
+    assert!(ConvexPolyhedronGeometry::try_new(&[0.0, 0.0, 0.0], &[1.0, 0.0, 0.0]).is_ok());
+    assert!(ConvexPolyhedronGeometry::try_new(&[0.0, 0.0, 0.0], &[0.0, 0.0, 0.0]).is_err());
+    // The half-spaces x <= 0 and x >= 1 do not overlap.
+    assert!(ConvexPolyhedronGeometry::try_new(&[0.0, 0.0, 0.0, 1.0, 0.0, 0.0], &[1.0, 0.0, 0.0, -1.0, 0.0, 0.0]).is_err());
+
+    // The hull of the cube corners and its center has one plane per side.
+    let mut corners: Vec<f64> = (0..8).flat_map(|i| [(i & 1) as f64, (i >> 1 & 1) as f64, (i >> 2 & 1) as f64]).collect();
+    corners.extend([0.5, 0.5, 0.5]);
+    let mut cube = ConvexPolyhedronGeometry::from_vertices(&corners).unwrap();
+    assert_eq!(cube.planes.len(), 6);
+    assert!(WallGeometry::contains(&cube, &[0.5, 0.2, 0.9]));
+    assert!(!WallGeometry::contains(&cube, &[0.5, 1.2, 0.5]));
+    assert!(ConvexPolyhedronGeometry::from_vertices(&corners[..12]).is_err());
+    cube.planes.push(([0.0, 0.0, 5.0], [0.0, 0.0, 1.0]));
+    assert_eq!(cube.prune_redundant_planes(), 1);
+    assert_eq!(cube.planes.len(), 6);
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+    tess.set_generators(&[1.0, 2.0, 3.0]).unwrap();
+    assert_eq!(tess.try_get_generator(0), Some([1.0, 2.0, 3.0]));
+    assert_eq!(tess.try_get_generator(1), None);
+}
+
+#[test]
+fn test_deterministic_thread_count() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(PlaneGeometry::new([0.0, 0.0, 7.0], [0.0, 0.0, 1.0]))).unwrap());
+    tess.random_generators(500);
+
+    let mut outputs = Vec::new();
+    for threads in [1, 2, 8] {
+        let pool = rayon::ThreadPoolBuilder::new().num_threads(threads).build().unwrap();
+        pool.install(|| tess.calculate());
+        let cells: Vec<(Vec<f64>, Vec<i32>)> = tess.cells.iter().map(|c| (c.vertices(), c.face_neighbors())).collect();
+        outputs.push((cells, pool.install(|| tess.total_volume()).to_bits()));
+    }
+    assert!(outputs.windows(2).all(|w| w[0] == w[1]));
+}
+
+#[test]
+fn test_generators_seeded_and_bytes() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(100, 7);
+    let generators = tess.generators();
+    tess.random_generators_seeded(100, 7);
+    assert_eq!(tess.generators(), generators);
+
+    let bytes = tess.generators_bytes();
+    assert_eq!(bytes.len(), 100 * 3 * 8);
+    tess.random_generators_seeded(100, 8);
+    assert_ne!(tess.generators(), generators);
+    tess.read_generators_bytes(&bytes).unwrap();
+    assert_eq!(tess.generators(), generators);
+
+    assert_eq!(tess.read_generators_bytes(&bytes[..20]), Err(GeneratorError::InvalidLength(20)));
+    assert_eq!(tess.generators(), generators);
+}
+
+#[test]
+fn test_adaptive_grid_matches_grid() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let inner = BoundingBox::new([4.0, 4.0, 4.0], [6.0, 6.0, 6.0]);
+
+    // A dense cluster in the center with a sparse background.
+    let mut clustered = Tessellation::<3, Cell3DFaces, _>::new(inner, Algorithm3DGrid::new(1, 1, 1, &inner));
+    clustered.random_generators_seeded(400, 1);
+    let mut generators = clustered.generators();
+    clustered.bounds = bounds;
+    clustered.random_generators_seeded(100, 2);
+    generators.extend(clustered.generators());
+
+    let mut grid = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(8, 8, 8, &bounds));
+    let mut adaptive = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(8, 8, 8, &bounds));
+    grid.set_generators(&generators).unwrap();
+    adaptive.set_generators(&generators).unwrap();
+    grid.calculate();
+    adaptive.calculate();
+    assert_eq!(grid.fingerprint(), adaptive.fingerprint());
+
+    // Moving a generator rebins it in the adapted grid.
+    adaptive.set_generator(0, &[9.5, 9.5, 9.5]);
+    grid.set_generator(0, &[9.5, 9.5, 9.5]);
+    grid.calculate();
+    adaptive.calculate();
+    assert_eq!(grid.fingerprint(), adaptive.fingerprint());
+}
+
+#[test]
+fn test_binned_bvh_matches_grid() {
+    use voronoid::AlgorithmBinnedBvh;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let inner = BoundingBox::new([4.0, 4.0, 4.0], [6.0, 6.0, 6.0]);
+
+    // A dense cluster in the center, surrounded by empty bins.
+    let mut clustered = Tessellation::<3, Cell3DFaces, _>::new(inner, Algorithm3DGrid::new(1, 1, 1, &inner));
+    clustered.random_generators_seeded(500, 1);
+    let generators = clustered.generators();
+
+    let mut grid = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(8, 8, 8, &bounds));
+    let mut bvh = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmBinnedBvh::new(8, 8, 8, &bounds));
+    grid.set_generators(&generators).unwrap();
+    bvh.set_generators(&generators).unwrap();
+    grid.calculate();
+    bvh.calculate();
+    assert_eq!(grid.fingerprint(), bvh.fingerprint());
+
+    // Moving generators into empty bins first queues the bins, and later rebuilds the hierarchy.
+    for (i, k) in [(0, 0.5), (1, 9.5), (2, 2.5), (3, 7.5)] {
+        grid.set_generator(i, &[k, 9.5 - k, 5.0]);
+        bvh.set_generator(i, &[k, 9.5 - k, 5.0]);
+    }
+    assert!(!bvh.algorithm.pending_bins.is_empty());
+    grid.calculate();
+    bvh.calculate();
+    assert_eq!(grid.fingerprint(), bvh.fingerprint());
+    for i in 0..40 {
+        let p = [0.2 + 0.24 * i as f64, 0.3, 9.7];
+        grid.set_generator(10 + i, &p);
+        bvh.set_generator(10 + i, &p);
+    }
+    grid.calculate();
+    bvh.calculate();
+    assert_eq!(grid.fingerprint(), bvh.fingerprint());
+    assert!((bvh.total_volume() - 1000.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_nested_tessellation() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut parent = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(3, 3, 3, &bounds));
+    parent.random_generators_seeded(30, 3);
+    parent.calculate();
+    let mut nested = NestedTessellation::new(parent);
+
+    let child = nested.subdivide_random(4, 40, 5).unwrap();
+    assert_eq!(child.count_cells(), 40);
+    let parent_cell = nested.parent.get_cell(4).unwrap();
+    let child_volume: f64 = (0..40).map(|i| nested.get_cell(4, i).unwrap().volume()).sum();
+    assert!((child_volume - parent_cell.volume()).abs() < 1e-9 * parent_cell.volume());
+
+    // Every face of a child cell borders a sibling or maps to a face neighbor of the parent cell.
+    let parent_neighbors = parent_cell.face_neighbors();
+    for i in 0..40 {
+        for id in nested.get_cell(4, i).unwrap().face_neighbors() {
+            let mapped = nested.parent_neighbor(4, id).unwrap();
+            assert!(id >= 0 || parent_neighbors.contains(&mapped), "unmapped face {}", id);
+        }
+    }
+
+    assert_eq!(nested.subdivided(), vec![4]);
+    assert!(matches!(nested.subdivide(30, &[1.0, 1.0, 1.0]), Err(GeneratorError::MissingParent(30))));
+    assert!(nested.merge(4).is_some());
+    assert!(nested.subdivided().is_empty());
+}
+
+#[test]
+fn test_merge_cells() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [3.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(3, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 2.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    let regions = tess.merge_cells(&[vec![0, 1], vec![2]]);
+    assert_eq!(regions.len(), 2);
+
+    // Two unit cubes merge into a box with the shared face removed and its vertices welded.
+    let merged = &regions[0];
+    assert_eq!(merged.cells, vec![0, 1]);
+    assert!((merged.volume - 2.0).abs() < 1e-12);
+    assert_eq!(merged.faces.len(), 10);
+    assert_eq!(merged.vertices.len() / 3, 12);
+    assert_eq!(merged.face_neighbors.iter().filter(|&&n| n >= 0).collect::<Vec<_>>(), vec![&2]);
+
+    assert_eq!(regions[1].faces.len(), 6);
+    assert_eq!(regions[1].vertices.len() / 3, 8);
+}
+
+#[test]
+fn test_site_cells() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 1, 1, &bounds));
+    let generators = [0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 2.5, 0.5, 0.5, 3.5, 0.5, 0.5];
+    tess.set_generators(&generators).unwrap();
+    assert!(tess.set_sites(&[7, 7, 3]).is_err());
+    tess.set_sites(&[7, 7, 3, 3]).unwrap();
+    tess.calculate();
+
+    // Each site is a box of two unit cubes, whose only neighbor is the other site.
+    assert_eq!(tess.site_of(2), 3);
+    assert_eq!(tess.site_members(7), vec![0, 1]);
+    let sites = tess.site_cells();
+    assert_eq!(sites.keys().copied().collect::<Vec<_>>(), vec![3, 7]);
+    for (site, other) in [(3, 7), (7, 3)] {
+        assert!((sites[&site].volume - 2.0).abs() < 1e-12);
+        assert_eq!(sites[&site].faces.len(), 10);
+        assert_eq!(sites[&site].face_neighbors.iter().filter(|&&n| n >= 0).collect::<Vec<_>>(), vec![&other]);
+    }
+
+    // The sites follow the generators through relaxation, but not when they are replaced.
+    tess.relax();
+    assert!(tess.has_sites());
+    assert_eq!(tess.site_of(3), 3);
+    tess.set_generators(&generators).unwrap();
+    assert!(!tess.has_sites());
+    assert_eq!(tess.site_of(3), 3);
+    tess.calculate();
+    assert_eq!(tess.site_cells().len(), 4);
+
+    // Refined cells stay with their site, and the site IDs must fit the face neighbors.
+    tess.set_sites(&[7, 7, 3, 3]).unwrap();
+    assert_eq!(tess.refine_where(|cell| cell.centroid()[0] < 1.0), vec![4]);
+    assert_eq!(tess.site_members(7), vec![0, 1, 4]);
+    tess.calculate();
+    let sites = tess.site_cells();
+    assert_eq!(sites.len(), 2);
+    assert!((sites[&7].volume - 2.0).abs() < 1e-12);
+    assert_eq!(tess.set_sites(&[0, 0, 0, 0, usize::MAX]), Err(GeneratorError::InvalidSite(usize::MAX)));
+}
+
+#[test]
+fn test_face_area_matrix() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // Four unit cubes: each shares a unit face with two others and only an edge with the diagonal one.
+    let areas = tess.face_area_matrix();
+    assert_eq!(areas.size, 4);
+    assert_eq!(areas.nnz(), 8);
+    for (i, j) in [(0, 1), (0, 2), (1, 3), (2, 3)] {
+        assert!((areas.get(i, j) - 1.0).abs() < 1e-12);
+        assert_eq!(areas.get(i, j), areas.get(j, i));
+    }
+    assert_eq!(areas.get(0, 3), 0.0);
+    assert_eq!(areas.get(1, 2), 0.0);
+}
+
+#[test]
+fn test_laplacian() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [3.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(3, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 2.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // A chain of unit cubes gives the 1D stencil with zero-flux ends.
+    let laplacian = tess.laplacian();
+    let expected = [[-1.0, 1.0, 0.0], [1.0, -2.0, 1.0], [0.0, 1.0, -1.0]];
+    for (i, row) in expected.iter().enumerate() {
+        for (j, &value) in row.iter().enumerate() {
+            assert!((laplacian.get(i, j) - value).abs() < 1e-12);
+        }
+    }
+
+    // A linear field has no net flux through the interior cell.
+    let flux = laplacian.multiply(&[0.5, 1.5, 2.5]);
+    assert!(flux[1].abs() < 1e-12);
+
+    let diffusion = tess.diffusion_operator(|i, j| if i.min(j) == 0 { 2.0 } else { 1.0 });
+    assert!((diffusion.get(1, 1) + 3.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_connectivity_queries() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 2.5, 0.5, 0.5, 3.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    let labels = [1, 1, 0, 1];
+    assert_eq!(tess.connected_components(|i, j, _| labels[i] == labels[j]), vec![0, 0, 1, 2]);
+    assert_eq!(tess.connected_components(|_, _, area| area > 0.5), vec![0, 0, 0, 0]);
+
+    let (cost, path) = tess.shortest_path(0, 3, |_, _, area| Some(1.0 / area)).unwrap();
+    assert!((cost - 3.0).abs() < 1e-12);
+    assert_eq!(path, vec![0, 1, 2, 3]);
+    assert_eq!(tess.shortest_path(2, 2, |_, _, _| Some(1.0)), Some((0.0, vec![2])));
+    assert!(tess.shortest_path(0, 3, |i, j, _| (labels[i] == labels[j]).then_some(1.0)).is_none());
+    assert!(tess.shortest_path(0, 4, |_, _, _| Some(1.0)).is_none());
+
+    assert_eq!(tess.neighbor_shell(1, 0), vec![1]);
+    let mut shell = tess.neighbor_shell(1, 1);
+    shell[1..].sort();
+    assert_eq!(shell, vec![1, 0, 2]);
+    assert_eq!(tess.neighbor_shell(0, 2), vec![0, 1, 2]);
+    assert_eq!(tess.neighbor_shell(0, 10).len(), 4);
+    assert!(tess.neighbor_shell(4, 1).is_empty());
+}
+
+#[test]
+fn test_color_cells() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(300, 2);
+    tess.calculate();
+
+    let colors = tess.color_cells(32).unwrap();
+    assert_eq!(colors.len(), 300);
+    for (i, cell) in tess.cells.iter().enumerate() {
+        for &n in voronoid::Cell::neighbors(cell) {
+            if n >= 0 {
+                assert_ne!(colors[i], colors[n as usize]);
+            }
+        }
+    }
+    let used = colors.iter().max().unwrap() + 1;
+    assert!(tess.color_cells(used).is_some());
+    assert!(tess.color_cells(used - 1).is_none());
+    assert!(tess.color_cells(1).is_none());
+}
+
+#[test]
+fn test_intersect_tessellations() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut a = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    a.random_generators_seeded(200, 1);
+    a.calculate();
+    let mut b = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    b.random_generators_seeded(100, 2);
+    b.calculate();
+
+    let overlaps = a.intersect(&b);
+
+    // The overlaps partition both tessellations.
+    let mut source_volumes = vec![0.0; a.count_cells()];
+    let mut target_volumes = vec![0.0; b.count_cells()];
+    for overlap in &overlaps {
+        assert!((overlap.cell.volume() - overlap.volume).abs() < 1e-12);
+        source_volumes[overlap.source] += overlap.volume;
+        target_volumes[overlap.target] += overlap.volume;
+    }
+    for (i, cell) in a.cells.iter().enumerate() {
+        assert!((source_volumes[i] - cell.volume()).abs() < 1e-9 * cell.volume().max(1.0));
+    }
+    for (j, cell) in b.cells.iter().enumerate() {
+        assert!((target_volumes[j] - cell.volume()).abs() < 1e-9 * cell.volume().max(1.0));
+    }
+
+    // A tessellation only overlaps itself cell by cell.
+    let identity = a.intersect(&a);
+    assert_eq!(identity.len(), a.count_cells());
+    assert!(identity.iter().all(|o| o.source == o.target));
+}
+
+#[test]
+fn test_search_factor() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    tess.random_generators_seeded(500, 3);
+    tess.calculate();
+    let reference = tess.fingerprint();
+
+    // A larger factor visits more candidates, but the exact default already finds every neighbor.
+    tess.set_search_factor(9.0);
+    tess.calculate();
+    assert_eq!(tess.fingerprint(), reference);
+
+    // A factor below the safe bound may leave cells uncut, so they cover more than the box.
+    tess.set_search_factor(0.25);
+    tess.calculate();
+    assert!(tess.total_volume() >= bounds.volume() - 1e-9);
+}
+
+#[test]
+fn test_open_faces() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 2.5, 0.5, 0.5, 3.5, 0.5, 0.5]).unwrap();
+    assert!(tess.set_open_face(3, false, Some(1.0)).is_err());
+    assert!(tess.set_open_face(0, true, Some(-1.0)).is_err());
+
+    // Without a margin, the last cell is only flagged.
+    tess.set_open_face(0, true, Some(0.0)).unwrap();
+    tess.calculate();
+    assert_eq!(tess.unbounded_cells(), vec![3]);
+    assert!((tess.total_volume() - 4.0).abs() < 1e-12);
+
+    // With a margin, it extends beyond the box.
+    tess.set_open_face(0, true, Some(0.5)).unwrap();
+    tess.calculate();
+    assert!(tess.is_cell_unbounded(3) && !tess.is_cell_unbounded(0));
+    assert!((tess.get_cell(3).unwrap().volume() - 1.5).abs() < 1e-12);
+    tess.calculate_warm();
+    assert!((tess.get_cell(3).unwrap().volume() - 1.5).abs() < 1e-12);
+    tess.preview_calculate(100);
+    assert!((tess.total_volume() - 4.5).abs() < 1e-12);
+
+    tess.set_open_face(0, true, None).unwrap();
+    tess.calculate();
+    assert!(tess.unbounded_cells().is_empty());
+    assert!((tess.total_volume() - 4.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_ghost_generators() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut full = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    full.random_generators_seeded(400, 5);
+    full.calculate();
+
+    // Split the generators at x = 5, with a halo of ghosts reaching 3 units into the other half.
+    let points: Vec<[f64; 3]> = full.generators().chunks_exact(3).map(|p| [p[0], p[1], p[2]]).collect();
+    let owned: Vec<usize> = (0..points.len()).filter(|&i| points[i][0] < 5.0).collect();
+    let ghosts: Vec<usize> = (0..points.len()).filter(|&i| points[i][0] >= 5.0 && points[i][0] < 8.0).collect();
+
+    let mut part = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    part.set_generators(&owned.iter().flat_map(|&i| points[i]).collect::<Vec<_>>()).unwrap();
+    part.set_ghosts(&ghosts.iter().flat_map(|&i| points[i]).collect::<Vec<_>>()).unwrap();
+    part.calculate();
+
+    assert_eq!(part.count_owned(), owned.len());
+    assert_eq!(part.count_cells(), owned.len());
+    assert_eq!(part.ghosts().len(), ghosts.len() * 3);
+
+    // The owned cells match the full tessellation, with ghost neighbors mapped back to global indices.
+    for (local, &global) in owned.iter().enumerate() {
+        let cell = &part.cells[local];
+        let reference = &full.cells[global];
+        assert!((cell.volume() - reference.volume()).abs() < 1e-9);
+
+        let mut neighbors: Vec<i32> = cell
+            .face_neighbors()
+            .into_iter()
+            .map(|n| match part.ghost_index(n) {
+                Some(k) => ghosts[k] as i32,
+                None if n >= 0 => owned[n as usize] as i32,
+                None => n,
+            })
+            .collect();
+        let mut expected = reference.face_neighbors();
+        neighbors.sort_unstable();
+        expected.sort_unstable();
+        assert_eq!(neighbors, expected);
+    }
+
+    assert!(part.is_ghost(owned.len() as i32));
+    assert!(!part.is_ghost(0));
+    assert!(!part.is_ghost(-1));
+    part.clear_ghosts();
+    assert_eq!(part.count_generators(), owned.len());
+}
+
+#[test]
+fn test_partition_generators() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut full = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    full.random_generators_seeded(1000, 6);
+    full.calculate();
+
+    let partitions = full.partition_generators(4);
+    assert_eq!(partitions.len(), 4);
+    let mut owned: Vec<usize> = partitions.iter().flat_map(|p| p.owned.clone()).collect();
+    owned.sort_unstable();
+    assert_eq!(owned, (0..1000).collect::<Vec<_>>());
+    assert!(partitions.iter().all(|p| p.owned.len() == 250));
+
+    // Each chunk computed with its halo reproduces the cells of the full tessellation.
+    let coordinates = |indices: &[usize]| indices.iter().flat_map(|&i| full.try_get_generator(i).unwrap()).collect::<Vec<_>>();
+    for partition in &partitions {
+        assert!(partition.halo.iter().all(|i| !partition.owned.contains(i)));
+        let mut part = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+        part.set_generators(&coordinates(&partition.owned)).unwrap();
+        part.set_ghosts(&coordinates(&partition.halo)).unwrap();
+        part.calculate();
+        for (local, &global) in partition.owned.iter().enumerate() {
+            assert!((part.cells[local].volume() - full.cells[global].volume()).abs() < 1e-9);
+        }
+    }
+}
+
+#[test]
+fn test_track_cells() {
+    use voronoid::{Algorithm2DGrid, Cell2D, CellMatching, T1Event, track_cells};
+
+    let bounds = BoundingBox::new([-3.0, -3.0], [3.0, 3.0]);
+    let state = |generators: &[f64]| {
+        let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(2, 2, &bounds));
+        tess.set_generators(generators).unwrap();
+        tess.calculate();
+        tess
+    };
+
+    // Cells 0 and 1 separate while cells 2 and 3 are pushed together.
+    let prev = state(&[-1.0, 0.0, 1.0, 0.0, 0.0, 1.5, 0.0, -1.5]);
+    let curr = state(&[-1.5, 0.0, 1.5, 0.0, 0.0, 1.0, 0.0, -1.0]);
+    let report = track_cells(&prev, &curr, CellMatching::Index);
+    assert_eq!(report.tracks.len(), 4);
+    assert!(report.appeared.is_empty() && report.vanished.is_empty());
+    assert_eq!(report.t1_events, vec![T1Event { lost: (0, 1), gained: (2, 3) }]);
+    assert_eq!(report.tracks[0].lost_neighbors, vec![1]);
+    assert_eq!(report.tracks[2].gained_neighbors, vec![3]);
+    let total_change: f64 = report.tracks.iter().map(|t| t.volume_change).sum();
+    assert!(total_change.abs() < 1e-9);
+
+    // Reordered generators with an added one are matched by their overlap.
+    let curr = state(&[0.0, -1.5, 1.0, 0.0, -1.0, 0.0, 0.0, 1.5, 2.5, 2.5]);
+    let report = track_cells(&prev, &curr, CellMatching::Overlap);
+    let pairs: Vec<(usize, usize)> = report.tracks.iter().map(|t| (t.prev, t.curr)).collect();
+    assert_eq!(pairs, vec![(3, 0), (1, 1), (0, 2), (2, 3)]);
+    assert_eq!(report.appeared, vec![4]);
+    assert!(report.vanished.is_empty());
+}
+
+#[test]
+fn test_topological_events() {
+    use voronoid::{Algorithm2DGrid, Cell2D, T1Event};
+
+    let bounds = BoundingBox::new([-3.0, -3.0], [3.0, 3.0]);
+    let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(2, 2, &bounds));
+    tess.set_generators(&[-1.0, 0.0, 1.0, 0.0, 0.0, 1.5, 0.0, -1.5]).unwrap();
+    tess.calculate();
+    let before = tess.adjacency_snapshot();
+    assert!(before.pairs.contains(&(0, 1)) && !before.pairs.contains(&(2, 3)));
+    assert_eq!(tess.topological_events(&before), Default::default());
+
+    // Cells 0 and 1 separate while cells 2 and 3 are pushed together.
+    tess.set_generators_sparse(&[0, 1, 2, 3], &[-1.5, 0.0, 1.5, 0.0, 0.0, 1.0, 0.0, -1.0]).unwrap();
+    tess.calculate();
+    let events = tess.topological_events(&before);
+    assert_eq!(events.created, vec![(2, 3)]);
+    assert_eq!(events.destroyed, vec![(0, 1)]);
+    assert_eq!(events.t1_events, vec![T1Event { lost: (0, 1), gained: (2, 3) }]);
+    assert!(events.t2_events.is_empty());
+
+    // A cell squeezed onto a wall vanishes.
+    let before = tess.adjacency_snapshot();
+    tess.set_boundary_policy(BoundaryPolicy::Keep);
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(voronoid::wall_2d::LineGeometry::new([0.0, 1.0], [0.0, -1.0]))).unwrap());
+    tess.add_wall(Wall::try_new(WALL_ID_MAX - 1, Box::new(voronoid::wall_2d::LineGeometry::new([0.0, 1.0], [0.0, 1.0]))).unwrap());
+    tess.calculate();
+    assert!(!tess.topological_events(&before).t2_events.is_empty());
+}
+
+#[test]
+fn test_morph_cells() {
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let state = |generators: &[f64]| {
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 5.0))).unwrap());
+        tess.set_generators(generators).unwrap();
+        tess.calculate();
+        tess
+    };
+    let mut prev = state(&[]);
+    prev.random_generators_seeded(100, 1);
+    prev.calculate();
+    // Move every generator a little towards the center, as in a frame of an animation.
+    let moved: Vec<f64> = prev.generators().iter().map(|&v| v + 0.05 * (5.0 - v)).collect();
+    let curr = state(&moved);
+    assert_eq!(curr.count_cells(), 100);
+
+    let morphs = prev.morph_to(&curr);
+    assert_eq!(morphs.len(), 100);
+
+    // The ends reproduce both states.
+    for morph in &morphs {
+        let start: Cell3DFaces = morph.at(0.0);
+        let end: Cell3DFaces = morph.at(1.0);
+        assert!((start.volume() - prev.cells[morph.index].volume()).abs() < 1e-9);
+        assert!((end.volume() - curr.cells[morph.index].volume()).abs() < 1e-9);
+    }
+
+    // Halfway, the cells still fill the domain.
+    let halfway: f64 = morphs.iter().map(|m| m.at::<Cell3DFaces>(0.5).volume()).sum();
+    let expected = 0.5 * (prev.total_volume() + curr.total_volume());
+    assert!((halfway - expected).abs() / expected < 0.01, "{} vs {}", halfway, expected);
+}
+
+#[test]
+fn test_limit_displacement() {
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    tess.set_generators(&[5.0, 5.0, 5.0, 4.0, 5.0, 5.0]).unwrap();
+
+    // The first generator reaches its target in three steps, the second stops at the wall.
+    let targets = [8.0, 5.0, 5.0, -4.0, 5.0, 5.0];
+    assert_eq!(tess.limit_displacement(&targets, 1.0), Ok(2));
+    assert_eq!(tess.try_get_generator(0).unwrap(), [6.0, 5.0, 5.0]);
+    assert_eq!(tess.try_get_generator(1).unwrap(), [3.0, 5.0, 5.0]);
+    tess.limit_displacement(&targets, 1.0).unwrap();
+    assert_eq!(tess.limit_displacement(&targets, 1.0), Ok(1));
+    assert_eq!(tess.try_get_generator(0).unwrap(), [8.0, 5.0, 5.0]);
+    for _ in 0..10 {
+        tess.limit_displacement(&targets, 1.0).unwrap();
+    }
+    assert!(tess.try_get_generator(1).unwrap()[0] >= 1.0);
+
+    // The grid index follows the moves.
+    tess.calculate();
+    assert!(tess.cells[0].face_neighbors().contains(&1));
+    assert!(tess.cells[1].face_neighbors().contains(&0));
+
+    assert_eq!(tess.limit_displacement(&[0.0; 3], 1.0), Err(GeneratorError::CountMismatch(2, 1)));
+    assert_eq!(tess.limit_displacement(&[f64::NAN, 0.0, 0.0, 5.0, 5.0, 5.0], 1.0), Err(GeneratorError::NonFinite(vec![0])));
+}
+
+#[test]
+fn test_refine_where() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(100, 4);
+    tess.calculate();
+
+    // Refine until no cell is larger than 8, with the mean volume being 10.
+    for _ in 0..10 {
+        let inserted = tess.refine_where(|cell| cell.volume() > 8.0);
+        if inserted.is_empty() {
+            break;
+        }
+        assert_eq!(inserted[0], tess.count_generators() - inserted.len());
+        tess.calculate();
+    }
+    assert!(tess.count_cells() > 100);
+    assert!(tess.cells.iter().all(|cell| cell.volume() <= 8.0));
+    assert!((tess.total_volume() - 1000.0).abs() < 1e-6);
+
+    // Refining a single cubic cell splits it, although its centroid is its generator.
+    let mut cube = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(1, 1, 1, &bounds));
+    cube.set_generators(&[5.0, 5.0, 5.0]).unwrap();
+    cube.calculate();
+    assert_eq!(cube.refine_where(|_| true), vec![1]);
+    cube.calculate();
+    assert_eq!(cube.count_cells(), 2);
+    assert!(cube.cells.iter().all(|cell| cell.volume() > 100.0));
+}
+
+#[test]
+fn test_faces_on_wall() {
+    use voronoid::box_side;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // The bottom of the box is made of four unit squares on a 3x3 grid of vertices.
+    let bottom = tess.faces_on_wall(box_side(2, false), true);
+    assert_eq!(bottom.faces.len(), 4);
+    assert_eq!(bottom.cells, vec![0, 1, 2, 3]);
+    assert_eq!(bottom.vertices.len() / 3, 9);
+    assert!((bottom.area - 4.0).abs() < 1e-12);
+    assert!(bottom.vertices.chunks_exact(3).all(|v| v[2] == 0.0));
+
+    let separate = tess.faces_on_wall(box_side(2, false), false);
+    // Without welding, every face has its own vertices.
+    let flattened: Vec<usize> = separate.faces.iter().flatten().copied().collect();
+    assert_eq!(flattened, (0..separate.vertices.len() / 3).collect::<Vec<_>>());
+    assert!((separate.area - 4.0).abs() < 1e-12);
+
+    assert!(tess.faces_on_wall(WALL_ID_MAX, true).faces.is_empty());
+}
+
+#[test]
+fn test_wall_areas() {
+    use voronoid::box_side;
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+
+    let areas = tess.wall_areas();
+    assert_eq!(areas.totals.len(), 6);
+    assert!((areas.totals[&box_side(2, true)] - 4.0).abs() < 1e-12);
+    assert!((areas.totals[&box_side(0, false)] - 2.0).abs() < 1e-12);
+    assert_eq!(areas.contributions[&box_side(0, false)].iter().map(|c| c.0).collect::<Vec<_>>(), vec![0, 2]);
+
+    // The contact area with a sphere approaches its surface area.
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(8, 8, 8, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    tess.random_generators_seeded(2000, 7);
+    tess.calculate();
+    let areas = tess.wall_areas();
+    assert_eq!(areas.totals.keys().copied().collect::<Vec<_>>(), vec![WALL_ID_MAX]);
+    let sphere = 4.0 * std::f64::consts::PI * 16.0;
+    assert!((areas.totals[&WALL_ID_MAX] - sphere).abs() / sphere < 0.05);
+}
+
+#[test]
+fn test_volume_by_label() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+    assert_eq!(tess.volume_by_label(&[0, 1]), Err(GeneratorError::CountMismatch(4, 2)));
+
+    // Three cells of phase 7 in an L shape around one cell of phase 3.
+    let phases = tess.volume_by_label(&[7, 7, 7, 3]).unwrap();
+    assert_eq!(phases.volumes, [(3, 1.0), (7, 3.0)].into_iter().collect());
+    assert_eq!(phases.volume_fractions()[&3], 0.25);
+    assert!((phases.interfaces[&(3, 7)] - 2.0).abs() < 1e-12);
+    assert!((phases.interfaces[&(7, 7)] - 2.0).abs() < 1e-12);
+    assert_eq!(phases.interfaces.len(), 2);
+}
+
+#[test]
+fn test_recalculate_region() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+    tess.random_generators_seeded(1000, 3);
+    tess.calculate();
+
+    // Move one generator and recalculate the cells around it.
+    let old = tess.try_get_generator(500).unwrap();
+    let new = [old[0] + 0.2, old[1] - 0.1, old[2] + 0.1];
+    tess.set_generator(500, &new);
+    let margin = 2.5;
+    let region = BoundingBox::new(old.map(|v| v - margin), old.map(|v| v + margin));
+    let updated = tess.recalculate_region(&region);
+    assert!(updated.contains(&500));
+    assert!(updated.len() < 500);
+
+    let partial = tess.cells();
+    tess.calculate();
+    for (a, b) in partial.iter().zip(tess.cells()) {
+        assert_eq!(a.face_neighbors(), b.face_neighbors());
+        assert!((a.volume() - b.volume()).abs() < 1e-12);
+    }
+    assert!((tess.total_volume() - 1000.0).abs() < 1e-9);
+
+    // Changing the number of generators recalculates every cell.
+    tess.random_generators_seeded(200, 4);
+    assert_eq!(tess.recalculate_region(&region).len(), 200);
+    assert_eq!(tess.count_cells(), 200);
+}
+
+#[test]
+fn test_random_generators_density() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(10, 10, 10, &bounds));
+
+    // A density rising linearly along x puts three quarters of the points in the upper half.
+    tess.random_generators_density_seeded(4000, 5, |p| p[0]);
+    assert_eq!(tess.count_generators(), 4000);
+    let upper = (0..4000).filter(|&i| tess.try_get_generator(i).unwrap()[0] > 5.0).count() as f64 / 4000.0;
+    assert!((upper - 0.75).abs() < 0.03, "upper fraction {upper}");
+
+    // Points are only placed where the density is positive.
+    tess.random_generators_density_seeded(500, 6, |p| if p[1] < 2.0 { 1.0 } else { -1.0 });
+    assert_eq!(tess.count_generators(), 500);
+    assert!((0..500).all(|i| tess.try_get_generator(i).unwrap()[1] < 2.0));
+
+    // The sampling is reproducible from the seed.
+    let first = tess.generators();
+    tess.random_generators_density_seeded(500, 6, |p| if p[1] < 2.0 { 1.0 } else { -1.0 });
+    assert_eq!(first, tess.generators());
+}
+
+#[test]
+fn test_stratified_generators() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 2, 1, &bounds));
+
+    // Without jitter the generators form a regular grid of equal cells.
+    tess.stratified_generators_seeded([4, 2, 1], 0.0, 1);
+    assert_eq!(tess.count_generators(), 8);
+    assert_eq!(tess.try_get_generator(5).unwrap(), [1.5, 1.5, 0.5]);
+    tess.calculate();
+    assert!(tess.cells().iter().all(|c| (c.volume() - 1.0).abs() < 1e-12));
+
+    // With full jitter each generator stays within its own sub-cell.
+    tess.stratified_generators_seeded([8, 4, 2], 1.0, 2);
+    assert_eq!(tess.count_generators(), 64);
+    for i in 0..64 {
+        let g = tess.try_get_generator(i).unwrap();
+        let cell = [(i % 8) as f64 * 0.5, (i / 8 % 4) as f64 * 0.5, (i / 32) as f64 * 0.5];
+        assert!((0..3).all(|k| g[k] >= cell[k] && g[k] <= cell[k] + 0.5));
+    }
+}
+
+#[test]
+fn test_strut_mesh() {
+    use std::collections::HashSet;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(1, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // The 12 edges and 8 corners of the box, each a closed and consistently oriented shell.
+    let (radius, segments) = (0.05, 8);
+    let mesh = tess.strut_mesh(radius, segments);
+    let strut_triangles = 12 * 4 * segments;
+    let node_triangles = 8 * 2 * segments * (segments / 2 - 1);
+    assert_eq!(mesh.triangles.len(), strut_triangles + node_triangles);
+    let directed: HashSet<(usize, usize)> = mesh
+        .triangles
+        .iter()
+        .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+        .collect();
+    assert_eq!(directed.len(), mesh.triangles.len() * 3);
+    assert!(directed.iter().all(|&(a, b)| directed.contains(&(b, a))));
+
+    let strut = 0.5 * segments as f64 * radius * radius * (std::f64::consts::TAU / segments as f64).sin();
+    let sphere = 4.0 / 3.0 * std::f64::consts::PI * radius.powi(3);
+    let volume = mesh.volume();
+    assert!(volume > 12.0 * strut && volume < 12.0 * strut + 8.0 * sphere);
+}
+
+#[test]
+fn test_shell_mesh() {
+    use std::collections::HashSet;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // Both cells are inset by 0.1 on the box and by 0.05 on the shared face.
+    let mesh = tess.shell_mesh(0.1);
+    let cavity = 0.85 * 0.8 * 0.8;
+    assert!((mesh.volume() - (2.0 - 2.0 * cavity)).abs() < 1e-12);
+    let directed: HashSet<(usize, usize)> = mesh
+        .triangles
+        .iter()
+        .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+        .collect();
+    assert!(directed.iter().all(|&(a, b)| directed.contains(&(b, a))));
+
+    // Cells thinner than the walls are left solid.
+    assert!((tess.shell_mesh(0.6).volume() - 2.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_exploded_transforms() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // Without an origin the cells move away from the center of the domain.
+    let transforms = tess.exploded_transforms(None, 0.5);
+    assert!((0..3).all(|k| (transforms[0][k] - [-0.25, -0.25, 0.0][k]).abs() < 1e-12));
+    assert!((0..3).all(|k| (transforms[3][k] - [0.25, 0.25, 0.0][k]).abs() < 1e-12));
+    let transforms = tess.exploded_transforms(Some([0.0, 0.0, 0.0]), 1.0);
+    assert!((0..3).all(|k| (transforms[3][k] - [1.5, 1.5, 0.5][k]).abs() < 1e-12));
+
+    // The baked vertices are the translated cell vertices.
+    let vertices = tess.exploded_vertices(None, 0.5);
+    let cell = tess.get_cell(3).unwrap();
+    for (moved, original) in vertices[3].chunks_exact(3).zip(cell.vertices().chunks_exact(3)) {
+        assert!((moved[0] - original[0] - 0.25).abs() < 1e-12 && (moved[2] - original[2]).abs() < 1e-12);
+    }
+}
+
+#[test]
+fn test_convex_hull_points() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+
+    // Each cell is a box, with its 8 corners as the hull points.
+    let hulls = tess.convex_hull_points();
+    assert_eq!(hulls.len(), 4);
+    for (hull, cell) in hulls.iter().zip(&tess.cells) {
+        assert_eq!(hull.len(), 8 * 3);
+        let centroid = cell.centroid();
+        for p in hull.chunks_exact(3) {
+            assert!((0..3).all(|k| ((p[k] - centroid[k]).abs() - 0.5).abs() < 1e-12));
+        }
+    }
+}
+
+#[test]
+fn test_displaced_cell_meshes() {
+    use std::collections::HashSet;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 1, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5]).unwrap();
+    tess.calculate();
+
+    let flat = tess.displaced_cell_meshes(3, 0.0, 4.0, 1);
+    assert!(flat.iter().all(|m| (m.volume() - 1.0).abs() < 1e-12));
+
+    let meshes = tess.displaced_cell_meshes(3, 0.02, 4.0, 1);
+    assert_eq!(meshes.len(), 2);
+    for mesh in &meshes {
+        let directed: HashSet<(usize, usize)> = mesh
+            .triangles
+            .iter()
+            .flat_map(|t| [(t[0], t[1]), (t[1], t[2]), (t[2], t[0])])
+            .collect();
+        assert_eq!(directed.len(), mesh.triangles.len() * 3);
+        assert!(directed.iter().all(|&(a, b)| directed.contains(&(b, a))));
+    }
+
+    // The shared face is displaced alike in both cells.
+    let shared: Vec<&[f64]> = meshes[0]
+        .vertices
+        .chunks_exact(3)
+        .filter(|p| (p[0] - 1.0).abs() < 0.03 && (0.05..0.95).contains(&p[1]) && (0.05..0.95).contains(&p[2]))
+        .collect();
+    assert!(shared.iter().any(|p| (p[0] - 1.0).abs() > 1e-3));
+    for p in shared {
+        assert!(meshes[1].vertices.chunks_exact(3).any(|q| (0..3).all(|k| (p[k] - q[k]).abs() < 1e-12)));
+    }
+}
+
+#[test]
+fn test_cell_histogram() {
+    use voronoid::Binning;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.random_generators_seeded(500, 2);
+    tess.calculate();
+
+    let histogram = tess.cell_histogram(|c| c.volume(), 10, Binning::Linear);
+    assert_eq!(histogram.total(), 500);
+    assert_eq!(histogram.edges.len(), 11);
+    let faces = tess.cell_histogram(|c| c.face_neighbors().len() as f64, 5, Binning::Log);
+    assert_eq!(faces.total(), 500);
+}
+
+#[test]
+fn test_wall_distances() {
+    use voronoid::box_side;
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+    tess.set_generators(&[5.0, 5.0, 5.0, 5.0, 5.0, 8.0, 9.5, 5.0, 5.0]).unwrap();
+
+    let distances = tess.wall_distances();
+    assert_eq!(distances[1], (box_side(2, true), 2.0));
+    assert_eq!(distances[2], (box_side(0, true), 0.5));
+
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    let distances = tess.wall_distances();
+    assert_eq!(distances.len(), 2);
+    assert_eq!(distances[0].1, 5.0);
+    assert_eq!(distances[1].0, WALL_ID_MAX);
+    assert!((distances[1].1 - 1.0).abs() < 1e-12);
+}
+
+#[test]
+fn test_project_generators_to_wall() {
+    use voronoid::box_side;
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    tess.set_generators(&[5.0, 5.0, 7.0, 5.0, 3.0, 5.0, 4.0, 5.0, 5.0]).unwrap();
+
+    assert_eq!(tess.project_generators_to_wall(&[0, 1, 7], WALL_ID_MAX, 0.5), 2);
+    let g = tess.try_get_generator(0).unwrap();
+    assert!((g[2] - 8.5).abs() < 1e-12 && g[0] == 5.0 && g[1] == 5.0);
+    let g = tess.try_get_generator(1).unwrap();
+    assert!((g[1] - 1.5).abs() < 1e-12);
+    assert_eq!(tess.try_get_generator(2).unwrap(), [4.0, 5.0, 5.0]);
+
+    // Projecting onto a box side outside the sphere is rejected.
+    assert_eq!(tess.project_generators_to_wall(&[2], box_side(0, false), 0.5), 0);
+    tess.clear_walls();
+    assert_eq!(tess.project_generators_to_wall(&[2], box_side(0, false), 1.0), 1);
+    assert_eq!(tess.try_get_generator(2).unwrap(), [1.0, 5.0, 5.0]);
+}
+
+#[test]
+fn test_tetrahedralize_tessellation() {
+    use std::collections::HashMap;
+    use voronoid::Tetrahedralization;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 4.0, 4.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(3, 3, 3, &bounds));
+    tess.random_generators_seeded(60, 9);
+    tess.calculate();
+
+    for method in [Tetrahedralization::Centroid, Tetrahedralization::Fan] {
+        let mesh = tess.tetrahedralize(method);
+        let p = |v: usize| [mesh.vertices[v * 3], mesh.vertices[v * 3 + 1], mesh.vertices[v * 3 + 2]];
+        let mut total = 0.0;
+        // Each interior triangle is shared by exactly two tetrahedra, each boundary triangle by one.
+        let mut triangles: HashMap<[usize; 3], usize> = HashMap::new();
+        for tet in &mesh.tetrahedra {
+            let [a, b, c, d] = tet.map(p);
+            let (u, v, w) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]], [d[0] - a[0], d[1] - a[1], d[2] - a[2]]);
+            let det = u[0] * (v[1] * w[2] - v[2] * w[1]) + u[1] * (v[2] * w[0] - v[0] * w[2]) + u[2] * (v[0] * w[1] - v[1] * w[0]);
+            assert!(det > 0.0);
+            total += det / 6.0;
+            for skip in 0..4 {
+                let mut face: Vec<usize> = (0..4).filter(|&k| k != skip).map(|k| tet[k]).collect();
+                face.sort_unstable();
+                *triangles.entry([face[0], face[1], face[2]]).or_insert(0) += 1;
+            }
+        }
+        assert!((total - 64.0).abs() < 1e-9);
+        assert!(triangles.values().all(|&n| n <= 2));
+        let boundary: f64 = triangles
+            .iter()
+            .filter(|&(_, &n)| n == 1)
+            .map(|(t, _)| {
+                let [a, b, c] = t.map(p);
+                let (u, v) = ([b[0] - a[0], b[1] - a[1], b[2] - a[2]], [c[0] - a[0], c[1] - a[1], c[2] - a[2]]);
+                let n = [u[1] * v[2] - u[2] * v[1], u[2] * v[0] - u[0] * v[2], u[0] * v[1] - u[1] * v[0]];
+                0.5 * (n[0] * n[0] + n[1] * n[1] + n[2] * n[2]).sqrt()
+            })
+            .sum();
+        assert!((boundary - 96.0).abs() < 1e-9, "{boundary}");
+    }
+}
+
+#[test]
+fn test_mesh_quality() {
+    use voronoid::{Algorithm2DGrid, Cell2D};
+
+    // A regular grid is orthogonal and unskewed.
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [2.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.5, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+    let report = tess.mesh_quality();
+    assert_eq!(report.faces.len(), 4);
+    assert!(report.max_offset < 1e-12 && report.max_non_orthogonality < 1e-12 && report.max_skewness < 1e-12);
+
+    // Shifting generators along a face skews it, and moving them without recalculating
+    // tilts and offsets the faces against the generators.
+    tess.set_generators(&[0.5, 0.5, 0.5, 1.5, 0.9, 0.5, 0.5, 1.5, 0.5, 1.5, 1.5, 0.5]).unwrap();
+    tess.calculate();
+    let report = tess.mesh_quality();
+    assert!(report.max_non_orthogonality < 1e-12 && report.max_offset < 1e-12);
+    assert!(report.max_skewness > 0.01 && report.mean_skewness > 0.0);
+    tess.set_generator(0, &[0.3, 0.3, 0.5]);
+    let report = tess.mesh_quality();
+    assert!(report.max_non_orthogonality > 0.01 && report.max_offset > 0.01);
+
+    // The metrics are equally defined for the edges of 2D cells.
+    let bounds = BoundingBox::new([0.0, 0.0], [2.0, 1.0]);
+    let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(2, 1, &bounds));
+    tess.set_generators(&[0.5, 0.3, 1.5, 0.7]).unwrap();
+    tess.calculate();
+    let report = tess.mesh_quality();
+    assert_eq!(report.faces.len(), 1);
+    assert!(report.faces[0].non_orthogonality < 1e-12 && report.faces[0].skewness < 1e-12);
+}
+
+#[test]
+fn test_calculate_quantized() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.random_generators_seeded(300, 8);
+
+    let quantized = tess.calculate_quantized();
+    assert!(tess.cells().is_empty());
+    tess.calculate();
+    assert_eq!(quantized.len(), 300);
+    for (q, cell) in quantized.iter().zip(tess.cells()) {
+        assert_eq!(q.volume, cell.volume());
+        assert_eq!(q.neighbors, cell.face_neighbors());
+        let error = q.max_error();
+        for (a, b) in q.dequantized_vertices().chunks_exact(3).zip(cell.vertices().chunks_exact(3)) {
+            assert!((0..3).all(|k| (a[k] - b[k]).abs() <= error[k] * (1.0 + 1e-9)));
+        }
+    }
+}
+
+#[test]
+fn test_generator_ids() {
+    use voronoid::NeighborId;
+    use voronoid::wall_3d::SphereGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    let generators = [1.0, 1.0, 1.0, 5.0, 5.0, 5.0, 8.5, 5.0, 5.0, 6.0, 5.0, 5.0];
+    assert_eq!(tess.set_generators_with_ids(&generators, &[7, 8]), Err(GeneratorError::CountMismatch(4, 2)));
+    assert_eq!(tess.set_generators_with_ids(&generators, &[7, 8, 7, 9]), Err(GeneratorError::DuplicateId(7)));
+
+    // The generator outside the wall is dropped together with its ID.
+    tess.set_generators_with_ids(&generators, &[100, 200, 300, 400]).unwrap();
+    assert_eq!(tess.count_generators(), 3);
+    assert_eq!(tess.generator_id(2), 400);
+    assert_eq!(tess.generator_index(100), None);
+
+    // Pruning by another wall shifts the indices, but not the IDs.
+    tess.add_wall(Wall::try_new(WALL_ID_MAX - 1, Box::new(PlaneGeometry::new([8.0, 0.0, 0.0], [-1.0, 0.0, 0.0]))).unwrap());
+    assert_eq!(tess.count_generators(), 2);
+    assert_eq!(tess.generator_index(200), Some(0));
+    assert_eq!(tess.generator_index(400), Some(1));
+    assert_eq!(tess.generator_index(300), None);
+
+    tess.calculate();
+    let ids = tess.face_neighbor_ids(1).unwrap();
+    assert!(ids.contains(&NeighborId::Generator(200)));
+    assert!(ids.contains(&NeighborId::Wall(WALL_ID_MAX - 1)));
+    assert_eq!(tess.face_neighbor_ids(5), None);
+
+    // Without IDs, the indices are reported.
+    tess.set_generators(&[4.0, 5.0, 5.0, 6.0, 5.0, 5.0]).unwrap();
+    assert!(!tess.has_generator_ids());
+    assert_eq!(tess.generator_id(1), 1);
+    assert_eq!(tess.generator_index(1), Some(1));
+    assert_eq!(tess.set_generator_ids(&[5]), Err(GeneratorError::CountMismatch(2, 1)));
+    tess.set_generator_ids(&[u64::MAX, 3]).unwrap();
+    assert_eq!(tess.generator_index(u64::MAX), Some(0));
+}
+
+#[test]
+fn test_refine_where_with_ids() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 2, 2, &bounds));
+    tess.set_generators(&[2.0, 5.0, 5.0, 8.0, 5.0, 5.0]).unwrap();
+    tess.set_ghosts(&[5.0, 9.5, 5.0]).unwrap();
+    tess.set_generator_ids(&[10, 20, 30]).unwrap();
+    tess.calculate();
+
+    // Refining nothing keeps the IDs.
+    assert!(tess.refine_where(|_| false).is_empty());
+    assert_eq!(tess.generator_id(2), 30);
+
+    // The inserted generator shifts the ghost and has no ID, so the indices are reported,
+    // even once removing the ghost restores the number of generators.
+    assert_eq!(tess.refine_where(|cell| cell.centroid()[0] < 5.0), vec![2]);
+    assert!(!tess.has_generator_ids());
+    tess.clear_ghosts();
+    assert!(!tess.has_generator_ids());
+    assert_eq!(tess.generator_id(2), 2);
+    assert_eq!(tess.generator_index(30), None);
+    tess.calculate();
+    for i in 0..tess.count_cells() {
+        for neighbor in tess.face_neighbor_ids(i).unwrap() {
+            if let voronoid::NeighborId::Generator(id) = neighbor {
+                assert_eq!(tess.generator_index(id), Some(id as usize));
+            }
+        }
+    }
+}
+
+#[test]
+fn test_relax_projects_onto_walls() {
+    use voronoid::wall_3d::TorusGeometry;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    let torus = TorusGeometry::new([5.0, 5.0, 5.0], [0.0, 0.0, 1.0], 3.0, 1.5);
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(torus)).unwrap());
+    tess.random_generators_seeded(20, 4);
+    let ids: Vec<u64> = (0..20).map(|i| 1000 + i).collect();
+    tess.set_generator_ids(&ids).unwrap();
+
+    // The few large cells extend around the bend of the torus, so their centroids lie outside it,
+    // but they are projected back instead of being pruned.
+    for _ in 0..10 {
+        tess.calculate();
+        tess.relax();
+        assert_eq!(tess.count_generators(), 20);
+    }
+    assert!(tess.generators.chunks_exact(3).all(|p| tess.walls[0].contains(&[p[0], p[1], p[2]])));
+    assert_eq!(tess.generator_id(19), 1019);
+}
+
+#[test]
+fn test_set_generators_sparse() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(PlaneGeometry::new([0.0, 0.0, 9.0], [0.0, 0.0, -1.0]))).unwrap());
+    tess.random_generators_seeded(100, 5);
+
+    assert_eq!(tess.set_generators_sparse(&[0, 1], &[1.0, 1.0, 1.0]), Err(GeneratorError::CountMismatch(2, 1)));
+    assert_eq!(tess.set_generators_sparse(&[0], &[f64::NAN, 1.0, 1.0]), Err(GeneratorError::NonFinite(vec![0])));
+
+    // A few updates, skipping an index out of range and a position outside the wall.
+    let moved = tess.set_generators_sparse(&[3, 500, 7, 3], &[1.0, 1.0, 1.0, 2.0, 2.0, 2.0, 3.0, 3.0, 9.5, 4.0, 4.0, 4.0]);
+    assert_eq!(moved, Ok(2));
+    assert_eq!(tess.try_get_generator(3).unwrap(), [4.0, 4.0, 4.0]);
+    assert_ne!(tess.try_get_generator(7).unwrap(), [3.0, 3.0, 9.5]);
+
+    // Moving most generators rebuilds the bins, with the same cells as setting them all.
+    let indices: Vec<usize> = (0..80).collect();
+    let positions: Vec<f64> = (0..80).flat_map(|i| [0.1 * i as f64 + 0.5, 5.0, 1.0 + 0.09 * i as f64]).collect();
+    assert_eq!(tess.set_generators_sparse(&indices, &positions), Ok(80));
+    tess.calculate();
+    let sparse: Vec<f64> = tess.cells().iter().map(|c| c.volume()).collect();
+    let all = tess.generators.clone();
+    tess.set_generators(&all).unwrap();
+    tess.calculate();
+    assert_eq!(sparse, tess.cells().iter().map(|c| c.volume()).collect::<Vec<_>>());
+}
+
+#[test]
+fn test_find_generator_near() {
+    use voronoid::{Algorithm2DGrid, Algorithm3DOctree, Cell2D};
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut grid = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    let mut adaptive = Tessellation::<3, Cell3DFaces, _>::new(bounds, AlgorithmAdaptiveGrid::new(6, 6, 6, &bounds));
+    let mut octree = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DOctree::new(bounds, 8));
+    grid.random_generators_seeded(500, 9);
+    adaptive.set_generators(&grid.generators).unwrap();
+    octree.set_generators(&grid.generators).unwrap();
+
+    let brute = |point: &[f64; 3], radius: f64| {
+        (0..grid.count_generators())
+            .map(|i| (i, grid.try_get_generator(i).unwrap().iter().zip(point).map(|(a, b)| (a - b).powi(2)).sum::<f64>()))
+            .filter(|&(_, d2)| d2 <= radius * radius)
+            .min_by(|a, b| a.1.total_cmp(&b.1))
+            .map(|(i, _)| i)
+    };
+    for (point, radius) in [([5.0, 5.0, 5.0], 2.0), ([0.1, 9.9, 3.0], 0.5), ([-3.0, 5.0, 5.0], 4.0), ([2.0, 2.0, 2.0], 0.01)] {
+        let expected = brute(&point, radius);
+        assert_eq!(grid.find_generator_near(&point, radius), expected);
+        assert_eq!(adaptive.find_generator_near(&point, radius), expected);
+        assert_eq!(octree.find_generator_near(&point, radius), expected);
+    }
+    assert_eq!(grid.find_generator_near(&grid.try_get_generator(42).unwrap(), 0.0), Some(42));
+
+    let bounds = BoundingBox::new([0.0, 0.0], [10.0, 10.0]);
+    let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(5, 5, &bounds));
+    tess.set_generators(&[1.0, 1.0, 8.0, 8.0, 8.5, 8.0]).unwrap();
+    assert_eq!(tess.find_generator_near(&[8.3, 8.0], 1.0), Some(2));
+    assert_eq!(tess.find_generator_near(&[5.0, 5.0], 1.0), None);
+}
+
+#[test]
+fn test_set_algorithm() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(1, 1, 1, &bounds));
+    tess.random_generators_seeded(200, 7);
+    tess.calculate();
+    let volumes: Vec<f64> = tess.cells.iter().map(|c| c.volume()).collect();
+
+    // The new algorithm indexes the current generators and gives the same cells,
+    // up to rounding from the order of the clips.
+    tess.set_algorithm(Algorithm3DGrid::new(4, 4, 4, &bounds));
+    assert_eq!(tess.algorithm.resolution(), [4, 4, 4]);
+    assert_eq!(tess.algorithm.bin_occupancy().iter().sum::<usize>(), 200);
+    tess.calculate();
+    assert!(tess.cells.iter().zip(&volumes).all(|(c, v)| (c.volume() - v).abs() < 1e-9));
+}
+
+#[test]
+fn test_calculate_warm() {
+    use rand::{Rng, SeedableRng};
+    use voronoid::Cell;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.random_generators_seeded(300, 3);
+
+    // Without previous cells the warm start calculates from scratch.
+    tess.calculate_warm();
+    assert_eq!(tess.count_cells(), 300);
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(5);
+    let moved: Vec<f64> = tess.generators().iter().map(|&x| (x + rng.gen_range(-0.05..0.05)).clamp(0.0, 10.0)).collect();
+    tess.set_generators(&moved).unwrap();
+    tess.calculate_warm();
+    let warm: Vec<(f64, Vec<i32>)> = tess.cells.iter().map(|c| (c.volume(), sorted(c.neighbors()))).collect();
+    tess.calculate();
+    for ((volume, neighbors), cell) in warm.iter().zip(&tess.cells) {
+        assert!((volume - cell.volume()).abs() < 1e-9);
+        assert_eq!(neighbors, &sorted(cell.neighbors()));
+    }
+
+    fn sorted(neighbors: &[i32]) -> Vec<i32> {
+        let mut neighbors = neighbors.to_vec();
+        neighbors.sort_unstable();
+        neighbors
+    }
+}
+
+#[test]
+fn test_domain_transform() {
+    use voronoid::DomainTransform;
+
+    // A slab a thousand times longer than it is thick, computed in the unit cube.
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [1000.0, 1.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 1, 1, &bounds));
+    tess.set_domain_transform(DomainTransform::normalizing(&bounds));
+    assert_eq!((tess.bounds.min, tess.bounds.max), ([0.0; 3], [1.0; 3]));
+
+    let generators = [100.0, 0.5, 0.5, 400.0, 0.5, 0.5, 900.0, 0.5, 0.5];
+    tess.set_generators_physical(&generators).unwrap();
+    assert_eq!(tess.try_get_generator(1).unwrap(), [0.4, 0.5, 0.5]);
+    assert!(tess.physical_generators().iter().zip(&generators).all(|(a, b)| (a - b).abs() < 1e-9));
+    tess.calculate();
+
+    // The cells along the long axis end halfway between the generators.
+    assert!((tess.physical_volume(0).unwrap() - 250.0).abs() < 1e-9);
+    assert!((tess.physical_volume(2).unwrap() - 350.0).abs() < 1e-9);
+    assert!((tess.physical_centroid(0).unwrap()[0] - 125.0).abs() < 1e-9);
+    let vertices = tess.physical_vertices(1).unwrap();
+    assert!(vertices.chunks_exact(3).all(|v| (v[0] - 250.0).abs() < 1e-9 || (v[0] - 650.0).abs() < 1e-9));
+    assert_eq!(tess.physical_volume(3), None);
+
+    // Removing the transform restores the physical domain.
+    tess.clear_domain_transform();
+    assert!(tess.domain_transform().is_none());
+    assert_eq!(tess.bounds.max, [1000.0, 1.0, 1.0]);
+    assert!((tess.try_get_generator(1).unwrap()[0] - 400.0).abs() < 1e-9);
+}
+
+#[test]
+fn test_cell_diameter() {
+    use voronoid::{Algorithm2DGrid, Cell, Cell2D};
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [4.0, 2.0, 1.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(2, 1, 1, &bounds));
+    assert_eq!(tess.max_cell_diameter(), 0.0);
+    tess.set_generators(&[1.0, 1.0, 0.5, 2.5, 1.0, 0.5]).unwrap();
+    tess.calculate();
+
+    // The cells are boxes split at x = 1.75, with their space diagonals as diameters.
+    assert!((tess.cells[0].diameter() - (1.75f64 * 1.75 + 5.0).sqrt()).abs() < 1e-12);
+    assert!((tess.max_cell_diameter() - (2.25f64 * 2.25 + 5.0).sqrt()).abs() < 1e-12);
+
+    let bounds = BoundingBox::new([0.0, 0.0], [3.0, 4.0]);
+    assert_eq!(Cell2D::new(0, bounds).diameter(), 5.0);
+    let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(1, 1, &bounds));
+    tess.set_generators(&[1.0, 1.0]).unwrap();
+    tess.calculate();
+    assert_eq!(tess.max_cell_diameter(), 5.0);
+}
+
+#[test]
+fn test_custom_tube_wall() {
+    use voronoid::curve::{SegmentCurve, TubeGeometry};
+    use voronoid::Wall;
+
+    // A tube around a helix, sampled from its parametric form.
+    let helix = SegmentCurve::from_fn(|t| {
+        let angle = 4.0 * std::f64::consts::PI * t;
+        [5.0 + 3.0 * angle.cos(), 5.0 + 3.0 * angle.sin(), 1.0 + 8.0 * t]
+    }, 200, false);
+    assert_eq!(helix.segment_count(), 200);
+    let length = helix.length();
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(8, 8, 8, &bounds));
+    tess.add_wall(Wall::try_new(-1000, Box::new(TubeGeometry::new(helix, 1.0))).unwrap());
+    tess.random_generators_seeded(500, 7);
+    tess.calculate();
+
+    // The cells fill the tube with its rounded ends, up to the planar approximation of its surface.
+    let tube = std::f64::consts::PI * length + 4.0 / 3.0 * std::f64::consts::PI;
+    assert_eq!(tess.count_cells(), 500);
+    assert!((tess.total_volume() - tube).abs() / tube < 0.25);
+    assert!(tess.walls[0].contains(&[8.0, 5.0, 1.0]));
+}
+
+#[test]
+fn test_extrude_layers() {
+    use voronoid::{Algorithm2DGrid, Cell, Cell2D};
+
+    let bounds = BoundingBox::new([0.0, 0.0], [1.0, 1.0]);
+    let mut tess = Tessellation::<2, Cell2D, _>::new(bounds, Algorithm2DGrid::new(5, 5, &bounds));
+    tess.random_generators_seeded(40, 11);
+    tess.calculate();
+
+    // A single layer matches the 3D tessellation of the generators at half the height.
+    let prisms = tess.extrude(0.5);
+    let generators: Vec<f64> = tess.generators().chunks_exact(2).flat_map(|p| [p[0], p[1], 0.25]).collect();
+    let bounds_3d = BoundingBox::new([0.0, 0.0, 0.0], [1.0, 1.0, 0.5]);
+    let mut tess_3d = Tessellation::<3, Cell3DFaces, _>::new(bounds_3d, Algorithm3DGrid::new(5, 5, 1, &bounds_3d));
+    tess_3d.set_generators(&generators).unwrap();
+    tess_3d.calculate();
+    for (i, prism) in prisms.iter().enumerate() {
+        let cell = tess_3d.get_cell(i).unwrap();
+        assert!((Cell::volume(prism) - Cell::volume(&cell)).abs() < 1e-12);
+        assert!((Cell::volume(prism) - 0.5 * Cell::volume(&tess.get_cell(i).unwrap())).abs() < 1e-12);
+        let mut a = Cell::neighbors(prism).to_vec();
+        let mut b = Cell::neighbors(&cell).to_vec();
+        a.sort();
+        b.sort();
+        assert_eq!(a, b);
+    }
+
+    // Stacked layers link up through their bottom and top faces.
+    let layers = tess.extrude_layers(&[0.0, 0.5, 1.5]);
+    assert_eq!(layers.len(), 80);
+    assert!((layers.iter().map(Cell::volume).sum::<f64>() - 1.5).abs() < 1e-12);
+    assert_eq!(Cell::neighbors(&layers[3])[..2], [-5, 43]);
+    assert_eq!(Cell::neighbors(&layers[43])[..2], [3, -6]);
+    assert!(tess.extrude_layers(&[1.0]).is_empty());
+}
+
+#[test]
+fn test_convex_hull() {
+    use rand::{Rng, SeedableRng};
+    use voronoid::{convex_hull, Wall};
+
+    let mut rng = rand::rngs::StdRng::seed_from_u64(3);
+    let points: Vec<f64> = (0..600).map(|_| rng.gen_range(2.0..8.0)).collect();
+    let hull = convex_hull(&points).unwrap();
+    for p in points.chunks_exact(3) {
+        assert!(hull.geometry.planes.iter().all(|(q, n)| (0..3).map(|k| (p[k] - q[k]) * n[k]).sum::<f64>() < 1e-9));
+    }
+
+    // The mesh is closed, with each edge shared by two triangles in opposite directions.
+    let edges: std::collections::HashSet<(usize, usize)> = hull.mesh.elements.iter().flat_map(|&[a, b, c]| [(a, b), (b, c), (c, a)]).collect();
+    assert_eq!(edges.len(), 3 * hull.mesh.elements.len());
+    assert!(edges.iter().all(|&(a, b)| edges.contains(&(b, a))));
+    let p = |v: usize| &hull.mesh.vertices[v * 3..v * 3 + 3];
+    let volume: f64 = hull
+        .mesh
+        .elements
+        .iter()
+        .map(|&[a, b, c]| {
+            let (a, b, c) = (p(a), p(b), p(c));
+            a[0] * (b[1] * c[2] - b[2] * c[1]) + a[1] * (b[2] * c[0] - b[0] * c[2]) + a[2] * (b[0] * c[1] - b[1] * c[0])
+        })
+        .sum::<f64>()
+        / 6.0;
+
+    // Clipping a tessellation to the hull fills it exactly.
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.add_wall(Wall::try_new(-1000, Box::new(hull.geometry)).unwrap());
+    tess.random_generators_seeded(300, 5);
+    tess.calculate();
+    assert!((tess.total_volume() - volume).abs() < 1e-9 * volume, "{} {volume}", tess.total_volume());
+}
+
+#[test]
+fn test_preview_calculate() {
+    use voronoid::Cell;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(6, 6, 6, &bounds));
+    tess.random_generators_seeded(500, 9);
+    tess.calculate();
+    let exact = tess.cells();
+
+    // A small budget leaves some cells approximate, which contain the exact cells.
+    tess.preview_calculate(24);
+    let approximate = tess.approximate_cells();
+    assert!(!approximate.is_empty() && approximate.len() < 500);
+    for (i, (a, b)) in tess.cells().iter().zip(&exact).enumerate() {
+        if tess.is_cell_exact(i) {
+            assert!((Cell::volume(a) - Cell::volume(b)).abs() < 1e-9);
+        } else {
+            assert!(Cell::volume(a) > Cell::volume(b) - 1e-9);
+        }
+    }
+    assert!(tess.total_volume() > 1000.0);
+
+    // A generous budget gives the exact cells, and an exact calculation resets the flags.
+    tess.preview_calculate(1000);
+    assert!(tess.approximate_cells().is_empty());
+    assert!((tess.total_volume() - 1000.0).abs() < 1e-9);
+    tess.preview_calculate(24);
+    tess.calculate();
+    assert!((0..500).all(|i| tess.is_cell_exact(i)));
+}
+
+#[test]
+fn test_candidate_buffer() {
+    use voronoid::Cell;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(500, 5);
+    tess.calculate();
+    let native = tess.cells();
+    let native_clips: usize = (0..500).map(|i| tess.candidate_order(i).len()).sum();
+
+    // Clipping the nearest of the held back candidates first gives the same cells with fewer clips.
+    tess.set_candidate_buffer(Some(8));
+    tess.calculate();
+    for (a, b) in tess.cells().iter().zip(&native) {
+        assert!((Cell::volume(a) - Cell::volume(b)).abs() < 1e-9);
+    }
+    let buffered_clips: usize = (0..500).map(|i| tess.candidate_order(i).len()).sum();
+    assert!(buffered_clips < native_clips);
+    assert!((tess.total_volume() - 1000.0).abs() < 1e-9);
+
+    let order = tess.candidate_order(0);
+    assert!(!order.is_empty() && !order.contains(&0));
+    assert!(tess.candidate_order(500).is_empty());
+}
+
+#[test]
+fn test_snapshot_swap() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    let handle = tess.snapshot_handle();
+    assert_eq!(handle.generation(), 0);
+    assert!(handle.load().cells.is_empty());
+
+    tess.random_generators_seeded(100, 1);
+    tess.calculate();
+    assert_eq!(tess.swap(), 1);
+    let first = handle.load();
+    assert_eq!(first.cells.len(), 100);
+
+    // A reader thread keeps the published cells while the next calculation runs.
+    let reader = {
+        let handle = handle.clone();
+        std::thread::spawn(move || {
+            let snapshot = handle.load();
+            (snapshot.generation, snapshot.cells.len())
+        })
+    };
+    tess.random_generators_seeded(200, 2);
+    tess.calculate();
+    let (generation, count) = reader.join().unwrap();
+    assert_eq!((generation, count), (1, 100));
+    assert_eq!(handle.load().cells.len(), 100);
+
+    assert_eq!(tess.swap(), 2);
+    assert_eq!(handle.load().cells.len(), 200);
+    assert_eq!(tess.published().generators.len(), 600);
+    assert_eq!(first.cells.len(), 100);
+}
+
+#[test]
+fn test_calculate_streaming() {
+    use std::sync::Mutex;
+    use voronoid::Cell;
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.random_generators_seeded(400, 6);
+    tess.calculate();
+    let expected: Vec<f64> = tess.cells().iter().map(Cell::volume).collect();
+
+    // Each cell is streamed exactly once, and without storing the tessellation has no cells.
+    let streamed = Mutex::new(vec![None; 400]);
+    tess.calculate_streaming(false, |i, cell| {
+        let previous = streamed.lock().unwrap()[i].replace(cell.volume());
+        assert!(previous.is_none());
+    });
+    assert_eq!(tess.count_cells(), 0);
+    let streamed: Vec<f64> = streamed.into_inner().unwrap().into_iter().map(Option::unwrap).collect();
+    assert_eq!(streamed, expected);
+
+    let total = Mutex::new(0);
+    tess.calculate_streaming(true, |_, _| *total.lock().unwrap() += 1);
+    assert_eq!(total.into_inner().unwrap(), 400);
+    assert_eq!(tess.cells().iter().map(Cell::volume).collect::<Vec<_>>(), expected);
+}
@@ -19,7 +19,7 @@ fn test_mapping_face_counts() {
             }
         }
     }
-    tess.set_generators(&generators);
+    tess.set_generators(&generators).unwrap();
 
     // Use map_cells to calculate the number of faces for each cell.
     // This avoids storing the full Cell objects in memory.
@@ -4,16 +4,10 @@ use rand::Rng;
 
 trait NeighborCell: Cell<3> {
     fn face_neighbors(&self) -> Vec<i32>;
-    fn vertices(&self) -> Vec<f64>;
-    fn volume(&self) -> f64;
-    fn face_area(&self, face_index: usize) -> f64;
 }
 
 impl NeighborCell for Cell3DFaces {
     fn face_neighbors(&self) -> Vec<i32> { self.face_neighbors() }
-    fn vertices(&self) -> Vec<f64> { self.vertices() }
-    fn volume(&self) -> f64 { self.volume() }
-    fn face_area(&self, face_index: usize) -> f64 { self.face_area(face_index) }
 }
 
 fn check_reciprocity<C: NeighborCell, A: voronoid::SpatialAlgorithm<3>>(tess: &Tessellation<3, C, A>) {
@@ -99,7 +93,7 @@ macro_rules! run_two_cells {
         test_neighbors!($test_name, $cell, $algo, 10.0, 
             |tess: &mut Tessellation<3, $cell, _>, _| {
                 let generators = vec![2.5, 5.0, 5.0, 7.5, 5.0, 5.0];
-                tess.set_generators(&generators);
+                tess.set_generators(&generators).unwrap();
             },
             |tess: &Tessellation<3, $cell, _>| {
                 let c0 = tess.get_cell(0).unwrap();
@@ -126,7 +120,7 @@ macro_rules! run_random {
                     generators.push(rng.gen_range(0.0..size));
                     generators.push(rng.gen_range(0.0..size));
                 }
-                tess.set_generators(&generators);
+                tess.set_generators(&generators).unwrap();
             },
             check_reciprocity
         );
@@ -148,9 +142,9 @@ macro_rules! run_half_sphere {
                     generators.push(rng.gen_range(0.0..size));
                     generators.push(rng.gen_range(0.0..size));
                 }
-                tess.set_generators(&generators);
-                tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(SphereGeometry::new([15.0, 15.0, 15.0], 12.0))));
-                tess.add_wall(Wall::new(WALL_ID_MAX - 1, Box::new(PlaneGeometry::new([15.0, 15.0, 15.0], [1.0, 0.0, 0.0]))));
+                tess.set_generators(&generators).unwrap();
+                tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([15.0, 15.0, 15.0], 12.0))).unwrap());
+                tess.add_wall(Wall::try_new(WALL_ID_MAX - 1, Box::new(PlaneGeometry::new([15.0, 15.0, 15.0], [1.0, 0.0, 0.0]))).unwrap());
             },
             check_reciprocity
         );
@@ -165,7 +159,7 @@ macro_rules! run_sphere_small {
     ($test_name:ident, $cell:ty, $algo:expr) => {
         test_neighbors!($test_name, $cell, $algo, 20.0,
             |tess: &mut Tessellation<3, $cell, _>, _| {
-                tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(SphereGeometry::new([10.0, 10.0, 10.0], 8.0))));
+                tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([10.0, 10.0, 10.0], 8.0))).unwrap());
                 tess.random_generators(40);
             },
             check_reciprocity
@@ -188,8 +182,8 @@ macro_rules! run_dodecahedron {
                     generators.push(rng.gen_range(0.0..size));
                     generators.push(rng.gen_range(0.0..size));
                 }
-                tess.set_generators(&generators);
-                tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([15.0, 15.0, 15.0], 10.0))));
+                tess.set_generators(&generators).unwrap();
+                tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([15.0, 15.0, 15.0], 10.0))).unwrap());
             },
             check_reciprocity
         );
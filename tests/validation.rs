@@ -0,0 +1,107 @@
+use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Cell, Cell3DFaces, SliverRepair, Wall, WALL_ID_MAX};
+use voronoid::wall_3d::{PlaneGeometry, SphereGeometry};
+
+#[test]
+fn test_validate_random_box() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators(200);
+    tess.calculate();
+
+    let report = tess.validate();
+    assert!(!report.stale);
+    assert_eq!(report.expected_volume, Some(1000.0));
+    assert!(report.asymmetric_neighbors.is_empty(), "Asymmetric neighbors: {:?}", report.asymmetric_neighbors);
+    assert!(report.invalid_cells.is_empty());
+    assert!(report.overflowed_cells.is_empty());
+    assert!(report.is_valid(1e-6), "Volume error: {:?}", report.volume_error());
+}
+
+#[test]
+fn test_validate_stale_and_walls() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap());
+    tess.random_generators(50);
+
+    // No cells have been calculated yet.
+    assert!(tess.validate().stale);
+
+    tess.calculate();
+    let report = tess.validate();
+    assert!(!report.stale);
+    assert_eq!(report.expected_volume, None);
+    assert!(report.total_volume > 0.0 && report.total_volume < 1000.0);
+
+    // Planar walls are filled exactly by the cells, so their domain volume is checked.
+    tess.clear_walls();
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(PlaneGeometry::new([0.0, 0.0, 4.0], [0.0, 0.0, 1.0]))).unwrap());
+    tess.calculate();
+    let report = tess.validate();
+    assert!((report.expected_volume.unwrap() - 600.0).abs() < 1e-9);
+    assert!(report.is_valid(1e-9), "Volume error: {:?}", report.volume_error());
+}
+
+#[test]
+fn test_validate_ghosts() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.set_generators(&[2.0, 5.0, 5.0, 4.0, 5.0, 5.0]).unwrap();
+    tess.set_ghosts(&[6.0, 5.0, 5.0]).unwrap();
+    tess.calculate();
+    assert!(tess.get_cell(1).unwrap().neighbors().contains(&2));
+
+    // The owned cells only fill the part of the box up to the ghost, so no volume is expected.
+    let report = tess.validate();
+    assert!(!report.stale);
+    assert_eq!(report.expected_volume, None);
+    assert!(report.asymmetric_neighbors.is_empty(), "Asymmetric neighbors: {:?}", report.asymmetric_neighbors);
+    assert!(report.invalid_cells.is_empty());
+    assert!(report.is_valid(1e-9));
+}
+
+#[test]
+fn test_repair_asymmetric_neighbors() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [20.0, 20.0, 20.0]);
+    for mode in [SliverRepair::Drop, SliverRepair::Recut] {
+        let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([10.0, 10.0, 10.0], 8.0))).unwrap());
+        tess.random_generators(200);
+        tess.calculate();
+
+        let threshold = 1e-2;
+        let slivers = tess.find_asymmetric_neighbors().iter().filter(|s| s.2 < threshold).count();
+        let repaired = tess.repair_asymmetric_neighbors(threshold, mode);
+        assert!(repaired <= slivers);
+        if mode == SliverRepair::Drop {
+            assert_eq!(repaired, slivers);
+            assert!(tess.find_asymmetric_neighbors().iter().all(|s| s.2 >= threshold));
+        }
+    }
+}
+
+#[test]
+fn test_empty_cells() {
+    use voronoid::{BoundaryPolicy, EmptyCell};
+
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(4, 4, 4, &bounds));
+    tess.random_generators_seeded(50, 3);
+    tess.calculate();
+    assert!(tess.empty_cells().is_empty());
+
+    // Two opposite walls leave only the plane x = 5, which collapses the cells on it.
+    tess.set_boundary_policy(BoundaryPolicy::Keep);
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(PlaneGeometry::new([5.0, 0.0, 0.0], [1.0, 0.0, 0.0]))).unwrap());
+    tess.add_wall(Wall::try_new(WALL_ID_MAX - 1, Box::new(PlaneGeometry::new([5.0, 0.0, 0.0], [-1.0, 0.0, 0.0]))).unwrap());
+    tess.set_generators(&[5.0, 2.0, 2.0, 5.0, 8.0, 8.0]).unwrap();
+    tess.calculate();
+    assert_eq!(tess.validate().empty_cells, vec![0, 1]);
+    let empty = tess.empty_cells();
+    assert_eq!(empty.len(), 2);
+    assert!(empty.iter().all(|e| matches!(e, EmptyCell { cause: Some(id), .. } if *id <= WALL_ID_MAX)));
+
+    // The generators of the collapsed cells are kept by the relaxation.
+    tess.relax();
+    assert_eq!(tess.generators, vec![5.0, 2.0, 2.0, 5.0, 8.0, 8.0]);
+}
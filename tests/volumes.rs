@@ -1,5 +1,5 @@
 use voronoid::{BoundingBox, Tessellation, Algorithm3DGrid, Algorithm3DOctree, Cell3DFaces, Wall, WALL_ID_MAX};
-use voronoid::wall_3d::{SphereGeometry, CylinderGeometry, ConvexPolyhedronGeometry};
+use voronoid::wall_3d::{SphereGeometry, CylinderGeometry, ConvexPolyhedronGeometry, PlaneGeometry, TorusGeometry};
 
 const GRID_SIZE: usize = 20;
 
@@ -31,7 +31,7 @@ macro_rules! test_volume {
             let mut tess = Tessellation::<3, $cell_type, _>::new(bounds, algo);
 
             let generators = generate_grid(size);
-            tess.set_generators(&generators);
+            tess.set_generators(&generators).unwrap();
 
             $setup(&mut tess, size);
             tess.calculate();
@@ -41,6 +41,7 @@ macro_rules! test_volume {
                 .sum();
 
             let expected_volume = $expected;
+            assert!((tess.domain_volume() - expected_volume).abs() < 1e-9 * expected_volume);
             
             let error = (total_volume - expected_volume).abs() / expected_volume;
             println!("{} Volume: Got {:.4}, Expected {:.4}, Error {:.4}%", stringify!($test_name), total_volume, expected_volume, error * 100.0);
@@ -53,14 +54,14 @@ macro_rules! test_volume {
 test_volume!(test_sphere_volume_grid_faces, Cell3DFaces, |b| Algorithm3DGrid::new(5, 5, 5, b), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(SphereGeometry::new([size/2.0, size/2.0, size/2.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([size/2.0, size/2.0, size/2.0], r))).unwrap());
     },
     4.0 / 3.0 * std::f64::consts::PI * 4.0f64.powi(3)
 );
 test_volume!(test_sphere_volume_octree_faces, Cell3DFaces, |b: &BoundingBox<3>| Algorithm3DOctree::new(*b, 16), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(SphereGeometry::new([size/2.0, size/2.0, size/2.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([size/2.0, size/2.0, size/2.0], r))).unwrap());
     },
     4.0 / 3.0 * std::f64::consts::PI * 4.0f64.powi(3)
 );
@@ -69,14 +70,14 @@ test_volume!(test_sphere_volume_octree_faces, Cell3DFaces, |b: &BoundingBox<3>|
 test_volume!(test_cylinder_volume_grid_faces, Cell3DFaces, |b| Algorithm3DGrid::new(5, 5, 5, b), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(CylinderGeometry::new([size/2.0, size/2.0, size/2.0], [0.0, 0.0, 1.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(CylinderGeometry::new([size/2.0, size/2.0, size/2.0], [0.0, 0.0, 1.0], r))).unwrap());
     },
     std::f64::consts::PI * 4.0f64.powi(2) * 10.0
 );
 test_volume!(test_cylinder_volume_octree_faces, Cell3DFaces, |b: &BoundingBox<3>| Algorithm3DOctree::new(*b, 16), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(CylinderGeometry::new([size/2.0, size/2.0, size/2.0], [0.0, 0.0, 1.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(CylinderGeometry::new([size/2.0, size/2.0, size/2.0], [0.0, 0.0, 1.0], r))).unwrap());
     },
     std::f64::consts::PI * 4.0f64.powi(2) * 10.0
 );
@@ -92,14 +93,63 @@ fn dodecahedron_volume(radius: f64) -> f64 {
 test_volume!(test_dodecahedron_volume_grid_faces, Cell3DFaces, |b| Algorithm3DGrid::new(5, 5, 5, b), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([size/2.0, size/2.0, size/2.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([size/2.0, size/2.0, size/2.0], r))).unwrap());
     },
     dodecahedron_volume(4.0)
 );
 test_volume!(test_dodecahedron_volume_octree_faces, Cell3DFaces, |b: &BoundingBox<3>| Algorithm3DOctree::new(*b, 16), 
     |tess: &mut Tessellation<3, Cell3DFaces, _>, size: f64| {
         let r = 4.0;
-        tess.add_wall(Wall::new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([size/2.0, size/2.0, size/2.0], r))));
+        tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(ConvexPolyhedronGeometry::new_dodecahedron([size/2.0, size/2.0, size/2.0], r))).unwrap());
     },
     dodecahedron_volume(4.0)
 );
+
+// Curvature corrections of the single-plane approximation
+fn corrected_volume_errors(wall: Wall<3>, expected: f64) -> (f64, f64) {
+    let size = 10.0;
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [size, size, size]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    tess.set_generators(&generate_grid(size)).unwrap();
+    tess.add_wall(wall);
+    tess.calculate();
+
+    let volume = tess.total_volume();
+    let correction: f64 = tess.volume_corrections().iter().sum();
+    println!("Volume: {:.4}, Corrected {:.4}, Expected {:.4}", volume, volume + correction, expected);
+    ((volume - expected).abs() / expected, (volume + correction - expected).abs() / expected)
+}
+
+#[test]
+fn test_sphere_volume_correction() {
+    let wall = Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([5.0, 5.0, 5.0], 4.0))).unwrap();
+    let (error, corrected) = corrected_volume_errors(wall, 4.0 / 3.0 * std::f64::consts::PI * 4.0f64.powi(3));
+    assert!(corrected < 0.1 * error, "Corrected error {:.4}% not below {:.4}%", corrected * 100.0, error * 100.0);
+}
+
+#[test]
+fn test_cylinder_volume_correction() {
+    let wall = Wall::try_new(WALL_ID_MAX, Box::new(CylinderGeometry::new([5.0, 5.0, 5.0], [0.0, 0.0, 1.0], 4.0))).unwrap();
+    let (error, corrected) = corrected_volume_errors(wall, std::f64::consts::PI * 4.0f64.powi(2) * 10.0);
+    assert!(corrected < 0.1 * error, "Corrected error {:.4}% not below {:.4}%", corrected * 100.0, error * 100.0);
+}
+
+#[test]
+fn test_sampled_domain_volume() {
+    let bounds = BoundingBox::new([0.0, 0.0, 0.0], [10.0, 10.0, 10.0]);
+    let mut tess = Tessellation::<3, Cell3DFaces, _>::new(bounds, Algorithm3DGrid::new(5, 5, 5, &bounds));
+    assert_eq!(tess.domain_volume(), 1000.0);
+
+    // An eighth of a sphere around a corner of the box has no closed form volume in the crate.
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(SphereGeometry::new([0.0, 0.0, 0.0], 4.0))).unwrap());
+    let expected = std::f64::consts::PI * 4.0f64.powi(3) / 6.0;
+    assert!((tess.domain_volume() - expected).abs() < 1e-4 * 1000.0);
+
+    // Half of a torus, cut by a plane through its center.
+    tess.clear_walls();
+    tess.add_wall(Wall::try_new(WALL_ID_MAX, Box::new(TorusGeometry::new([5.0, 5.0, 5.0], [0.0, 0.0, 1.0], 3.0, 1.0))).unwrap());
+    let torus = 2.0 * std::f64::consts::PI.powi(2) * 3.0;
+    assert!((tess.domain_volume() - torus).abs() < 1e-9);
+    tess.add_wall(Wall::try_new(WALL_ID_MAX - 1, Box::new(PlaneGeometry::new([5.0, 5.0, 5.0], [1.0, 0.0, 0.0]))).unwrap());
+    assert!((tess.domain_volume() - 0.5 * torus).abs() < 1e-4 * 1000.0);
+}